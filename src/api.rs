@@ -1,16 +1,24 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
+use chrono::{Days, NaiveDate};
 use futures::lock::{Mutex, MutexGuard};
 
-use crate::errors::Result;
+use crate::errors::{HnCliError, Result};
 
-use self::{algolia_client::AlgoliaHnClient, client::ClassicHnClient};
+use self::{
+    algolia_client::AlgoliaHnClient,
+    client::{ClassicHnClient, HnStoriesSorting},
+    types::{HnItem, HnItemIdScalar},
+};
 
 pub mod algolia_client;
 pub mod algolia_types;
 pub mod client;
 pub mod types;
 
+/// Title prefix of the monthly hiring threads.
+const WHO_IS_HIRING_THREAD_TITLE_PREFIX: &str = "Ask HN: Who is hiring?";
+
 /// The exposed Hacker News API client, wrapping two sources: official API and Algolia-based API.
 pub struct HnClient {
     /// Original Hacker News API client.
@@ -21,6 +29,8 @@ pub struct HnClient {
     ///
     /// Documentation: https://hn.algolia.com/api
     algolia_client: Arc<Mutex<AlgoliaHnClient>>,
+    /// Timeout of each request.
+    timeout: Duration,
 }
 
 impl HnClient {
@@ -35,13 +45,169 @@ impl HnClient {
     pub async fn algolia(&self) -> MutexGuard<'_, AlgoliaHnClient> {
         self.algolia_client.lock().await
     }
+
+    /// Try to fetch the given items, each along with its rank among the top stories, if listed.
+    pub async fn get_items_with_top_stories_rank(
+        &self,
+        ids: &[HnItemIdScalar],
+    ) -> Result<Vec<(HnItem, Option<usize>)>> {
+        let classic = self.classic().await;
+        let top_stories_ids = classic
+            .get_home_stories_ids_listing(&HnStoriesSorting::Top)
+            .await?;
+        let items = classic.get_items(ids).await?;
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                let rank = top_stories_ids
+                    .iter()
+                    .position(|id| *id == item.get_id())
+                    .map(|index| index + 1);
+                (item, rank)
+            })
+            .collect())
+    }
+
+    /// Try to fetch the stories which made it to the front page on the given (UTC) day,
+    /// most popular first.
+    ///
+    /// The Algolia API is only used for the lookup, the items themselves coming from the official API.
+    ///
+    /// NB: neither API archives the standings of a past front page, so the stories are
+    /// sorted by their current score, not by their rank on that day.
+    pub async fn get_front_page_items_at(&self, day: NaiveDate) -> Result<Vec<HnItem>> {
+        let (day_start, day_end) = get_day_timestamps_range(day)?;
+        let hits = self
+            .algolia()
+            .await
+            .search_front_page_stories(day_start, day_end)
+            .await?;
+        let stories_ids: Vec<HnItemIdScalar> = hits
+            .get_hits()
+            .iter()
+            .filter_map(|story| story.object_id.parse().ok())
+            .collect();
+
+        let mut items = self.classic().await.get_items(&stories_ids).await?;
+        items.sort_by_key(|item| std::cmp::Reverse(item.get_score()));
+        Ok(items)
+    }
+
+    /// Try to fetch the root item (story, job or poll) of the thread containing the given item.
+    ///
+    /// For a comment, this goes up the chain of its parents.
+    pub async fn get_thread_root_item(&self, id: HnItemIdScalar) -> Result<HnItem> {
+        let classic = self.classic().await;
+        let mut item = classic.get_item(id).await?;
+        while let HnItem::Comment(comment) = &item {
+            item = classic.get_item(comment.parent).await?;
+        }
+        if item.is_null() || item.is_deleted() || item.is_dead() {
+            return Err(HnCliError::ItemNotFound(id));
+        }
+        Ok(item)
+    }
+
+    /// Try to fetch the stories matching the given full-text query, most relevant first.
+    ///
+    /// The Algolia API is only used for the lookup, the items themselves coming from the official API.
+    pub async fn search_stories_items(&self, query: &str) -> Result<Vec<HnItem>> {
+        let hits = self
+            .algolia()
+            .await
+            .search_stories_by_relevance(query)
+            .await?;
+        let stories_ids: Vec<HnItemIdScalar> = hits
+            .get_hits()
+            .iter()
+            .filter_map(|story| story.object_id.parse().ok())
+            .collect();
+
+        self.classic().await.get_items(&stories_ids).await
+    }
+
+    /// Try to fetch the latest monthly "Who is hiring?" thread, along with
+    /// its top-level comments (*i.e.* the job postings) in ranked order.
+    pub async fn get_latest_who_is_hiring_thread(&self) -> Result<(HnItem, Vec<HnItem>)> {
+        let hits = self.algolia().await.search_who_is_hiring_stories().await?;
+        let thread_id: HnItemIdScalar = hits
+            .get_hits()
+            .iter()
+            .find(|story| story.title.starts_with(WHO_IS_HIRING_THREAD_TITLE_PREFIX))
+            .and_then(|story| story.object_id.parse().ok())
+            .ok_or_else(|| HnCliError::ThreadNotFound(WHO_IS_HIRING_THREAD_TITLE_PREFIX.into()))?;
+
+        let classic = self.classic().await;
+        let thread = classic.get_item(thread_id).await?;
+        let postings = classic
+            .get_items(thread.get_kids().unwrap_or_default())
+            .await?;
+        Ok((thread, postings))
+    }
 }
 
 impl HnClient {
-    pub fn new() -> Result<Self> {
+    /// Create the client. In offline mode, every request fails with `HnCliError::OfflineError`.
+    pub fn new(offline: bool, timeout: Duration) -> Result<Self> {
         Ok(Self {
-            classic_client: Arc::new(Mutex::new(ClassicHnClient::new()?)),
-            algolia_client: Arc::new(Mutex::new(AlgoliaHnClient::new()?)),
+            classic_client: Arc::new(Mutex::new(ClassicHnClient::new(offline, timeout)?)),
+            algolia_client: Arc::new(Mutex::new(AlgoliaHnClient::new(offline, timeout)?)),
+            timeout,
         })
     }
+
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Try to change the timeout of the requests, without waiting for the pending ones.
+    ///
+    /// Returns false if a client is busy, in which case nothing is changed.
+    pub fn try_set_timeout(&mut self, timeout: Duration) -> bool {
+        {
+            let (Some(mut classic), Some(mut algolia)) = (
+                self.classic_client.try_lock(),
+                self.algolia_client.try_lock(),
+            ) else {
+                return false;
+            };
+            classic.set_timeout(timeout);
+            algolia.set_timeout(timeout);
+        }
+        self.timeout = timeout;
+        true
+    }
+}
+
+/// UTC timestamps of the start (inclusive) and the end (exclusive) of the given day.
+fn get_day_timestamps_range(day: NaiveDate) -> Result<(i64, i64)> {
+    let day_end = day
+        .checked_add_days(Days::new(1))
+        .ok_or_else(|| HnCliError::UiError(format!("invalid front page day: {day}")))?;
+    Ok((
+        day.and_time(Default::default()).and_utc().timestamp(),
+        day_end.and_time(Default::default()).and_utc().timestamp(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::get_day_timestamps_range;
+
+    #[test]
+    fn test_get_day_timestamps_range() {
+        let day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        // 2024-02-29T00:00:00Z and 2024-03-01T00:00:00Z
+        assert_eq!(
+            get_day_timestamps_range(day).unwrap(),
+            (1_709_164_800, 1_709_251_200)
+        );
+        let day = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        assert_eq!(get_day_timestamps_range(day).unwrap(), (0, 86_400));
+
+        // no next day to end the range
+        assert!(get_day_timestamps_range(NaiveDate::MAX).is_err());
+    }
 }
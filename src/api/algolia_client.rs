@@ -1,16 +1,26 @@
 use std::time::Duration;
 
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder};
+use url::form_urlencoded;
 
 use crate::{
     api::algolia_types::AlgoliaHnCommentsHits,
     errors::{HnCliError, Result},
 };
 
-use super::algolia_types::{AlgoliaHnFilter, AlgoliaHnSearchTag, AlgoliaHnStoriesHits};
+use super::algolia_types::{
+    AlgoliaHnFilter, AlgoliaHnNumericFilter, AlgoliaHnNumericOperator, AlgoliaHnSearchTag,
+    AlgoliaHnStoriesHits,
+};
 
 const ALGOLIA_HACKER_NEWS_API_BASE_URL: &str = "http://hn.algolia.com/api/v1";
 const ALGOLIA_HACKER_NEWS_API_MAX_HITS: u8 = 2;
+const ALGOLIA_HACKER_NEWS_API_FRONT_PAGE_MAX_HITS: u8 = 50;
+/// Username of the account posting the monthly hiring threads.
+const ALGOLIA_HACKER_NEWS_WHO_IS_HIRING_AUTHOR: &str = "whoishiring";
+/// The account posts three threads per month, so this covers a few months.
+const ALGOLIA_HACKER_NEWS_API_WHO_IS_HIRING_MAX_HITS: u8 = 10;
+const ALGOLIA_HACKER_NEWS_API_SEARCH_RESULTS_MAX_HITS: u8 = 50;
 
 /// The internal Algolia Hacker News API client.
 ///
@@ -20,17 +30,34 @@ pub struct AlgoliaHnClient {
     base_url: &'static str,
     /// `reqwest`client.
     client: Client,
+    /// Offline mode: every request fails.
+    offline: bool,
+    /// Timeout of each request.
+    timeout: Duration,
 }
 
 impl AlgoliaHnClient {
-    pub fn new() -> Result<Self> {
+    pub fn new(offline: bool, timeout: Duration) -> Result<Self> {
         Ok(Self {
             base_url: ALGOLIA_HACKER_NEWS_API_BASE_URL,
-            // TODO: duration from CLI args and/or local configuration
-            client: Client::builder().timeout(Duration::from_secs(10)).build()?,
+            client: Client::builder().build()?,
+            offline,
+            timeout,
         })
     }
 
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Prepare a GET request, unless in offline mode.
+    fn request(&self, url: String) -> Result<RequestBuilder> {
+        if self.offline {
+            return Err(HnCliError::OfflineError);
+        }
+        Ok(self.client.get(url).timeout(self.timeout))
+    }
+
     /// Perform a full-text query search with (optionally) filtering tags that will combine as AND.
     ///
     /// Returns the most recent Hacker News items first.
@@ -63,8 +90,7 @@ impl AlgoliaHnClient {
 
         // request
         let result: AlgoliaHnStoriesHits = self
-            .client
-            .get(url)
+            .request(url)?
             .send()
             .await?
             .text()
@@ -85,8 +111,7 @@ impl AlgoliaHnClient {
         );
 
         let result: AlgoliaHnCommentsHits = self
-            .client
-            .get(url)
+            .request(url)?
             .send()
             .await?
             .text()
@@ -110,8 +135,7 @@ impl AlgoliaHnClient {
         );
 
         let result: AlgoliaHnStoriesHits = self
-            .client
-            .get(url)
+            .request(url)?
             .send()
             .await?
             .text()
@@ -124,4 +148,91 @@ impl AlgoliaHnClient {
 
         Ok(result)
     }
+
+    /// Fetch the stories having reached the front page, and created in the given
+    /// `[from, to[` Unix timestamps interval.
+    ///
+    /// Returns the most popular stories first.
+    pub async fn search_front_page_stories(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<AlgoliaHnStoriesHits> {
+        let url = format!(
+            "{}/search?hitsPerPage={}&tags={}&numericFilters={},{}",
+            self.base_url,
+            ALGOLIA_HACKER_NEWS_API_FRONT_PAGE_MAX_HITS,
+            AlgoliaHnSearchTag::FrontPage.to_query(),
+            AlgoliaHnNumericFilter::CreatedAt
+                .condition(AlgoliaHnNumericOperator::GreaterOrEqual, from),
+            AlgoliaHnNumericFilter::CreatedAt.condition(AlgoliaHnNumericOperator::Lower, to),
+        );
+
+        let result: AlgoliaHnStoriesHits = self
+            .request(url)?
+            .send()
+            .await?
+            .text()
+            .await
+            .map(|raw| {
+                serde_json::from_str(&raw)
+                    .expect("api.algolia.search_front_page_stories: deserialization should work")
+            })
+            .map_err(HnCliError::HttpError)?;
+
+        Ok(result)
+    }
+
+    /// Fetch the latest threads posted by the monthly "Who is hiring?" account, most recent first.
+    ///
+    /// NB: this includes the "Who wants to be hired?" and "Freelancer?" threads.
+    pub async fn search_who_is_hiring_stories(&self) -> Result<AlgoliaHnStoriesHits> {
+        let url = format!(
+            "{}/search_by_date?hitsPerPage={}&tags=story,{}",
+            self.base_url,
+            ALGOLIA_HACKER_NEWS_API_WHO_IS_HIRING_MAX_HITS,
+            AlgoliaHnSearchTag::AuthorUsername(ALGOLIA_HACKER_NEWS_WHO_IS_HIRING_AUTHOR.into())
+                .to_query()
+        );
+
+        let result: AlgoliaHnStoriesHits = self
+            .request(url)?
+            .send()
+            .await?
+            .text()
+            .await
+            .map(|raw| {
+                serde_json::from_str(&raw)
+                    .expect("api.algolia.search_who_is_hiring_stories: deserialization should work")
+            })
+            .map_err(HnCliError::HttpError)?;
+
+        Ok(result)
+    }
+
+    /// Perform a full-text query search on Hacker News stories, most relevant first.
+    pub async fn search_stories_by_relevance(&self, query: &str) -> Result<AlgoliaHnStoriesHits> {
+        let encoded_query: String = form_urlencoded::byte_serialize(query.as_bytes()).collect();
+        let url = format!(
+            "{}/search?hitsPerPage={}&query={}&tags={}",
+            self.base_url,
+            ALGOLIA_HACKER_NEWS_API_SEARCH_RESULTS_MAX_HITS,
+            encoded_query,
+            AlgoliaHnSearchTag::Story.to_query()
+        );
+
+        let result: AlgoliaHnStoriesHits = self
+            .request(url)?
+            .send()
+            .await?
+            .text()
+            .await
+            .map(|raw| {
+                serde_json::from_str(&raw)
+                    .expect("api.algolia.search_stories_by_relevance: deserialization should work")
+            })
+            .map_err(HnCliError::HttpError)?;
+
+        Ok(result)
+    }
 }
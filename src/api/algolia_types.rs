@@ -101,3 +101,26 @@ impl AlgoliaHnFilter for AlgoliaHnNumericFilter {
         }
     }
 }
+
+impl AlgoliaHnNumericFilter {
+    /// Build the query fragment for the given condition on this numerical field.
+    pub fn condition(&self, operator: AlgoliaHnNumericOperator, value: i64) -> String {
+        format!("{}{}{}", self.to_query(), operator.to_query(), value)
+    }
+}
+
+/// The comparison operators supported by the Algolia numeric filters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlgoliaHnNumericOperator {
+    Lower,
+    GreaterOrEqual,
+}
+
+impl AlgoliaHnFilter for AlgoliaHnNumericOperator {
+    fn to_query(&self) -> String {
+        match self {
+            Self::Lower => "<".into(),
+            Self::GreaterOrEqual => ">=".into(),
+        }
+    }
+}
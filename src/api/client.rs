@@ -2,7 +2,8 @@ use std::{collections::HashMap, time::Duration};
 
 use async_recursion::async_recursion;
 use futures::future::join_all;
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
 
 use crate::errors::{HnCliError, Result};
 
@@ -10,7 +11,7 @@ use super::types::{HnDead, HnDeleted, HnItem, HnItemIdScalar, HnUser};
 
 const HACKER_NEWS_API_BASE_URL: &str = "https://hacker-news.firebaseio.com/v0";
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HnStoriesSorting {
     New,
     Top,
@@ -30,6 +31,14 @@ impl HnStoriesSorting {
             Best => "beststories",
         }
     }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::Top => "Top",
+            Self::Best => "Best",
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -67,6 +76,10 @@ pub struct ClassicHnClient {
     base_url: &'static str,
     /// `reqwest` client.
     client: Client,
+    /// Offline mode: every request fails.
+    offline: bool,
+    /// Timeout of each request.
+    timeout: Duration,
 }
 
 /// Flat storage structure for a comments thread.
@@ -77,14 +90,27 @@ pub type HnStoredItemCommentsIds = HashMap<HnItemIdScalar, ()>;
 
 // TODO: timeouts should be logged and not panic in every case except first ever request (how to track?)
 impl ClassicHnClient {
-    pub fn new() -> Result<Self> {
+    pub fn new(offline: bool, timeout: Duration) -> Result<Self> {
         Ok(Self {
             base_url: HACKER_NEWS_API_BASE_URL,
-            // TODO: duration from CLI args and/or local configuration
-            client: Client::builder().timeout(Duration::from_secs(10)).build()?,
+            client: Client::builder().build()?,
+            offline,
+            timeout,
         })
     }
 
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Prepare a GET request, unless in offline mode.
+    fn request(&self, url: String) -> Result<RequestBuilder> {
+        if self.offline {
+            return Err(HnCliError::OfflineError);
+        }
+        Ok(self.client.get(url).timeout(self.timeout))
+    }
+
     /// Try to fetch user data from its **case-sensitive** ID (the username).
     ///
     /// NB: as per the [documentation](https://github.com/HackerNews/API#users),
@@ -93,12 +119,11 @@ impl ClassicHnClient {
     /// In such a case, we return the error `HnCliError::UserNotFound`.
     pub async fn get_user_data(&self, username: &str) -> Result<HnUser> {
         let raw = self
-            .client
-            .get(format!(
+            .request(format!(
                 "{}/{}.json",
                 self.base_url,
                 get_user_data_resource(username)
-            ))
+            ))?
             .send()
             .await?
             .text()
@@ -138,8 +163,7 @@ impl ClassicHnClient {
         &self,
         sorting: &HnStoriesSorting,
     ) -> Result<Vec<HnItemIdScalar>> {
-        self.client
-            .get(format!("{}/{}.json", self.base_url, sorting.get_resource()))
+        self.request(format!("{}/{}.json", self.base_url, sorting.get_resource()))?
             .send()
             .await?
             .json()
@@ -152,8 +176,7 @@ impl ClassicHnClient {
         &self,
         section: &HnStoriesSections,
     ) -> Result<Vec<HnItemIdScalar>> {
-        self.client
-            .get(format!("{}/{}.json", self.base_url, section.get_resource()))
+        self.request(format!("{}/{}.json", self.base_url, section.get_resource()))?
             .send()
             .await?
             .json()
@@ -218,8 +241,7 @@ impl ClassicHnClient {
 
     /// Try to fetch the `HnItem` by its given ID.
     pub async fn get_item(&self, id: HnItemIdScalar) -> Result<HnItem> {
-        self.client
-            .get(format!("{}/item/{}.json", self.base_url, id))
+        self.request(format!("{}/item/{}.json", self.base_url, id))?
             .send()
             .await?
             .text()
@@ -262,8 +284,7 @@ impl ClassicHnClient {
 
     /// Try to fetch the ID of the latest `HnItem` inserted into the Firebase store.
     pub async fn get_max_item_id(&self) -> Result<HnItemIdScalar> {
-        self.client
-            .get(format!("{}/maxitem.json", self.base_url))
+        self.request(format!("{}/maxitem.json", self.base_url))?
             .send()
             .await?
             .json()
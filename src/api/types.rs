@@ -52,6 +52,31 @@ impl HnItem {
         }
     }
 
+    /// Get the score of the item, if applicable.
+    pub fn get_score(&self) -> u32 {
+        use HnItem::*;
+
+        match self {
+            Story(story) => story.score,
+            Comment(comment) => comment.score.unwrap_or(0),
+            Job(job) => job.score,
+            Poll(poll) => poll.score,
+            PollOpt(poll_option) => poll_option.score,
+            Null | Deleted(_) | Dead(_) => 0,
+        }
+    }
+
+    /// Get the total comments count of the item, if applicable.
+    pub fn get_descendants(&self) -> u32 {
+        use HnItem::*;
+
+        match self {
+            Story(story) => story.descendants,
+            Poll(poll) => poll.descendants,
+            Null | Comment(_) | Job(_) | PollOpt(_) | Deleted(_) | Dead(_) => 0,
+        }
+    }
+
     /// Get the `kids`, if any, of the item.
     pub fn get_kids(&self) -> Option<&[HnItemIdScalar]> {
         use HnItem::*;
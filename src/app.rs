@@ -1,23 +1,30 @@
 use std::collections::HashMap;
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::layout::Rect;
 
 use crate::{
-    api::client::HnStoriesSections,
+    api::{client::HnStoriesSections, types::HnItemIdScalar},
     config::AppConfiguration,
+    errors::Result,
     ui::{
         common::UiComponentId,
+        displayable_item::DisplayableHackerNewsItem,
+        flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
         handlers::{ApplicationAction, InputsController},
         router::{AppRoute, AppRouter},
         screens::{Screen, ScreenComponentsRegistry, ScreenEventResponse},
-        theme::UiTheme,
+        theme::UiThemePalette,
     },
 };
 
-use self::{history::AppHistory, state::AppState};
+use self::{
+    history::{AppHistory, BrowsedItem},
+    state::AppState,
+};
 
 pub mod history;
+pub mod killfile;
 pub mod state;
 
 /// Interact with application state from the components.
@@ -49,7 +56,7 @@ impl<'a> AppContext<'a> {
     }
 
     /// Shorthand for `get_config().get_theme()`.
-    pub fn get_theme(&self) -> &UiTheme {
+    pub fn get_theme(&self) -> &UiThemePalette {
         self.config.get_theme()
     }
 
@@ -57,6 +64,29 @@ impl<'a> AppContext<'a> {
         self.history
     }
 
+    pub fn get_history_mut(&mut self) -> &mut AppHistory {
+        self.history
+    }
+
+    /// Switch to another configuration profile, created if needed, along with its history.
+    pub fn switch_profile(&mut self, profile: &str) -> Result<()> {
+        if profile == self.config.get_profile() {
+            return Ok(());
+        }
+        let config = self.config.switch_to_profile(profile)?;
+        self.history.persist();
+        *self.history = AppHistory::restored(profile);
+        *self.config = config;
+        self.state
+            .set_main_stories_sorting(self.config.get_default_stories_sorting());
+        self.state.set_flash_message(FlashMessage::new(
+            format!("Switched to the \"{profile}\" profile."),
+            FlashMessageType::Info,
+            FLASH_MESSAGE_DEFAULT_DURATION_MS,
+        ));
+        Ok(())
+    }
+
     pub fn get_inputs(&self) -> &InputsController {
         self.inputs
     }
@@ -67,10 +97,45 @@ impl<'a> AppContext<'a> {
 
     /// Push a new navigation route state.
     pub fn router_push_navigation_stack(&mut self, route: AppRoute) {
+        self.record_browsed_item(&route);
         self.router.push_navigation_stack(route);
         self.update_screen();
     }
 
+    /// Record the story, comment or user profile opened by the given route, if any, in the browsing history.
+    fn record_browsed_item(&mut self, route: &AppRoute) {
+        let item = match route {
+            AppRoute::ItemDetails(item) => BrowsedItem::Story {
+                id: item.id,
+                title: item.title.clone().unwrap_or_default(),
+            },
+            AppRoute::ItemNestedComments(comment) => {
+                let Some(story) = self.state.get_currently_viewed_item() else {
+                    return;
+                };
+                BrowsedItem::Comment {
+                    id: comment.id,
+                    by_username: comment.by_username.clone(),
+                    story_id: story.id,
+                    story_title: story.title.clone().unwrap_or_default(),
+                    comments_chain: self
+                        .state
+                        .get_currently_viewed_item_comments_chain()
+                        .to_vec(),
+                }
+            }
+            AppRoute::UserProfile(username) => BrowsedItem::UserProfile {
+                username: username.clone(),
+            },
+            _ => return,
+        };
+        self.history.record_browsed_item(
+            item,
+            self.config.get_browsing_history_max_entries(),
+            self.config.get_browsing_history_retention_days(),
+        );
+    }
+
     /// Go to the previous navigation route state.
     pub fn router_pop_navigation_stack(&mut self) -> Option<AppRoute> {
         let previous = self.router.pop_navigation_stack();
@@ -85,7 +150,12 @@ impl<'a> AppContext<'a> {
         &mut self,
         route: AppRoute,
     ) -> Option<AppRoute> {
-        if route.is_settings() || route.is_help() || route.is_search_help() {
+        if route.is_settings()
+            || route.is_help()
+            || route.is_search_help()
+            || route.is_browsing_history()
+            || route.is_bookmarks()
+        {
             self.router.push_navigation_stack(route);
             self.update_screen();
             None
@@ -97,12 +167,54 @@ impl<'a> AppContext<'a> {
         }
     }
 
+    /// Jump to a comment of the currently viewed item, given its chain starting at its top-level comment.
+    ///
+    /// The nested comments routes are replaced by the ones of the comment's parents, without
+    /// going through the screens mounting hooks since the viewed item stays the same.
+    pub fn router_jump_to_comment(
+        &mut self,
+        parent_comments: Vec<DisplayableHackerNewsItem>,
+        comments_chain: Vec<HnItemIdScalar>,
+    ) {
+        let previous_route = self.router.get_current_route().clone();
+        while matches!(
+            self.router.get_current_route(),
+            AppRoute::ItemNestedComments(_)
+        ) {
+            self.router.pop_navigation_stack();
+        }
+        for parent_comment in parent_comments {
+            self.router
+                .push_navigation_stack(AppRoute::ItemNestedComments(parent_comment));
+        }
+        if self.router.get_current_route() != &previous_route {
+            *self.screen =
+                AppRouter::build_screen_from_route(self.router.get_current_route().clone());
+        }
+
+        self.state
+            .set_comment_to_focus_id(comments_chain.last().copied());
+        self.state
+            .set_currently_viewed_item_comments_chain(comments_chain);
+    }
+
     fn update_screen(&mut self) {
         *self.screen = AppRouter::build_screen_from_route(self.router.get_current_route().clone());
         self.screen.before_mount(self.state, self.config);
     }
 }
 
+/// Initial navigation of the application, as requested from the command-line.
+#[derive(Debug)]
+pub struct AppStartup {
+    /// Stories section of the home screen.
+    pub section: HnStoriesSections,
+    /// Screen to open on top of the home screen, if any.
+    pub route: Option<AppRoute>,
+    /// Is the network access disabled?
+    pub offline: bool,
+}
+
 /// Global application.
 #[derive(Debug)]
 pub struct App {
@@ -128,21 +240,64 @@ pub struct App {
 }
 
 impl App {
-    pub fn new(config: AppConfiguration) -> Self {
+    pub fn new(config: AppConfiguration, startup: AppStartup) -> Self {
         let mut state = AppState::from_config(&config);
-        let initial_route = AppRoute::Home(HnStoriesSections::Home);
+        if let Some(key_bindings_error) = config.get_key_bindings_error() {
+            state.set_flash_message(FlashMessage::new(
+                format!("Invalid key bindings, using the preset ones: {key_bindings_error}"),
+                FlashMessageType::Warning,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        } else if let Some(theme_error) = config.get_theme_error() {
+            state.set_flash_message(FlashMessage::new(
+                format!("Invalid custom theme, using the default one: {theme_error}"),
+                FlashMessageType::Warning,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        } else if let Some(killfile_error) = config.get_killfile_error() {
+            state.set_flash_message(FlashMessage::new(
+                format!("Invalid killfile, ignoring it: {killfile_error}"),
+                FlashMessageType::Warning,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        } else if let Some(options_error) = config.get_options_error() {
+            state.set_flash_message(FlashMessage::new(
+                format!("Invalid options, using their default values: {options_error}"),
+                FlashMessageType::Warning,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        } else if let Some(environment_error) = config.get_environment_overrides_error() {
+            state.set_flash_message(FlashMessage::new(
+                format!("Invalid environment variables, ignoring them: {environment_error}"),
+                FlashMessageType::Warning,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        } else if startup.offline {
+            state.set_flash_message(FlashMessage::new(
+                "Offline mode: Hacker News cannot be reached.",
+                FlashMessageType::Info,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        }
+        let initial_route = AppRoute::Home(startup.section);
         let (router, current_screen) = AppRouter::new(initial_route, &mut state, &config);
-        let history = AppHistory::restored();
+        let history = AppHistory::restored(config.get_profile());
+        let inputs = InputsController::new(config.get_key_bindings().clone());
 
-        Self {
+        let mut app = Self {
             state,
             router,
             config,
             history,
             current_screen,
-            inputs: InputsController::new(),
+            inputs,
             layout_components: HashMap::new(),
+        };
+        // the home screen stays below, to go back to
+        if let Some(route) = startup.route {
+            app.get_context().router_push_navigation_stack(route);
         }
+        app
     }
 
     /// Get the context handle allowing components to interact with the application.
@@ -157,16 +312,88 @@ impl App {
         }
     }
 
+    /// Apply the changes made to the config file while running, if any.
+    ///
+    /// Flashes a warning if the new config file is invalid, the current configuration being kept.
+    pub fn reload_config_if_modified(&mut self) {
+        let (message, message_type) = match self.config.reload_from_file_if_modified() {
+            Ok(false) => return,
+            Ok(true) => {
+                self.inputs
+                    .set_key_bindings(self.config.get_key_bindings().clone());
+                if let Some(key_bindings_error) = self.config.get_key_bindings_error() {
+                    (
+                        format!(
+                            "Configuration reloaded, invalid key bindings: {key_bindings_error}"
+                        ),
+                        FlashMessageType::Warning,
+                    )
+                } else if let Some(theme_error) = self.config.get_theme_error() {
+                    (
+                        format!("Configuration reloaded, invalid custom theme: {theme_error}"),
+                        FlashMessageType::Warning,
+                    )
+                } else if let Some(killfile_error) = self.config.get_killfile_error() {
+                    (
+                        format!("Configuration reloaded, invalid killfile: {killfile_error}"),
+                        FlashMessageType::Warning,
+                    )
+                } else if let Some(options_error) = self.config.get_options_error() {
+                    (
+                        format!("Configuration reloaded, invalid options: {options_error}"),
+                        FlashMessageType::Warning,
+                    )
+                } else {
+                    ("Configuration reloaded.".into(), FlashMessageType::Info)
+                }
+            }
+            Err(why) => (
+                format!("Invalid configuration file, keeping the current one. {why}"),
+                FlashMessageType::Warning,
+            ),
+        };
+        self.state.set_flash_message(FlashMessage::new(
+            message,
+            message_type,
+            FLASH_MESSAGE_DEFAULT_DURATION_MS,
+        ));
+    }
+
+    /// Persist the history, along with the comment being read if any, *e.g.* when quitting.
+    pub fn persist_history(&mut self) {
+        if let Some(item) = self.state.get_currently_viewed_item() {
+            self.history.persist_comments_chain_for_story(
+                item.id,
+                self.state.get_currently_viewed_item_comments_chain(),
+            );
+        }
+        self.history.persist();
+    }
+
+    /// Apply the key bindings changed from the settings screen, if any.
+    pub fn refresh_key_bindings(&mut self) {
+        if self.inputs.get_key_bindings() != self.config.get_key_bindings() {
+            self.inputs
+                .set_key_bindings(self.config.get_key_bindings().clone());
+        }
+    }
+
     /// Inject an event to be processed into `InputsController`.
     pub fn pump_event(&mut self, event: KeyEvent) {
         self.inputs.pump_event(event, &self.state);
     }
 
+    /// Pump a mouse event, returning false if it is to be ignored.
+    pub fn pump_mouse_event(&mut self, event: MouseEvent) -> bool {
+        self.inputs.pump_mouse_event(event)
+    }
+
     /// Handle inputs, at the application level. Returns true if
     /// the active event is to be captured (swallowed) and not passed down to screens.
     pub fn handle_inputs(&mut self) -> bool {
-        // global help page toggle (not in search)
+        // global help page toggle (not in search nor when typing)
         if !self.router.get_current_route().is_in_search_mode()
+            && !self.state.is_text_input_active()
             && self.inputs.is_active(&ApplicationAction::ToggleHelp)
         {
             if self.router.get_current_route().is_help() {
@@ -1,16 +1,18 @@
 use std::{
-    collections::HashMap,
-    fs::{create_dir_all, read_to_string, write},
+    collections::{HashMap, HashSet},
+    fs::{create_dir_all, read_to_string},
+    hash::Hash,
     path::PathBuf,
 };
 
-use chrono::{DateTime, Utc, serde::ts_seconds};
+use chrono::{DateTime, Duration, Utc, serde::ts_seconds};
 use log::warn;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     api::types::HnItemIdScalar,
-    config::get_project_os_directory,
+    archive::ImportMode,
+    config::{FileLock, get_profile_directory, write_atomically},
     errors::{HnCliError, Result},
 };
 
@@ -21,11 +23,212 @@ pub struct TopLevelCommentHistoryData {
     top_level_comment_id: HnItemIdScalar,
 }
 
-/// TODO: support more than top-level comments (would also need refactoring elsewhere)
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct CommentsChainHistoryData {
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+    /// The successive IDs of the viewed comment, starting at its top-level comment.
+    comments_chain: Vec<HnItemIdScalar>,
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ReadItemHistoryData {
+    /// When the item was last opened.
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct HiddenItemHistoryData {
+    /// When the item was hidden.
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct WatchedItemHistoryData {
+    /// When the item started to be watched.
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+}
+
+/// Performance of a watched story at a given time.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemSnapshot {
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+    score: u32,
+    comments_count: u32,
+    /// Rank of the story on the front page (top stories), if listed.
+    rank: Option<usize>,
+}
+
+impl ItemSnapshot {
+    pub fn get_score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn get_comments_count(&self) -> u32 {
+        self.comments_count
+    }
+
+    pub fn get_rank(&self) -> Option<usize> {
+        self.rank
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemSnapshotsHistoryData {
+    /// The successive snapshots of the item, oldest first.
+    snapshots: Vec<ItemSnapshot>,
+}
+
+impl ItemSnapshotsHistoryData {
+    fn get_latest_datetime(&self) -> DateTime<Utc> {
+        self.snapshots
+            .last()
+            .map_or(DateTime::<Utc>::MIN_UTC, |snapshot| snapshot.datetime)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SeenCommentsHistoryData {
+    /// When the thread was last visited.
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+    /// Highest comment ID seen in the thread, comment IDs being increasing in time.
+    latest_comment_id: HnItemIdScalar,
+}
+
+/// A story, comment or user profile opened by the user.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BrowsedItem {
+    Story {
+        id: HnItemIdScalar,
+        title: String,
+    },
+    /// A comment whose sub-comments were viewed.
+    Comment {
+        id: HnItemIdScalar,
+        by_username: String,
+        story_id: HnItemIdScalar,
+        story_title: String,
+        /// The successive IDs of the comment parents, starting at its top-level comment, and of the comment itself.
+        comments_chain: Vec<HnItemIdScalar>,
+    },
+    UserProfile {
+        username: String,
+    },
+}
+
+/// Identifies a browsed story, comment or user profile, regardless of its details.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum BrowsedItemKey {
+    Item(HnItemIdScalar),
+    UserProfile(String),
+}
+
+impl BrowsedItem {
+    fn get_key(&self) -> BrowsedItemKey {
+        match self {
+            Self::Story { id, .. } | Self::Comment { id, .. } => BrowsedItemKey::Item(*id),
+            Self::UserProfile { username } => BrowsedItemKey::UserProfile(username.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrowsingHistoryEntry {
+    /// When the item was last opened.
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+    item: BrowsedItem,
+}
+
+impl BrowsingHistoryEntry {
+    pub fn get_datetime(&self) -> &DateTime<Utc> {
+        &self.datetime
+    }
+
+    pub fn get_item(&self) -> &BrowsedItem {
+        &self.item
+    }
+}
+
+/// A story or comment saved for later by the user.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookmarkedItem {
+    Story {
+        id: HnItemIdScalar,
+        title: String,
+    },
+    Comment {
+        id: HnItemIdScalar,
+        by_username: String,
+        story_id: HnItemIdScalar,
+        story_title: String,
+        /// The successive IDs of the comment parents, starting at its top-level comment, and of the comment itself.
+        comments_chain: Vec<HnItemIdScalar>,
+    },
+}
+
+impl BookmarkedItem {
+    pub fn get_id(&self) -> HnItemIdScalar {
+        match self {
+            Self::Story { id, .. } | Self::Comment { id, .. } => *id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    /// When the item was bookmarked.
+    #[serde(with = "ts_seconds")]
+    datetime: DateTime<Utc>,
+    item: BookmarkedItem,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    notes: String,
+}
+
+impl Bookmark {
+    pub fn get_datetime(&self) -> &DateTime<Utc> {
+        &self.datetime
+    }
+
+    pub fn get_item(&self) -> &BookmarkedItem {
+        &self.item
+    }
+
+    pub fn get_tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn get_notes(&self) -> &str {
+        &self.notes
+    }
+
+    /// Parse comma-separated tags, ignoring the empty and duplicated ones.
+    pub fn parse_tags(raw_tags: &str) -> Vec<String> {
+        let mut tags: Vec<String> = vec![];
+        for tag in raw_tags.split(',').map(|tag| tag.trim().to_lowercase()) {
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum SynchronizedHistoryItem {
     /// Saves the navigation state of a top-level comment for a given Item thread.
+    ///
+    /// Only read from the history files written before `CommentsChain` was introduced.
     TopLevelComment(TopLevelCommentHistoryData),
+    /// Saves the navigation state of a (possibly nested) comment for a given Item thread.
+    CommentsChain(CommentsChainHistoryData),
 }
 
 impl SynchronizedHistoryItem {
@@ -33,35 +236,55 @@ impl SynchronizedHistoryItem {
     fn get_timestamp(&self) -> &DateTime<Utc> {
         match self {
             Self::TopLevelComment(data) => &data.datetime,
+            Self::CommentsChain(data) => &data.datetime,
         }
     }
 
-    /// Get the stored ID corresponding to the saved navigation state.
-    fn get_value(&self) -> HnItemIdScalar {
+    /// Get the stored comments chain corresponding to the saved navigation state.
+    fn get_value(&self) -> Vec<HnItemIdScalar> {
         match self {
-            Self::TopLevelComment(data) => data.top_level_comment_id,
-        }
-    }
-
-    /// Update the stored ID corresponding to the saved navigation state.
-    fn set_value(&mut self, id: HnItemIdScalar) {
-        match self {
-            Self::TopLevelComment(data) => {
-                data.datetime = Utc::now();
-                data.top_level_comment_id = id;
-            }
+            Self::TopLevelComment(data) => vec![data.top_level_comment_id],
+            Self::CommentsChain(data) => data.comments_chain.clone(),
         }
     }
 }
 
 type SynchronizedHistoryItemStorage = HashMap<HnItemIdScalar, SynchronizedHistoryItem>;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SynchronizedHistory {
-    /// Stores the latest focused top-level comment for a given Hacker News item.
+    /// Stores the latest focused comment, with its parents, for a given Hacker News item.
     ///
     /// Also keeps track of the insertion datetime to enforce hard limits on the history size.
     latest_top_level_comments_per_item_map: SynchronizedHistoryItemStorage,
+    /// Stores the IDs of the "Who is hiring?" postings marked as seen, oldest first.
+    #[serde(default)]
+    seen_job_postings: Vec<HnItemIdScalar>,
+    /// Stores the stories opened by the user, *i.e.* read, with the datetime of their latest opening.
+    #[serde(default)]
+    read_items: HashMap<HnItemIdScalar, ReadItemHistoryData>,
+    /// Stores, for each visited thread, the latest comment seen by the user.
+    #[serde(default)]
+    seen_comments_per_item: HashMap<HnItemIdScalar, SeenCommentsHistoryData>,
+    /// Stores the stories, comments and user profiles opened by the user, oldest first.
+    ///
+    /// Its limits are configurable, and thus enforced when recording a new entry.
+    #[serde(default)]
+    browsing_history: Vec<BrowsingHistoryEntry>,
+    /// Stores the stories and comments bookmarked by the user, oldest first.
+    ///
+    /// Never limited, since explicitly managed by the user.
+    #[serde(default)]
+    bookmarks: Vec<Bookmark>,
+    /// Stores the stories manually hidden by the user, with the datetime of their hiding.
+    #[serde(default)]
+    hidden_items: HashMap<HnItemIdScalar, HiddenItemHistoryData>,
+    /// Stores the stories explicitly watched by the user, bookmarked stories being watched anyway.
+    #[serde(default)]
+    watched_items: HashMap<HnItemIdScalar, WatchedItemHistoryData>,
+    /// Stores the score, comments count and front page rank snapshots of the watched stories.
+    #[serde(default)]
+    items_snapshots: HashMap<HnItemIdScalar, ItemSnapshotsHistoryData>,
 }
 
 impl SynchronizedHistory {
@@ -70,6 +293,14 @@ impl SynchronizedHistory {
             latest_top_level_comments_per_item_map: SynchronizedHistoryItemStorage::with_capacity(
                 SYNCHRONIZED_HISTORY_ITEMS_LIMIT,
             ),
+            seen_job_postings: vec![],
+            read_items: HashMap::new(),
+            seen_comments_per_item: HashMap::new(),
+            browsing_history: vec![],
+            bookmarks: vec![],
+            hidden_items: HashMap::new(),
+            watched_items: HashMap::new(),
+            items_snapshots: HashMap::new(),
         }
     }
 
@@ -148,15 +379,47 @@ impl SynchronizedHistory {
             &self.latest_top_level_comments_per_item_map,
             SYNCHRONIZED_HISTORY_ITEMS_LIMIT,
         );
+        let seen_job_postings_overflow = self
+            .seen_job_postings
+            .len()
+            .saturating_sub(SYNCHRONIZED_HISTORY_SEEN_JOB_POSTINGS_LIMIT);
         let limited_synchronized_history = Self {
             latest_top_level_comments_per_item_map: limited_latest_top_level_comments_per_item_map,
+            seen_job_postings: self.seen_job_postings[seen_job_postings_overflow..].to_vec(),
+            read_items: Self::enforced_latest_entries_limit(
+                &self.read_items,
+                SYNCHRONIZED_HISTORY_READ_ITEMS_LIMIT,
+                |data| data.datetime,
+            ),
+            seen_comments_per_item: Self::enforced_latest_entries_limit(
+                &self.seen_comments_per_item,
+                SYNCHRONIZED_HISTORY_SEEN_COMMENTS_LIMIT,
+                |data| data.datetime,
+            ),
+            browsing_history: self.browsing_history.clone(),
+            bookmarks: self.bookmarks.clone(),
+            hidden_items: Self::enforced_latest_entries_limit(
+                &self.hidden_items,
+                SYNCHRONIZED_HISTORY_HIDDEN_ITEMS_LIMIT,
+                |data| data.datetime,
+            ),
+            watched_items: Self::enforced_latest_entries_limit(
+                &self.watched_items,
+                SYNCHRONIZED_HISTORY_WATCHED_ITEMS_LIMIT,
+                |data| data.datetime,
+            ),
+            items_snapshots: Self::enforced_latest_entries_limit(
+                &self.items_snapshots,
+                SYNCHRONIZED_HISTORY_SNAPSHOTTED_ITEMS_LIMIT,
+                ItemSnapshotsHistoryData::get_latest_datetime,
+            ),
         };
 
         let history_raw = serde_json::to_string(&limited_synchronized_history).map_err(|err| {
             HnCliError::HistorySynchronizationError(format!("cannot serialize history: {err}"))
         })?;
 
-        write(&history_filepath, history_raw).map_err(|err| {
+        write_atomically(&history_filepath, &history_raw).map_err(|err| {
             HnCliError::HistorySynchronizationError(format!(
                 "cannot save history file ({:?}): {}",
                 history_filepath.display(),
@@ -165,6 +428,94 @@ impl SynchronizedHistory {
         })
     }
 
+    /// Merge the history written in the meantime by other hncli instances into this one.
+    ///
+    /// Given the `base` history this one was last synchronized with, the entries changed
+    /// (or removed) here since then win over the ones of the written `theirs` history.
+    fn merged_with(&self, base: &Self, theirs: Self) -> Self {
+        let by_id = |ids: &[HnItemIdScalar]| -> HashMap<HnItemIdScalar, ()> {
+            ids.iter().map(|id| (*id, ())).collect()
+        };
+        let merged_seen_job_postings = merged_entries(
+            &by_id(&base.seen_job_postings),
+            &by_id(&self.seen_job_postings),
+            by_id(&theirs.seen_job_postings),
+        );
+        // theirs are the oldest ones, since ours were not written yet
+        let mut seen_job_postings: Vec<HnItemIdScalar> = vec![];
+        for id in theirs
+            .seen_job_postings
+            .iter()
+            .chain(&self.seen_job_postings)
+        {
+            if merged_seen_job_postings.contains_key(id) && !seen_job_postings.contains(id) {
+                seen_job_postings.push(*id);
+            }
+        }
+
+        let by_browsed_item = |entries: &[BrowsingHistoryEntry]| -> HashMap<_, _> {
+            entries
+                .iter()
+                .map(|entry| (entry.item.get_key(), entry.clone()))
+                .collect()
+        };
+        let mut browsing_history: Vec<_> = merged_entries(
+            &by_browsed_item(&base.browsing_history),
+            &by_browsed_item(&self.browsing_history),
+            by_browsed_item(&theirs.browsing_history),
+        )
+        .into_values()
+        .collect();
+        browsing_history.sort_by_key(|entry| entry.datetime);
+
+        let by_bookmarked_item = |bookmarks: &[Bookmark]| -> HashMap<_, _> {
+            bookmarks
+                .iter()
+                .map(|bookmark| (bookmark.item.get_id(), bookmark.clone()))
+                .collect()
+        };
+        let mut bookmarks: Vec<_> = merged_entries(
+            &by_bookmarked_item(&base.bookmarks),
+            &by_bookmarked_item(&self.bookmarks),
+            by_bookmarked_item(&theirs.bookmarks),
+        )
+        .into_values()
+        .collect();
+        bookmarks.sort_by_key(|bookmark| bookmark.datetime);
+
+        Self {
+            latest_top_level_comments_per_item_map: merged_entries(
+                &base.latest_top_level_comments_per_item_map,
+                &self.latest_top_level_comments_per_item_map,
+                theirs.latest_top_level_comments_per_item_map,
+            ),
+            seen_job_postings,
+            read_items: merged_entries(&base.read_items, &self.read_items, theirs.read_items),
+            seen_comments_per_item: merged_entries(
+                &base.seen_comments_per_item,
+                &self.seen_comments_per_item,
+                theirs.seen_comments_per_item,
+            ),
+            browsing_history,
+            bookmarks,
+            hidden_items: merged_entries(
+                &base.hidden_items,
+                &self.hidden_items,
+                theirs.hidden_items,
+            ),
+            watched_items: merged_entries(
+                &base.watched_items,
+                &self.watched_items,
+                theirs.watched_items,
+            ),
+            items_snapshots: merged_entries(
+                &base.items_snapshots,
+                &self.items_snapshots,
+                theirs.items_snapshots,
+            ),
+        }
+    }
+
     /// Enforce an arbitrary items count limit on the stored navigation data.
     fn enforced_history_limit(
         storage: &SynchronizedHistoryItemStorage,
@@ -181,11 +532,82 @@ impl SynchronizedHistory {
         }
         limited_storage
     }
+
+    /// Keep only the most recent entries, according to the given datetime getter.
+    fn enforced_latest_entries_limit<T, F>(
+        entries: &HashMap<HnItemIdScalar, T>,
+        limit: usize,
+        get_datetime: F,
+    ) -> HashMap<HnItemIdScalar, T>
+    where
+        T: Clone,
+        F: Fn(&T) -> DateTime<Utc>,
+    {
+        let mut sorted_entries: Vec<_> = entries.iter().collect();
+        sorted_entries.sort_by_key(|(_, data)| std::cmp::Reverse(get_datetime(data)));
+        sorted_entries
+            .into_iter()
+            .take(limit)
+            .map(|(id, data)| (*id, data.clone()))
+            .collect()
+    }
+}
+
+/// Three-way merge of keyed entries: the ones changed in `ours` since `base` are kept,
+/// and the other ones are taken from `theirs`.
+fn merged_entries<K, V>(
+    base: &HashMap<K, V>,
+    ours: &HashMap<K, V>,
+    mut theirs: HashMap<K, V>,
+) -> HashMap<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone + PartialEq,
+{
+    let changed_keys: HashSet<&K> = base
+        .keys()
+        .chain(ours.keys())
+        .filter(|key| ours.get(*key) != base.get(*key))
+        .collect();
+    for key in changed_keys {
+        match ours.get(key) {
+            Some(value) => theirs.insert(key.clone(), value.clone()),
+            None => theirs.remove(key),
+        };
+    }
+    theirs
 }
 
 /// Maximum number of entries that will be kept in the history file.
 pub const SYNCHRONIZED_HISTORY_ITEMS_LIMIT: usize = 500;
 
+/// Maximum number of seen "Who is hiring?" postings that will be kept in the history file.
+///
+/// A monthly thread usually counts a few hundred postings.
+pub const SYNCHRONIZED_HISTORY_SEEN_JOB_POSTINGS_LIMIT: usize = 3000;
+
+/// Maximum number of read stories that will be kept in the history file.
+pub const SYNCHRONIZED_HISTORY_READ_ITEMS_LIMIT: usize = 5000;
+
+/// Maximum number of visited threads for which the latest seen comment will be kept in the history file.
+pub const SYNCHRONIZED_HISTORY_SEEN_COMMENTS_LIMIT: usize = 1000;
+
+/// Maximum number of manually hidden stories that will be kept in the history file.
+pub const SYNCHRONIZED_HISTORY_HIDDEN_ITEMS_LIMIT: usize = 5000;
+
+/// Maximum number of explicitly watched stories that will be kept in the history file.
+pub const SYNCHRONIZED_HISTORY_WATCHED_ITEMS_LIMIT: usize = 200;
+
+/// Maximum number of stories whose snapshots will be kept in the history file.
+pub const SYNCHRONIZED_HISTORY_SNAPSHOTTED_ITEMS_LIMIT: usize = 200;
+
+/// Minimum duration between two snapshots of a watched story.
+const ITEM_SNAPSHOTS_INTERVAL_MINUTES: i64 = 15;
+
+/// Duration after the first snapshot of a watched story beyond which it is not snapshotted anymore,
+/// stories hardly moving after a few days.
+const ITEM_SNAPSHOTS_TRACKING_DAYS: i64 = 7;
+
 /// Responsible for restoring navigation state in the application from previous sessions.
 #[derive(Debug)]
 pub struct AppHistory {
@@ -193,81 +615,394 @@ pub struct AppHistory {
     ///
     /// Reading must be done at application startup, and writing as rarely as possible.
     synchronized: SynchronizedHistory,
+    /// The synchronized history as last read from or written to the history file,
+    /// to only merge our own changes with the ones of the other hncli instances.
+    synchronized_base: SynchronizedHistory,
+    /// Path of the history file of the current profile, if it can be determined.
+    file_path: Option<PathBuf>,
 }
 
 impl AppHistory {
-    pub fn restored() -> Self {
-        match Self::get_history_file_path() {
-            Ok(history_file_path) => Self {
-                synchronized: SynchronizedHistory::read_from_json_file(history_file_path),
-            },
+    /// Restore the history of the given configuration profile.
+    pub fn restored(profile: &str) -> Self {
+        match Self::get_history_file_path(profile) {
+            Ok(history_file_path) => {
+                let synchronized =
+                    SynchronizedHistory::read_from_json_file(history_file_path.clone());
+                Self {
+                    synchronized_base: synchronized.clone(),
+                    synchronized,
+                    file_path: Some(history_file_path),
+                }
+            }
             Err(why) => {
                 warn!(
                     "History: cannot retrieve OS filepath for history.json (reading history): {why}"
                 );
                 Self {
                     synchronized: SynchronizedHistory::empty(),
+                    synchronized_base: SynchronizedHistory::empty(),
+                    file_path: None,
                 }
             }
         }
     }
 
-    /// Persist the history in OS-dependent JSON storage.
+    /// Persist the history in OS-dependent JSON storage, merged with the changes
+    /// persisted in the meantime by the other hncli instances.
     ///
     /// Should not be called too often for performance reasons.
-    pub fn persist(&self) {
-        match Self::get_history_file_path() {
-            Ok(history_filepath) => match self.synchronized.write_to_json_file(history_filepath) {
-                Ok(()) => (),
-                Err(why) => {
-                    warn!("History.persist error: {why}");
-                }
-            },
-            Err(why) => {
-                warn!(
-                    "History: cannot retrieve OS filepath for history.json (writing history): {why}"
-                );
+    pub fn persist(&mut self) {
+        let Some(history_filepath) = self.file_path.clone() else {
+            warn!("History: cannot retrieve OS filepath for history.json (writing history)");
+            return;
+        };
+        if let Err(why) = self.merge_and_write(history_filepath) {
+            warn!("History.persist error: {why}");
+        }
+    }
+
+    /// Get the file-synchronized part of the history, to export it.
+    pub fn get_synchronized(&self) -> &SynchronizedHistory {
+        &self.synchronized
+    }
+
+    /// Import a history exported by another hncli installation, either merged into
+    /// the current one or replacing it, and persist it.
+    ///
+    /// When merging, the imported entries take precedence over the existing ones.
+    pub fn import(&mut self, imported: SynchronizedHistory, mode: ImportMode) -> Result<()> {
+        let history_filepath = self.file_path.clone().ok_or_else(|| {
+            HnCliError::HistorySynchronizationError("cannot get the history file path".into())
+        })?;
+        self.synchronized = match mode {
+            // compared to an empty base, every imported entry counts as changed
+            ImportMode::Merge => {
+                imported.merged_with(&SynchronizedHistory::empty(), self.synchronized.clone())
             }
+            ImportMode::Replace => imported,
+        };
+        self.merge_and_write(history_filepath)
+    }
+
+    fn merge_and_write(&mut self, history_filepath: PathBuf) -> Result<()> {
+        if let Some(history_directory) = history_filepath.parent() {
+            create_dir_all(history_directory).map_err(|err| {
+                HnCliError::HistorySynchronizationError(format!(
+                    "cannot create history directory ({}): {}",
+                    history_directory.display(),
+                    err
+                ))
+            })?;
         }
+        let _lock = FileLock::acquire(&history_filepath).map_err(|err| {
+            HnCliError::HistorySynchronizationError(format!(
+                "cannot lock history file ({}): {}",
+                history_filepath.display(),
+                err
+            ))
+        })?;
+
+        let theirs = SynchronizedHistory::read_from_json_file(history_filepath.clone());
+        let merged = self
+            .synchronized
+            .merged_with(&self.synchronized_base, theirs);
+        merged.write_to_json_file(history_filepath)?;
+        self.synchronized_base = merged.clone();
+        self.synchronized = merged;
+        Ok(())
     }
 
-    pub fn persist_top_level_comment_id_for_story(
+    /// Save the successive IDs of the viewed comment for the given story, starting at its top-level comment.
+    pub fn persist_comments_chain_for_story(
         &mut self,
         story_id: HnItemIdScalar,
-        top_level_comment_id: HnItemIdScalar,
+        comments_chain: &[HnItemIdScalar],
     ) {
-        if let Some(entry) = self
-            .synchronized
+        if comments_chain.is_empty() {
+            return;
+        }
+        self.synchronized
             .latest_top_level_comments_per_item_map
-            .get_mut(&story_id)
-        {
-            entry.set_value(top_level_comment_id);
-        } else {
-            self.synchronized
-                .latest_top_level_comments_per_item_map
-                .insert(
-                    story_id,
-                    SynchronizedHistoryItem::TopLevelComment(TopLevelCommentHistoryData {
-                        top_level_comment_id,
-                        datetime: Utc::now(),
-                    }),
-                );
-        };
+            .insert(
+                story_id,
+                SynchronizedHistoryItem::CommentsChain(CommentsChainHistoryData {
+                    datetime: Utc::now(),
+                    comments_chain: comments_chain.to_vec(),
+                }),
+            );
     }
 
-    pub fn restored_top_level_comment_id_for_story(
+    /// Get the successive IDs of the latest viewed comment for the given story, starting at its top-level comment.
+    pub fn restored_comments_chain_for_story(
         &self,
         story_id: HnItemIdScalar,
-    ) -> Option<HnItemIdScalar> {
+    ) -> Option<Vec<HnItemIdScalar>> {
         self.synchronized
             .latest_top_level_comments_per_item_map
             .get(&story_id)
             .map(|entry| entry.get_value())
     }
 
-    fn get_history_file_path() -> Result<PathBuf> {
-        let project_os_directory = get_project_os_directory()?;
-        Ok(project_os_directory.join("history.json"))
+    pub fn is_job_posting_seen(&self, posting_id: HnItemIdScalar) -> bool {
+        self.synchronized.seen_job_postings.contains(&posting_id)
+    }
+
+    /// Mark the given "Who is hiring?" posting as seen, or as not seen if it already was.
+    pub fn toggle_job_posting_seen(&mut self, posting_id: HnItemIdScalar) {
+        let seen_job_postings = &mut self.synchronized.seen_job_postings;
+        if let Some(index) = seen_job_postings.iter().position(|id| *id == posting_id) {
+            seen_job_postings.remove(index);
+        } else {
+            seen_job_postings.push(posting_id);
+        }
+    }
+
+    pub fn is_item_read(&self, item_id: HnItemIdScalar) -> bool {
+        self.synchronized.read_items.contains_key(&item_id)
+    }
+
+    /// Mark the given story as read, now.
+    pub fn mark_item_as_read(&mut self, item_id: HnItemIdScalar) {
+        self.synchronized.read_items.insert(
+            item_id,
+            ReadItemHistoryData {
+                datetime: Utc::now(),
+            },
+        );
+    }
+
+    pub fn is_item_hidden(&self, item_id: HnItemIdScalar) -> bool {
+        self.synchronized.hidden_items.contains_key(&item_id)
+    }
+
+    /// Hide the given story, or show it back if it already was hidden, and returns true if now hidden.
+    pub fn toggle_item_hidden(&mut self, item_id: HnItemIdScalar) -> bool {
+        let hidden_items = &mut self.synchronized.hidden_items;
+        if hidden_items.remove(&item_id).is_some() {
+            false
+        } else {
+            hidden_items.insert(
+                item_id,
+                HiddenItemHistoryData {
+                    datetime: Utc::now(),
+                },
+            );
+            true
+        }
+    }
+
+    /// Is the given story watched, either explicitly or by being bookmarked?
+    pub fn is_item_watched(&self, item_id: HnItemIdScalar) -> bool {
+        self.synchronized.watched_items.contains_key(&item_id)
+            || self.synchronized.bookmarks.iter().any(|bookmark| {
+                matches!(bookmark.item, BookmarkedItem::Story { id, .. } if id == item_id)
+            })
+    }
+
+    /// Watch the given story, or stop watching it if it already was explicitly watched, and returns true if now watched.
+    ///
+    /// NB: a bookmarked story stays watched.
+    pub fn toggle_item_watched(&mut self, item_id: HnItemIdScalar) -> bool {
+        let watched_items = &mut self.synchronized.watched_items;
+        if watched_items.remove(&item_id).is_none() {
+            watched_items.insert(
+                item_id,
+                WatchedItemHistoryData {
+                    datetime: Utc::now(),
+                },
+            );
+        }
+        self.is_item_watched(item_id)
+    }
+
+    /// Get the IDs of the watched stories due for a snapshot, *i.e.* not snapshotted
+    /// recently, but still within their tracking period.
+    pub fn get_items_to_snapshot(&self) -> Vec<HnItemIdScalar> {
+        let now = Utc::now();
+        let mut watched_ids: Vec<HnItemIdScalar> =
+            self.synchronized
+                .watched_items
+                .keys()
+                .copied()
+                .chain(self.synchronized.bookmarks.iter().filter_map(
+                    |bookmark| match bookmark.item {
+                        BookmarkedItem::Story { id, .. } => Some(id),
+                        BookmarkedItem::Comment { .. } => None,
+                    },
+                ))
+                .collect();
+        watched_ids.sort_unstable();
+        watched_ids.dedup();
+        watched_ids.retain(|id| {
+            let snapshots = self.get_item_snapshots(*id);
+            match (snapshots.first(), snapshots.last()) {
+                (Some(first), Some(latest)) => {
+                    now - first.datetime < Duration::days(ITEM_SNAPSHOTS_TRACKING_DAYS)
+                        && now - latest.datetime
+                            >= Duration::minutes(ITEM_SNAPSHOTS_INTERVAL_MINUTES)
+                }
+                _ => true,
+            }
+        });
+        watched_ids
+    }
+
+    /// Record the current score, comments count and front page rank of the given story.
+    pub fn record_item_snapshot(
+        &mut self,
+        item_id: HnItemIdScalar,
+        score: u32,
+        comments_count: u32,
+        rank: Option<usize>,
+    ) {
+        self.synchronized
+            .items_snapshots
+            .entry(item_id)
+            .or_insert_with(|| ItemSnapshotsHistoryData { snapshots: vec![] })
+            .snapshots
+            .push(ItemSnapshot {
+                datetime: Utc::now(),
+                score,
+                comments_count,
+                rank,
+            });
+    }
+
+    /// Get the snapshots of the given story, oldest first.
+    pub fn get_item_snapshots(&self, item_id: HnItemIdScalar) -> &[ItemSnapshot] {
+        self.synchronized
+            .items_snapshots
+            .get(&item_id)
+            .map_or(&[], |data| &data.snapshots)
+    }
+
+    /// Get the ID of the latest comment seen in the given thread, if it was ever visited.
+    pub fn get_latest_seen_comment_id(&self, item_id: HnItemIdScalar) -> Option<HnItemIdScalar> {
+        self.synchronized
+            .seen_comments_per_item
+            .get(&item_id)
+            .map(|data| data.latest_comment_id)
+    }
+
+    /// Mark the comments of the given thread, up to the given comment ID, as seen.
+    pub fn mark_comments_as_seen(
+        &mut self,
+        item_id: HnItemIdScalar,
+        latest_comment_id: HnItemIdScalar,
+    ) {
+        let latest_comment_id = self
+            .get_latest_seen_comment_id(item_id)
+            .map_or(latest_comment_id, |id| id.max(latest_comment_id));
+        self.synchronized.seen_comments_per_item.insert(
+            item_id,
+            SeenCommentsHistoryData {
+                datetime: Utc::now(),
+                latest_comment_id,
+            },
+        );
+    }
+
+    /// Get the browsing history, oldest first.
+    pub fn get_browsing_history(&self) -> &[BrowsingHistoryEntry] {
+        &self.synchronized.browsing_history
+    }
+
+    /// Record the given item as opened now in the browsing history, enforcing its limits.
+    pub fn record_browsed_item(
+        &mut self,
+        item: BrowsedItem,
+        max_entries: usize,
+        retention_days: u64,
+    ) {
+        let browsing_history = &mut self.synchronized.browsing_history;
+        browsing_history.retain(|entry| entry.item.get_key() != item.get_key());
+        browsing_history.push(BrowsingHistoryEntry {
+            datetime: Utc::now(),
+            item,
+        });
+        self.enforce_browsing_history_limits(max_entries, retention_days);
+    }
+
+    /// Forget about the oldest entries of the browsing history, beyond the given limits.
+    pub fn enforce_browsing_history_limits(&mut self, max_entries: usize, retention_days: u64) {
+        let browsing_history = &mut self.synchronized.browsing_history;
+        let oldest_datetime = Utc::now() - Duration::days(retention_days as i64);
+        browsing_history.retain(|entry| entry.datetime >= oldest_datetime);
+        let overflow = browsing_history.len().saturating_sub(max_entries);
+        browsing_history.drain(..overflow);
+    }
+
+    /// Get the bookmarks, oldest first.
+    pub fn get_bookmarks(&self) -> &[Bookmark] {
+        &self.synchronized.bookmarks
+    }
+
+    pub fn is_bookmarked(&self, item_id: HnItemIdScalar) -> bool {
+        self.synchronized
+            .bookmarks
+            .iter()
+            .any(|bookmark| bookmark.item.get_id() == item_id)
+    }
+
+    /// Bookmark the given item, or remove its bookmark if it already was. Returns true if now bookmarked.
+    pub fn toggle_bookmark(&mut self, item: BookmarkedItem) -> bool {
+        let item_id = item.get_id();
+        if self.is_bookmarked(item_id) {
+            self.remove_bookmark(item_id);
+            false
+        } else {
+            self.synchronized.bookmarks.push(Bookmark {
+                datetime: Utc::now(),
+                item,
+                tags: vec![],
+                notes: String::new(),
+            });
+            true
+        }
+    }
+
+    pub fn remove_bookmark(&mut self, item_id: HnItemIdScalar) {
+        self.synchronized
+            .bookmarks
+            .retain(|bookmark| bookmark.item.get_id() != item_id);
+    }
+
+    pub fn set_bookmark_tags(&mut self, item_id: HnItemIdScalar, tags: Vec<String>) {
+        if let Some(bookmark) = self.get_bookmark_mut(item_id) {
+            bookmark.tags = tags;
+        }
+    }
+
+    pub fn set_bookmark_notes(&mut self, item_id: HnItemIdScalar, notes: String) {
+        if let Some(bookmark) = self.get_bookmark_mut(item_id) {
+            bookmark.notes = notes;
+        }
+    }
+
+    /// Get all the tags used by the bookmarks, alphabetically sorted.
+    pub fn get_bookmarks_tags(&self) -> Vec<&str> {
+        let mut tags: Vec<&str> = self
+            .synchronized
+            .bookmarks
+            .iter()
+            .flat_map(|bookmark| bookmark.tags.iter().map(String::as_str))
+            .collect();
+        tags.sort_unstable();
+        tags.dedup();
+        tags
+    }
+
+    fn get_bookmark_mut(&mut self, item_id: HnItemIdScalar) -> Option<&mut Bookmark> {
+        self.synchronized
+            .bookmarks
+            .iter_mut()
+            .find(|bookmark| bookmark.item.get_id() == item_id)
+    }
+
+    fn get_history_file_path(profile: &str) -> Result<PathBuf> {
+        let profile_directory = get_profile_directory(profile)?;
+        Ok(profile_directory.join("history.json"))
     }
 }
 
@@ -278,30 +1013,326 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_simple_item_top_level_persist_comment_id_scenario() {
+    fn test_simple_item_persist_comments_chain_scenario() {
         let mut history = AppHistory {
             synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
         };
 
-        // viewed item 1 and left while focused on comment ID 123
-        history.persist_top_level_comment_id_for_story(1, 123);
+        // viewed item 1 and left while focused on top-level comment ID 123
+        history.persist_comments_chain_for_story(1, &[123]);
 
-        // viewed item 2 and left while focused on comment ID 456
-        history.persist_top_level_comment_id_for_story(2, 456);
+        // viewed item 2 and quit while focused on the nested comment ID 458
+        history.persist_comments_chain_for_story(2, &[456, 457, 458]);
 
         // viewed item 1 again, left while focused on comment ID 1230
-        history.persist_top_level_comment_id_for_story(1, 1230);
+        history.persist_comments_chain_for_story(1, &[1230]);
+
+        // nothing to restore without any focused comment
+        history.persist_comments_chain_for_story(3, &[]);
 
         // basic assertions
         assert_eq!(
-            history.restored_top_level_comment_id_for_story(1),
-            Some(1230)
+            history.restored_comments_chain_for_story(1),
+            Some(vec![1230])
+        );
+        assert_eq!(
+            history.restored_comments_chain_for_story(2),
+            Some(vec![456, 457, 458])
         );
+        assert_eq!(history.restored_comments_chain_for_story(3), None);
+    }
+
+    #[test]
+    fn test_legacy_top_level_comment_history_restoring() {
+        let history = AppHistory {
+            synchronized: serde_json::from_str(
+                r#"{"latest_top_level_comments_per_item_map":{"1":{"TopLevelComment":{"datetime":1700000000,"top_level_comment_id":123}}}}"#,
+            )
+            .unwrap(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
         assert_eq!(
-            history.restored_top_level_comment_id_for_story(2),
-            Some(456)
+            history.restored_comments_chain_for_story(1),
+            Some(vec![123])
+        );
+    }
+
+    #[test]
+    fn test_job_postings_seen_toggling() {
+        let mut history = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+
+        history.toggle_job_posting_seen(10);
+        history.toggle_job_posting_seen(20);
+        assert!(history.is_job_posting_seen(10));
+        assert!(history.is_job_posting_seen(20));
+
+        history.toggle_job_posting_seen(10);
+        assert!(!history.is_job_posting_seen(10));
+        assert!(history.is_job_posting_seen(20));
+    }
+
+    #[test]
+    fn test_read_items() {
+        let mut history = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+
+        history.mark_item_as_read(1);
+        history.mark_item_as_read(2);
+        assert!(history.is_item_read(1));
+        assert!(history.is_item_read(2));
+        assert!(!history.is_item_read(3));
+
+        let mut read_items = HashMap::new();
+        for (id, days_ago) in [(10, 3), (20, 1), (30, 2)] {
+            read_items.insert(
+                id,
+                ReadItemHistoryData {
+                    datetime: Utc::now() - Duration::days(days_ago),
+                },
+            );
+        }
+        let limited_read_items =
+            SynchronizedHistory::enforced_latest_entries_limit(&read_items, 2, |data| {
+                data.datetime
+            });
+        assert_eq!(limited_read_items.len(), 2);
+        assert!(limited_read_items.contains_key(&20));
+        assert!(limited_read_items.contains_key(&30));
+    }
+
+    #[test]
+    fn test_hidden_items() {
+        let mut history = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+
+        assert!(history.toggle_item_hidden(1));
+        assert!(history.toggle_item_hidden(2));
+        assert!(history.is_item_hidden(1));
+        assert!(!history.toggle_item_hidden(1));
+        assert!(!history.is_item_hidden(1));
+        assert!(history.is_item_hidden(2));
+    }
+
+    #[test]
+    fn test_items_snapshots() {
+        let mut history = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+
+        assert!(history.toggle_item_watched(1));
+        history.toggle_bookmark(BookmarkedItem::Story {
+            id: 2,
+            title: "Story 2".into(),
+        });
+        assert!(history.is_item_watched(2));
+        // bookmarked stories stay watched
+        assert!(history.toggle_item_watched(2));
+        assert!(history.toggle_item_watched(2));
+        assert!(!history.is_item_watched(3));
+        assert_eq!(history.get_items_to_snapshot(), vec![1, 2]);
+
+        history.record_item_snapshot(1, 10, 2, Some(30));
+        history.record_item_snapshot(2, 5, 0, None);
+        // snapshotted too recently
+        assert!(history.get_items_to_snapshot().is_empty());
+
+        let snapshots = &mut history
+            .synchronized
+            .items_snapshots
+            .get_mut(&1)
+            .unwrap()
+            .snapshots;
+        snapshots[0].datetime = Utc::now() - Duration::hours(1);
+        assert_eq!(history.get_items_to_snapshot(), vec![1]);
+        history.record_item_snapshot(1, 42, 12, Some(3));
+        let snapshots = history.get_item_snapshots(1);
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[1].get_score(), 42);
+        assert_eq!(snapshots[1].get_rank(), Some(3));
+
+        // beyond the tracking period
+        let snapshots = &mut history
+            .synchronized
+            .items_snapshots
+            .get_mut(&2)
+            .unwrap()
+            .snapshots;
+        snapshots[0].datetime = Utc::now() - Duration::days(ITEM_SNAPSHOTS_TRACKING_DAYS);
+        assert!(history.get_items_to_snapshot().is_empty());
+
+        assert!(!history.toggle_item_watched(1));
+        assert!(!history.is_item_watched(1));
+        assert!(history.get_item_snapshots(3).is_empty());
+    }
+
+    #[test]
+    fn test_seen_comments() {
+        let mut history = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+
+        assert_eq!(history.get_latest_seen_comment_id(1), None);
+        history.mark_comments_as_seen(1, 120);
+        assert_eq!(history.get_latest_seen_comment_id(1), Some(120));
+        // comments fetched later on are more recent
+        history.mark_comments_as_seen(1, 150);
+        assert_eq!(history.get_latest_seen_comment_id(1), Some(150));
+        // but a partial fetch must not forget about the newer ones
+        history.mark_comments_as_seen(1, 130);
+        assert_eq!(history.get_latest_seen_comment_id(1), Some(150));
+        assert_eq!(history.get_latest_seen_comment_id(2), None);
+    }
+
+    #[test]
+    fn test_browsing_history() {
+        let mut history = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+        let story = |id| BrowsedItem::Story {
+            id,
+            title: format!("Story {id}"),
+        };
+        let user = BrowsedItem::UserProfile {
+            username: "pg".into(),
+        };
+
+        history.record_browsed_item(story(1), 3, 30);
+        history.record_browsed_item(user.clone(), 3, 30);
+        history.record_browsed_item(story(2), 3, 30);
+        // opened again: moved to the most recent entry
+        history.record_browsed_item(story(1), 3, 30);
+        let items: Vec<_> = history
+            .get_browsing_history()
+            .iter()
+            .map(|entry| entry.get_item().clone())
+            .collect();
+        assert_eq!(items, vec![user.clone(), story(2), story(1)]);
+
+        // entries count limit
+        history.record_browsed_item(story(3), 3, 30);
+        assert_eq!(history.get_browsing_history().len(), 3);
+        assert_eq!(history.get_browsing_history()[0].get_item(), &story(2));
+
+        // retention limit
+        history.synchronized.browsing_history[0].datetime = Utc::now() - Duration::days(8);
+        history.enforce_browsing_history_limits(3, 7);
+        assert_eq!(history.get_browsing_history().len(), 2);
+        assert_eq!(history.get_browsing_history()[0].get_item(), &story(1));
+    }
+
+    #[test]
+    fn test_bookmarks() {
+        let mut history = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+        let story = BookmarkedItem::Story {
+            id: 1,
+            title: "Story".into(),
+        };
+        let comment = BookmarkedItem::Comment {
+            id: 3,
+            by_username: "pg".into(),
+            story_id: 1,
+            story_title: "Story".into(),
+            comments_chain: vec![2, 3],
+        };
+
+        assert!(history.toggle_bookmark(story.clone()));
+        assert!(history.toggle_bookmark(comment));
+        assert!(history.is_bookmarked(1));
+        assert!(history.is_bookmarked(3));
+        assert!(!history.is_bookmarked(2));
+
+        history.set_bookmark_tags(1, Bookmark::parse_tags(" Rust, , tui,rust "));
+        history.set_bookmark_tags(3, Bookmark::parse_tags("essays,rust"));
+        history.set_bookmark_notes(3, "read later".into());
+        assert_eq!(history.get_bookmarks()[0].get_tags(), &["rust", "tui"]);
+        assert_eq!(history.get_bookmarks()[1].get_notes(), "read later");
+        assert_eq!(history.get_bookmarks_tags(), vec!["essays", "rust", "tui"]);
+
+        assert!(!history.toggle_bookmark(story));
+        assert!(!history.is_bookmarked(1));
+        history.remove_bookmark(3);
+        assert!(history.get_bookmarks().is_empty());
+    }
+
+    #[test]
+    fn test_merge_with_other_instance_history() {
+        let story = |id| BookmarkedItem::Story {
+            id,
+            title: format!("Story {id}"),
+        };
+        let mut base = AppHistory {
+            synchronized: SynchronizedHistory::empty(),
+            synchronized_base: SynchronizedHistory::empty(),
+            file_path: None,
+        };
+        base.toggle_job_posting_seen(10);
+        base.toggle_job_posting_seen(11);
+        base.toggle_bookmark(story(1));
+        base.toggle_bookmark(story(2));
+        let base = base.synchronized;
+
+        // this instance: unmarks a posting, removes a bookmark and reads a story
+        let mut ours = AppHistory {
+            synchronized: base.clone(),
+            synchronized_base: base.clone(),
+            file_path: None,
+        };
+        ours.toggle_job_posting_seen(10);
+        ours.remove_bookmark(1);
+        ours.mark_item_as_read(100);
+
+        // the other instance, which wrote first: marks a posting, tags and adds bookmarks
+        let mut theirs = AppHistory {
+            synchronized: base.clone(),
+            synchronized_base: base.clone(),
+            file_path: None,
+        };
+        theirs.toggle_job_posting_seen(12);
+        theirs.set_bookmark_tags(2, vec!["rust".into()]);
+        theirs.toggle_bookmark(story(3));
+        theirs.persist_comments_chain_for_story(200, &[201]);
+
+        let merged = ours
+            .synchronized
+            .merged_with(&ours.synchronized_base, theirs.synchronized);
+        assert_eq!(merged.seen_job_postings, vec![11, 12]);
+        let bookmarks: Vec<_> = merged
+            .bookmarks
+            .iter()
+            .map(|bookmark| (bookmark.item.get_id(), bookmark.tags.clone()))
+            .collect();
+        assert!(bookmarks.contains(&(2, vec!["rust".to_string()])));
+        assert!(bookmarks.contains(&(3, vec![])));
+        assert_eq!(bookmarks.len(), 2);
+        assert!(merged.read_items.contains_key(&100));
+        assert!(
+            merged
+                .latest_top_level_comments_per_item_map
+                .contains_key(&200)
         );
-        assert_eq!(history.restored_top_level_comment_id_for_story(3), None);
     }
 
     #[test]
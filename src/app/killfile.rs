@@ -0,0 +1,171 @@
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{HnCliError, Result},
+    ui::displayable_item::DisplayableHackerNewsItem,
+};
+
+/// User-defined rules hiding the matching stories and comments, as written
+/// in the `killfile` table of the config file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KillfileConfiguration {
+    /// Domains of the hidden stories, including their sub-domains.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domains: Vec<String>,
+    /// Users whose stories and comments are hidden.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub usernames: Vec<String>,
+    /// Case-insensitive regular expressions matching the titles of the hidden stories.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub title_patterns: Vec<String>,
+    /// Score below which the stories are hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_score: Option<u32>,
+}
+
+impl KillfileConfiguration {
+    /// Compile the killfile rules.
+    pub fn to_killfile(&self) -> Result<Killfile> {
+        let title_patterns = self
+            .title_patterns
+            .iter()
+            .map(|pattern| {
+                RegexBuilder::new(pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|why| HnCliError::KillfileError(format!("\"{pattern}\": {why}")))
+            })
+            .collect::<Result<_>>()?;
+        let normalized = |values: &[String]| -> Vec<String> {
+            values
+                .iter()
+                .map(|value| value.trim().to_lowercase())
+                .filter(|value| !value.is_empty())
+                .collect()
+        };
+
+        Ok(Killfile {
+            domains: normalized(&self.domains)
+                .into_iter()
+                .map(|domain| domain.trim_start_matches("www.").to_string())
+                .collect(),
+            usernames: normalized(&self.usernames),
+            title_patterns,
+            min_score: self.min_score.unwrap_or(0),
+        })
+    }
+}
+
+/// Compiled killfile rules.
+#[derive(Clone, Debug, Default)]
+pub struct Killfile {
+    domains: Vec<String>,
+    usernames: Vec<String>,
+    title_patterns: Vec<Regex>,
+    min_score: u32,
+}
+
+impl Killfile {
+    /// Does the story (or job posting) match any of the rules?
+    ///
+    /// NB: the score rule does not apply to job postings, which have no meaningful score.
+    pub fn kills_story(&self, story: &DisplayableHackerNewsItem) -> bool {
+        let hostname = story
+            .url_hostname
+            .as_deref()
+            .map(|hostname| hostname.to_lowercase());
+        let title = story.title.as_deref().unwrap_or_default();
+        (!story.is_job && story.score < self.min_score)
+            || self.kills_username(&story.by_username)
+            || hostname.is_some_and(|hostname| {
+                self.domains
+                    .iter()
+                    .any(|domain| hostname == *domain || hostname.ends_with(&format!(".{domain}")))
+            })
+            || self
+                .title_patterns
+                .iter()
+                .any(|pattern| pattern.is_match(title))
+    }
+
+    /// Does the comment match any of the rules?
+    ///
+    /// NB: only the users rule applies, comments having neither title, link nor public score.
+    pub fn kills_comment(&self, comment: &DisplayableHackerNewsItem) -> bool {
+        self.kills_username(&comment.by_username)
+    }
+
+    fn kills_username(&self, username: &str) -> bool {
+        let username = username.to_lowercase();
+        self.usernames.contains(&username)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::displayable_item::DisplayableHackerNewsItem;
+
+    use super::KillfileConfiguration;
+
+    fn build_story(
+        title: &str,
+        by_username: &str,
+        hostname: &str,
+        score: u32,
+    ) -> DisplayableHackerNewsItem {
+        DisplayableHackerNewsItem {
+            by_username: by_username.into(),
+            title: Some(title.into()),
+            text: None,
+            score,
+            url: Some(format!("https://{hostname}/")),
+            url_hostname: Some(hostname.into()),
+            is_comment: false,
+            ..DisplayableHackerNewsItem::test_item(1)
+        }
+    }
+
+    #[test]
+    fn test_killfile_rules() {
+        let configuration: KillfileConfiguration = toml::from_str(
+            r#"
+            domains = ["www.Example.com"]
+            usernames = ["Troll"]
+            title_patterns = ["\\bcrypto", "^Ask HN: Who"]
+            min_score = 10
+            "#,
+        )
+        .unwrap();
+        let killfile = configuration.to_killfile().unwrap();
+
+        assert!(!killfile.kills_story(&build_story("Rust 2.0", "pg", "rust-lang.org", 100)));
+        assert!(killfile.kills_story(&build_story("Rust 2.0", "pg", "rust-lang.org", 9)));
+        assert!(killfile.kills_story(&build_story("Rust 2.0", "troll", "rust-lang.org", 100)));
+        assert!(killfile.kills_story(&build_story("Rust 2.0", "pg", "blog.example.com", 100)));
+        assert!(killfile.kills_story(&build_story("Rust 2.0", "pg", "example.com", 100)));
+        assert!(!killfile.kills_story(&build_story("Rust 2.0", "pg", "notexample.com", 100)));
+        assert!(killfile.kills_story(&build_story(
+            "Why Crypto Failed",
+            "pg",
+            "rust-lang.org",
+            100
+        )));
+        assert!(!killfile.kills_story(&build_story("Encryptor", "pg", "rust-lang.org", 100)));
+
+        let mut job = build_story("Acme is hiring", "acme", "acme.com", 1);
+        job.is_job = true;
+        assert!(!killfile.kills_story(&job));
+        job.url_hostname = Some("jobs.example.com".into());
+        assert!(killfile.kills_story(&job));
+
+        let mut comment = build_story("", "TROLL", "", 0);
+        comment.is_comment = true;
+        assert!(killfile.kills_comment(&comment));
+        comment.by_username = "pg".into();
+        assert!(!killfile.kills_comment(&comment));
+
+        let invalid: KillfileConfiguration = toml::from_str("title_patterns = [\"(\"]").unwrap();
+        assert!(invalid.to_killfile().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use chrono::{Days, NaiveDate, Utc};
 use futures::lock::Mutex;
 
 use crate::{
@@ -11,8 +12,15 @@ use crate::{
     config::AppConfiguration,
     ui::{
         common::UiComponentId,
-        components::{stories::STORIES_PANEL_ID, widgets::text_input::TextInputState},
-        displayable_item::{DisplayableHackerNewsItem, DisplayableHackerNewsItemComments},
+        components::{
+            bookmarks::{BookmarkField, BookmarksSorting},
+            stories::STORIES_PANEL_ID,
+            widgets::text_input::TextInputState,
+        },
+        displayable_item::{
+            DisplayableHackerNewsItem, DisplayableHackerNewsItemComments,
+            job_posting::{DisplayableJobPosting, JobPostingsFilterField, JobPostingsFilters},
+        },
         flash::FlashMessage,
         screens::search::SearchScreenPart,
     },
@@ -31,6 +39,40 @@ pub struct AppState {
     main_stories_section: HnStoriesSections,
     /// Main screen(s): current stories sorting.
     main_stories_sorting: HnStoriesSorting,
+    /// Main screen(s): hide the already read stories?
+    main_stories_hide_read: bool,
+    /// Main screen(s): applied stories filter.
+    main_stories_filter: String,
+    /// Main screen(s): stories filter being typed in, if currently editing.
+    main_stories_filter_input: Option<TextInputState>,
+    /// Main screen(s): currently selected story, if any.
+    main_stories_selected_story: Option<DisplayableHackerNewsItem>,
+    /// Reveal the stories and comments hidden by the killfile or by the user?
+    reveal_hidden_items: bool,
+    /// Past front page screen: currently viewed (UTC) day.
+    past_front_page_day: NaiveDate,
+    /// Past front page screen: day being typed in, if currently editing.
+    past_front_page_day_input: Option<TextInputState>,
+    /// Who is hiring screen: active postings filters.
+    who_is_hiring_filters: JobPostingsFilters,
+    /// Who is hiring screen: filter being typed in, if currently editing.
+    who_is_hiring_filter_input: Option<(JobPostingsFilterField, TextInputState)>,
+    /// Who is hiring screen: currently selected posting, if any.
+    who_is_hiring_selected_posting: Option<DisplayableJobPosting>,
+    /// Browsing history screen: applied filter.
+    browsing_history_filter: String,
+    /// Browsing history screen: filter being typed in, if currently editing.
+    browsing_history_filter_input: Option<TextInputState>,
+    /// Bookmarks screen: tag the bookmarks are filtered by, if any.
+    bookmarks_tag_filter: Option<String>,
+    /// Bookmarks screen: order of the bookmarks.
+    bookmarks_sorting: BookmarksSorting,
+    /// Bookmarks screen: field of the given bookmark being typed in, if currently editing.
+    bookmark_input: Option<(HnItemIdScalar, BookmarkField, TextInputState)>,
+    /// Settings screen: value being typed in, if currently editing.
+    settings_value_input: Option<TextInputState>,
+    /// Settings screen: has the typed-in value been submitted, to be applied by the settings component?
+    settings_value_input_submitted: bool,
     /// The currently viewed item (not a comment).
     currently_viewed_item: Option<DisplayableHackerNewsItem>,
     /// Has the currently viewed item (not a comment) changed recently?
@@ -41,8 +83,16 @@ pub struct AppState {
     currently_viewed_item_comments_chain: Vec<HnItemIdScalar>,
     /// The ID of the comment to restore when coming back from a sub-comment.
     previously_viewed_comment_id: Option<HnItemIdScalar>,
+    /// The ID of the comment to focus after jumping to it across the thread, if any.
+    comment_to_focus_id: Option<HnItemIdScalar>,
+    /// The ID of the currently viewed item, and of its latest comment seen on the previous visit if any.
+    ///
+    /// Captured when opening the item, so that newer comments stay marked as new during the visit.
+    currently_viewed_item_previous_visit: Option<(HnItemIdScalar, Option<HnItemIdScalar>)>,
     /// Item details screen: is the comments panel visible or not.
     item_page_display_comments_panel: bool,
+    /// Item details screen: are the comments displayed as a whole tree, instead of one at a time?
+    item_page_comments_tree_view: bool,
     /// The currently viewed user ID.
     currently_viewed_user_id: Option<String>,
     /// The current Hacker News Algolia search state.
@@ -61,13 +111,36 @@ impl AppState {
             latest_interacted_with_component: Some(STORIES_PANEL_ID),
             main_stories_loading: true,
             main_stories_section: HnStoriesSections::Home,
-            main_stories_sorting: HnStoriesSorting::Top,
+            main_stories_sorting: config.get_default_stories_sorting(),
+            main_stories_hide_read: false,
+            main_stories_filter: String::new(),
+            main_stories_filter_input: None,
+            main_stories_selected_story: None,
+            reveal_hidden_items: false,
+            past_front_page_day: Utc::now()
+                .date_naive()
+                .checked_sub_days(Days::new(1))
+                .expect("AppState: yesterday should be a valid date"),
+            past_front_page_day_input: None,
+            who_is_hiring_filters: JobPostingsFilters::default(),
+            who_is_hiring_filter_input: None,
+            who_is_hiring_selected_posting: None,
+            browsing_history_filter: String::new(),
+            browsing_history_filter_input: None,
+            bookmarks_tag_filter: None,
+            bookmarks_sorting: BookmarksSorting::default(),
+            bookmark_input: None,
+            settings_value_input: None,
+            settings_value_input_submitted: false,
             currently_viewed_item: None,
             currently_viewed_item_switched: false,
             currently_viewed_item_comments: Arc::new(Mutex::new(None)),
             currently_viewed_item_comments_chain: vec![],
             previously_viewed_comment_id: None,
+            comment_to_focus_id: None,
+            currently_viewed_item_previous_visit: None,
             item_page_display_comments_panel: config.get_display_comments_panel_by_default(),
+            item_page_comments_tree_view: false,
             currently_viewed_user_id: None,
             current_algolia_query_state: TextInputState::default(),
             currently_used_algolia_part: SearchScreenPart::Input,
@@ -103,6 +176,63 @@ impl AppState {
         self.main_stories_sorting = sorting;
     }
 
+    /// Get the hide the already read stories boolean for the main screen.
+    pub fn get_main_stories_hide_read(&self) -> bool {
+        self.main_stories_hide_read
+    }
+
+    /// Set the hide the already read stories boolean for the main screen.
+    pub fn set_main_stories_hide_read(&mut self, hide_read: bool) {
+        self.main_stories_hide_read = hide_read;
+    }
+
+    /// Get the stories filter of the main screen(s), including while typing it in.
+    pub fn get_main_stories_filter(&self) -> &str {
+        self.main_stories_filter_input
+            .as_ref()
+            .map_or(&self.main_stories_filter, |input| input.get_value())
+    }
+
+    /// Set the applied stories filter of the main screen(s).
+    pub fn set_main_stories_filter(&mut self, filter: String) {
+        self.main_stories_filter = filter;
+    }
+
+    /// Get the stories filter being typed in on the main screen(s), if currently editing.
+    pub fn get_main_stories_filter_input(&self) -> Option<&TextInputState> {
+        self.main_stories_filter_input.as_ref()
+    }
+
+    /// Mutably get the stories filter being typed in on the main screen(s), if currently editing.
+    pub fn get_main_stories_filter_input_mut(&mut self) -> Option<&mut TextInputState> {
+        self.main_stories_filter_input.as_mut()
+    }
+
+    /// Start (with `Some`) or stop (with `None`) editing the stories filter on the main screen(s).
+    pub fn set_main_stories_filter_input(&mut self, input: Option<TextInputState>) {
+        self.main_stories_filter_input = input;
+    }
+
+    /// Get the currently selected story of the main screen(s), if any.
+    pub fn get_main_stories_selected_story(&self) -> Option<&DisplayableHackerNewsItem> {
+        self.main_stories_selected_story.as_ref()
+    }
+
+    /// Set the currently selected story of the main screen(s).
+    pub fn set_main_stories_selected_story(&mut self, story: Option<DisplayableHackerNewsItem>) {
+        self.main_stories_selected_story = story;
+    }
+
+    /// Are the stories and comments hidden by the killfile or by the user revealed?
+    pub fn get_reveal_hidden_items(&self) -> bool {
+        self.reveal_hidden_items
+    }
+
+    /// Reveal, or hide back, the stories and comments hidden by the killfile or by the user.
+    pub fn set_reveal_hidden_items(&mut self, reveal: bool) {
+        self.reveal_hidden_items = reveal;
+    }
+
     /// Get the current stories section for the main screen.
     pub fn get_main_stories_section(&self) -> &HnStoriesSections {
         &self.main_stories_section
@@ -113,6 +243,172 @@ impl AppState {
         self.main_stories_section = section;
     }
 
+    /// Get the currently viewed day on the past front page screen.
+    pub fn get_past_front_page_day(&self) -> &NaiveDate {
+        &self.past_front_page_day
+    }
+
+    /// Set the currently viewed day on the past front page screen.
+    pub fn set_past_front_page_day(&mut self, day: NaiveDate) {
+        self.past_front_page_day = day;
+    }
+
+    /// Get the day being typed in on the past front page screen, if currently editing.
+    pub fn get_past_front_page_day_input(&self) -> Option<&TextInputState> {
+        self.past_front_page_day_input.as_ref()
+    }
+
+    /// Mutably get the day being typed in on the past front page screen, if currently editing.
+    pub fn get_past_front_page_day_input_mut(&mut self) -> Option<&mut TextInputState> {
+        self.past_front_page_day_input.as_mut()
+    }
+
+    /// Start (with `Some`) or stop (with `None`) editing the day on the past front page screen.
+    pub fn set_past_front_page_day_input(&mut self, input: Option<TextInputState>) {
+        self.past_front_page_day_input = input;
+    }
+
+    /// Get the active postings filters on the who is hiring screen.
+    pub fn get_who_is_hiring_filters(&self) -> &JobPostingsFilters {
+        &self.who_is_hiring_filters
+    }
+
+    /// Mutably get the active postings filters on the who is hiring screen.
+    pub fn get_who_is_hiring_filters_mut(&mut self) -> &mut JobPostingsFilters {
+        &mut self.who_is_hiring_filters
+    }
+
+    /// Get the filter being typed in on the who is hiring screen, if currently editing.
+    pub fn get_who_is_hiring_filter_input(
+        &self,
+    ) -> Option<&(JobPostingsFilterField, TextInputState)> {
+        self.who_is_hiring_filter_input.as_ref()
+    }
+
+    /// Mutably get the filter being typed in on the who is hiring screen, if currently editing.
+    pub fn get_who_is_hiring_filter_input_mut(
+        &mut self,
+    ) -> Option<&mut (JobPostingsFilterField, TextInputState)> {
+        self.who_is_hiring_filter_input.as_mut()
+    }
+
+    /// Start (with `Some`) or stop (with `None`) editing a filter on the who is hiring screen.
+    pub fn set_who_is_hiring_filter_input(
+        &mut self,
+        input: Option<(JobPostingsFilterField, TextInputState)>,
+    ) {
+        self.who_is_hiring_filter_input = input;
+    }
+
+    /// Get the currently selected posting on the who is hiring screen.
+    pub fn get_who_is_hiring_selected_posting(&self) -> Option<&DisplayableJobPosting> {
+        self.who_is_hiring_selected_posting.as_ref()
+    }
+
+    /// Set the currently selected posting on the who is hiring screen.
+    pub fn set_who_is_hiring_selected_posting(&mut self, posting: Option<DisplayableJobPosting>) {
+        self.who_is_hiring_selected_posting = posting;
+    }
+
+    /// Get the filter of the browsing history screen, including while typing it in.
+    pub fn get_browsing_history_filter(&self) -> &str {
+        self.browsing_history_filter_input
+            .as_ref()
+            .map_or(&self.browsing_history_filter, |input| input.get_value())
+    }
+
+    /// Set the applied filter of the browsing history screen.
+    pub fn set_browsing_history_filter(&mut self, filter: String) {
+        self.browsing_history_filter = filter;
+    }
+
+    /// Get the filter being typed in on the browsing history screen, if currently editing.
+    pub fn get_browsing_history_filter_input(&self) -> Option<&TextInputState> {
+        self.browsing_history_filter_input.as_ref()
+    }
+
+    /// Mutably get the filter being typed in on the browsing history screen, if currently editing.
+    pub fn get_browsing_history_filter_input_mut(&mut self) -> Option<&mut TextInputState> {
+        self.browsing_history_filter_input.as_mut()
+    }
+
+    /// Start (with `Some`) or stop (with `None`) editing the filter on the browsing history screen.
+    pub fn set_browsing_history_filter_input(&mut self, input: Option<TextInputState>) {
+        self.browsing_history_filter_input = input;
+    }
+
+    pub fn get_bookmarks_tag_filter(&self) -> Option<&String> {
+        self.bookmarks_tag_filter.as_ref()
+    }
+
+    pub fn set_bookmarks_tag_filter(&mut self, tag: Option<String>) {
+        self.bookmarks_tag_filter = tag;
+    }
+
+    pub fn get_bookmarks_sorting(&self) -> &BookmarksSorting {
+        &self.bookmarks_sorting
+    }
+
+    pub fn set_bookmarks_sorting(&mut self, sorting: BookmarksSorting) {
+        self.bookmarks_sorting = sorting;
+    }
+
+    /// Get the bookmark field being typed in on the bookmarks screen, if currently editing.
+    pub fn get_bookmark_input(&self) -> Option<&(HnItemIdScalar, BookmarkField, TextInputState)> {
+        self.bookmark_input.as_ref()
+    }
+
+    /// Mutably get the bookmark field being typed in on the bookmarks screen, if currently editing.
+    pub fn get_bookmark_input_mut(
+        &mut self,
+    ) -> Option<&mut (HnItemIdScalar, BookmarkField, TextInputState)> {
+        self.bookmark_input.as_mut()
+    }
+
+    /// Start (with `Some`) or stop (with `None`) editing a bookmark field on the bookmarks screen.
+    pub fn set_bookmark_input(
+        &mut self,
+        input: Option<(HnItemIdScalar, BookmarkField, TextInputState)>,
+    ) {
+        self.bookmark_input = input;
+    }
+
+    /// Get the value being typed in on the settings screen, if currently editing.
+    pub fn get_settings_value_input(&self) -> Option<&TextInputState> {
+        self.settings_value_input.as_ref()
+    }
+
+    /// Mutably get the value being typed in on the settings screen, if currently editing.
+    pub fn get_settings_value_input_mut(&mut self) -> Option<&mut TextInputState> {
+        self.settings_value_input.as_mut()
+    }
+
+    /// Start (with `Some`) or stop (with `None`) editing a value on the settings screen.
+    pub fn set_settings_value_input(&mut self, input: Option<TextInputState>) {
+        self.settings_value_input = input;
+        self.settings_value_input_submitted = false;
+    }
+
+    /// Get has the value typed in on the settings screen been submitted?
+    pub fn get_settings_value_input_submitted(&self) -> bool {
+        self.settings_value_input_submitted
+    }
+
+    /// Set has the value typed in on the settings screen been submitted?
+    pub fn set_settings_value_input_submitted(&mut self, submitted: bool) {
+        self.settings_value_input_submitted = submitted;
+    }
+
+    /// Is a text input currently capturing the keyboard, *i.e.* should global shortcuts be disabled?
+    pub fn is_text_input_active(&self) -> bool {
+        self.main_stories_filter_input.is_some()
+            || self.past_front_page_day_input.is_some()
+            || self.who_is_hiring_filter_input.is_some()
+            || self.browsing_history_filter_input.is_some()
+            || self.bookmark_input.is_some()
+            || self.settings_value_input.is_some()
+    }
+
     /// Get the currently viewed item.
     pub fn get_currently_viewed_item(&self) -> Option<&DisplayableHackerNewsItem> {
         self.currently_viewed_item.as_ref()
@@ -186,6 +482,14 @@ impl AppState {
         &self.currently_viewed_item_comments_chain
     }
 
+    /// Replace the successively viewed comments for the currently viewed item, *e.g.* when jumping to a comment.
+    pub fn set_currently_viewed_item_comments_chain(
+        &mut self,
+        comments_chain: Vec<HnItemIdScalar>,
+    ) {
+        self.currently_viewed_item_comments_chain = comments_chain;
+    }
+
     /// Push a new comment ID to the successively viewed comments for the currently viewed item.
     pub fn push_currently_viewed_item_comments_chain(&mut self, comment_id: HnItemIdScalar) {
         match self.currently_viewed_item_comments_chain.last() {
@@ -228,6 +532,48 @@ impl AppState {
         self.previously_viewed_comment_id = comment_id;
     }
 
+    /// Get the ID of the comment to focus after jumping to it across the thread.
+    pub fn get_comment_to_focus_id(&self) -> Option<HnItemIdScalar> {
+        self.comment_to_focus_id
+    }
+
+    /// Set the ID of the comment to focus after jumping to it across the thread.
+    pub fn set_comment_to_focus_id(&mut self, comment_id: Option<HnItemIdScalar>) {
+        self.comment_to_focus_id = comment_id;
+    }
+
+    /// Has the previous visit of the given item already been captured?
+    pub fn is_previous_visit_captured(&self, item_id: HnItemIdScalar) -> bool {
+        self.currently_viewed_item_previous_visit
+            .is_some_and(|(visited_item_id, _)| visited_item_id == item_id)
+    }
+
+    /// Get the ID of the latest comment seen on the previous visit of the currently viewed item, if any.
+    ///
+    /// More recent comments are new.
+    pub fn get_previous_visit_latest_seen_comment_id(&self) -> Option<HnItemIdScalar> {
+        let item_id = self.currently_viewed_item.as_ref()?.id;
+        self.currently_viewed_item_previous_visit.and_then(
+            |(visited_item_id, latest_seen_comment_id)| {
+                latest_seen_comment_id.filter(|_| visited_item_id == item_id)
+            },
+        )
+    }
+
+    /// Set the latest comment seen on the previous visit of the given item, `None` if never visited before.
+    pub fn set_previous_visit(
+        &mut self,
+        item_id: HnItemIdScalar,
+        latest_seen_comment_id: Option<HnItemIdScalar>,
+    ) {
+        self.currently_viewed_item_previous_visit = Some((item_id, latest_seen_comment_id));
+    }
+
+    /// Forget about the previous visit, *e.g.* when leaving the item.
+    pub fn clear_previous_visit(&mut self) {
+        self.currently_viewed_item_previous_visit = None;
+    }
+
     /// Get the is comments panel visible on item details screen boolean.
     pub fn get_item_page_should_display_comments_panel(&self) -> bool {
         self.item_page_display_comments_panel
@@ -238,6 +584,16 @@ impl AppState {
         self.item_page_display_comments_panel = value;
     }
 
+    /// Get are the comments displayed as a whole tree on the item details screen?
+    pub fn get_item_page_comments_tree_view(&self) -> bool {
+        self.item_page_comments_tree_view
+    }
+
+    /// Set are the comments displayed as a whole tree on the item details screen?
+    pub fn set_item_page_comments_tree_view(&mut self, value: bool) {
+        self.item_page_comments_tree_view = value;
+    }
+
     /// Get the currently viewed user ID.
     pub fn get_currently_viewed_user_id(&self) -> Option<&String> {
         self.currently_viewed_user_id.as_ref()
@@ -0,0 +1,185 @@
+use std::{
+    fs::{read_to_string, write},
+    io::{self, Write},
+    path::Path,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::history::{AppHistory, SynchronizedHistory},
+    config::{AppConfiguration, HNCLI_VERSION},
+    errors::{HnCliError, Result},
+};
+
+/// Current version of the local state archive format.
+///
+/// Must be bumped alongside each breaking change of the archive, the older
+/// archives being then migrated on import.
+pub const LOCAL_STATE_ARCHIVE_VERSION: u64 = 1;
+
+const VERSION_KEY: &str = "version";
+
+/// How to import a local state archive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Merge the archive into the local state, the imported values winning on conflicts.
+    Merge,
+    /// Replace the local state by the archive.
+    Replace,
+}
+
+/// Versioned archive of the local state of a profile, to move it to another installation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocalStateArchive {
+    /// Version of the archive format.
+    version: u64,
+    /// Version of hncli which exported the archive.
+    hncli_version: String,
+    exported_at: DateTime<Utc>,
+    /// Name of the exported profile.
+    profile: String,
+    /// The raw config file, with the user's formatting and comments.
+    config: String,
+    /// The history, including the bookmarks.
+    history: SynchronizedHistory,
+}
+
+impl LocalStateArchive {
+    /// Archive the local state of the current profile.
+    pub fn from_local_state(config: &AppConfiguration, history: &AppHistory) -> Result<Self> {
+        Ok(Self {
+            version: LOCAL_STATE_ARCHIVE_VERSION,
+            hncli_version: HNCLI_VERSION.into(),
+            exported_at: Utc::now(),
+            profile: config.get_profile().into(),
+            config: config.export_file_raw()?,
+            history: history.get_synchronized().clone(),
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|err| HnCliError::ArchiveError(format!("cannot serialize archive: {err}")))
+    }
+
+    /// Parse an archive, checking first its format version is supported.
+    pub fn from_json(archive_raw: &str) -> Result<Self> {
+        let archive_value: serde_json::Value = serde_json::from_str(archive_raw)
+            .map_err(|err| HnCliError::ArchiveError(format!("cannot parse archive: {err}")))?;
+        let version = archive_value
+            .get(VERSION_KEY)
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| HnCliError::ArchiveError("not an hncli archive".into()))?;
+        if version > LOCAL_STATE_ARCHIVE_VERSION {
+            return Err(HnCliError::ArchiveError(format!(
+                "archive version {version} is newer than the supported one ({LOCAL_STATE_ARCHIVE_VERSION}), please update hncli"
+            )));
+        }
+
+        serde_json::from_value(archive_value)
+            .map_err(|err| HnCliError::ArchiveError(format!("cannot deserialize archive: {err}")))
+    }
+
+    /// Import the archive into the local state of the current profile.
+    pub fn import(
+        self,
+        config: &AppConfiguration,
+        history: &mut AppHistory,
+        mode: ImportMode,
+    ) -> Result<()> {
+        // the config is validated before writing anything
+        config.import_file_raw(&self.config, mode)?;
+        history.import(self.history, mode)
+    }
+}
+
+/// Export the local state of the current profile to the given file, or to the standard output.
+pub fn export_local_state(
+    config: &AppConfiguration,
+    history: &AppHistory,
+    archive_filepath: Option<&Path>,
+) -> Result<()> {
+    let archive_raw = LocalStateArchive::from_local_state(config, history)?.to_json()?;
+    match archive_filepath {
+        Some(archive_filepath) => {
+            write(archive_filepath, archive_raw).map_err(|err| {
+                HnCliError::ArchiveError(format!(
+                    "cannot write archive ({}): {}",
+                    archive_filepath.display(),
+                    err
+                ))
+            })?;
+            eprintln!(
+                "Profile \"{}\" exported to {}.",
+                config.get_profile(),
+                archive_filepath.display()
+            );
+        }
+        None => writeln!(io::stdout(), "{archive_raw}").map_err(HnCliError::IoError)?,
+    }
+    Ok(())
+}
+
+/// Import the given archive file into the local state of the current profile.
+pub fn import_local_state(
+    config: &AppConfiguration,
+    history: &mut AppHistory,
+    archive_filepath: &Path,
+    mode: ImportMode,
+) -> Result<()> {
+    let archive_raw = read_to_string(archive_filepath).map_err(|err| {
+        HnCliError::ArchiveError(format!(
+            "cannot open archive ({}): {}",
+            archive_filepath.display(),
+            err
+        ))
+    })?;
+    let archive = LocalStateArchive::from_json(&archive_raw)?;
+    let archived_profile = archive.profile.clone();
+    archive.import(config, history, mode)?;
+    eprintln!(
+        "Profile \"{archived_profile}\" {} into profile \"{}\".",
+        match mode {
+            ImportMode::Merge => "merged",
+            ImportMode::Replace => "imported",
+        },
+        config.get_profile()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LOCAL_STATE_ARCHIVE_VERSION, LocalStateArchive};
+
+    #[test]
+    fn test_archive_versioning() {
+        let archive_raw = |version: u64| {
+            format!(
+                r#"{{
+                    "version": {version},
+                    "hncli_version": "0.1.0",
+                    "exported_at": "2024-03-01T12:00:00Z",
+                    "profile": "default",
+                    "config": "theme = \"Light\"\n",
+                    "history": {{ "latest_top_level_comments_per_item_map": {{}} }}
+                }}"#
+            )
+        };
+
+        let archive = LocalStateArchive::from_json(&archive_raw(1)).unwrap();
+        assert_eq!(archive.profile, "default");
+        assert_eq!(archive.config, "theme = \"Light\"\n");
+        let reparsed = LocalStateArchive::from_json(&archive.to_json().unwrap()).unwrap();
+        assert_eq!(reparsed.version, LOCAL_STATE_ARCHIVE_VERSION);
+        assert_eq!(reparsed.exported_at, archive.exported_at);
+
+        assert!(
+            LocalStateArchive::from_json(&archive_raw(LOCAL_STATE_ARCHIVE_VERSION + 1)).is_err()
+        );
+        assert!(LocalStateArchive::from_json(r#"{ "theme": "Light" }"#).is_err());
+        assert!(LocalStateArchive::from_json("not json").is_err());
+    }
+}
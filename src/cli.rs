@@ -0,0 +1,237 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use url::Url;
+
+use crate::{
+    api::{client::HnStoriesSections, types::HnItemIdScalar},
+    archive::ImportMode,
+    config::{DEFAULT_PROFILE, validate_profile_name},
+};
+
+/// Hostname of the official Hacker News website.
+const HACKER_NEWS_HOSTNAME: &str = "news.ycombinator.com";
+
+/// A modern TUI for browsing Hacker News.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct CliArguments {
+    /// Configuration file to use instead of the default one.
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    /// Configuration profile to use, with its own config and history files, created if needed.
+    #[arg(
+        long,
+        value_name = "NAME",
+        default_value = DEFAULT_PROFILE,
+        value_parser = parse_profile,
+        conflicts_with = "config",
+    )]
+    pub profile: String,
+    /// Stories section to open on launch.
+    #[arg(long, value_enum)]
+    pub section: Option<CliStoriesSection>,
+    /// Open an item (story, job or comment thread) on launch, from its ID or Hacker News URL.
+    #[arg(
+        long,
+        value_name = "ID_OR_URL",
+        value_parser = parse_item_id,
+        conflicts_with_all = ["user", "search", "offline"],
+    )]
+    pub item: Option<HnItemIdScalar>,
+    /// Open a user profile on launch.
+    #[arg(long, value_name = "USERNAME", conflicts_with = "search")]
+    pub user: Option<String>,
+    /// Search the stories matching the query on launch.
+    #[arg(long, value_name = "QUERY")]
+    pub search: Option<String>,
+    /// Write the logs to the given file.
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+    /// Do not perform any network request.
+    #[arg(long)]
+    pub offline: bool,
+    /// Override a configuration option for this session, taking precedence over
+    /// its `HNCLI_<KEY>` environment variable and the config file.
+    #[arg(long = "set", value_name = "KEY=VALUE", value_parser = parse_setting)]
+    pub settings: Vec<(String, String)>,
+    /// Command to run instead of launching the TUI.
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CliCommand {
+    /// Export the configuration, history and bookmarks of the profile to a JSON archive.
+    Export {
+        /// Archive file to write, the standard output if not given.
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+    },
+    /// Import a JSON archive written by `hncli export` into the profile.
+    Import {
+        /// Archive file to read.
+        #[arg(value_name = "PATH")]
+        path: PathBuf,
+        /// Merge the archive into the local state, or replace the local state by it.
+        #[arg(long, value_enum, default_value_t = CliImportMode::Merge)]
+        mode: CliImportMode,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CliImportMode {
+    Merge,
+    Replace,
+}
+
+impl From<CliImportMode> for ImportMode {
+    fn from(mode: CliImportMode) -> Self {
+        match mode {
+            CliImportMode::Merge => Self::Merge,
+            CliImportMode::Replace => Self::Replace,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CliStoriesSection {
+    Home,
+    Ask,
+    Show,
+    Jobs,
+}
+
+impl From<CliStoriesSection> for HnStoriesSections {
+    fn from(section: CliStoriesSection) -> Self {
+        match section {
+            CliStoriesSection::Home => Self::Home,
+            CliStoriesSection::Ask => Self::Ask,
+            CliStoriesSection::Show => Self::Show,
+            CliStoriesSection::Jobs => Self::Jobs,
+        }
+    }
+}
+
+/// Parse an item ID, either given as is or from a Hacker News item URL
+/// like `https://news.ycombinator.com/item?id=1234`.
+pub fn parse_item_id(raw: &str) -> Result<HnItemIdScalar, String> {
+    let raw = raw.trim();
+    if let Ok(id) = raw.parse() {
+        return Ok(id);
+    }
+
+    let url = Url::parse(raw).map_err(|_| format!("\"{raw}\" is neither an ID nor a URL"))?;
+    let is_hacker_news_item = url
+        .host_str()
+        .is_some_and(|host| host.trim_start_matches("www.") == HACKER_NEWS_HOSTNAME)
+        && url.path() == "/item";
+    if !is_hacker_news_item {
+        return Err(format!("\"{raw}\" is not a Hacker News item URL"));
+    }
+    url.query_pairs()
+        .find(|(key, _)| key == "id")
+        .and_then(|(_, value)| value.parse().ok())
+        .ok_or_else(|| format!("\"{raw}\" has no valid item ID"))
+}
+
+/// Parse a configuration profile name.
+pub fn parse_profile(raw: &str) -> Result<String, String> {
+    validate_profile_name(raw).map(|()| raw.to_string())
+}
+
+/// Parse a `KEY=VALUE` configuration option override.
+pub fn parse_setting(raw: &str) -> Result<(String, String), String> {
+    raw.split_once('=')
+        .map(|(key, value)| (key.trim().to_lowercase(), value.to_string()))
+        .filter(|(key, _)| !key.is_empty())
+        .ok_or_else(|| format!("\"{raw}\" is not a KEY=VALUE setting"))
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::config::DEFAULT_PROFILE;
+
+    use super::{CliArguments, CliCommand, CliImportMode, parse_item_id, parse_setting};
+
+    #[test]
+    fn test_parse_item_id() {
+        assert_eq!(parse_item_id("8863"), Ok(8863));
+        assert_eq!(
+            parse_item_id("https://news.ycombinator.com/item?id=8863"),
+            Ok(8863)
+        );
+        assert_eq!(
+            parse_item_id("https://www.news.ycombinator.com/item?id=8863&p=2"),
+            Ok(8863)
+        );
+        assert!(parse_item_id("https://example.com/item?id=8863").is_err());
+        assert!(parse_item_id("https://news.ycombinator.com/user?id=pg").is_err());
+        assert!(parse_item_id("https://news.ycombinator.com/item?id=abc").is_err());
+        assert!(parse_item_id("not an item").is_err());
+
+        assert!(CliArguments::try_parse_from(["hncli", "--item", "1", "--user", "pg"]).is_err());
+        assert!(CliArguments::try_parse_from(["hncli", "--item", "1", "--offline"]).is_err());
+        assert!(CliArguments::try_parse_from(["hncli", "--section", "ask", "--offline"]).is_ok());
+    }
+
+    #[test]
+    fn test_parse_profile() {
+        let arguments = CliArguments::try_parse_from(["hncli", "--profile", "work"]).unwrap();
+        assert_eq!(arguments.profile, "work");
+        let arguments = CliArguments::try_parse_from(["hncli"]).unwrap();
+        assert_eq!(arguments.profile, DEFAULT_PROFILE);
+
+        assert!(CliArguments::try_parse_from(["hncli", "--profile", "../work"]).is_err());
+        assert!(
+            CliArguments::try_parse_from(["hncli", "--profile", "work", "--config", "hncli.toml"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_setting() {
+        assert_eq!(
+            parse_setting("theme=Light"),
+            Ok(("theme".into(), "Light".into()))
+        );
+        assert_eq!(
+            parse_setting("KEYBINDINGS={ preset = \"Vim\" }"),
+            Ok(("keybindings".into(), "{ preset = \"Vim\" }".into()))
+        );
+        assert!(parse_setting("theme").is_err());
+        assert!(parse_setting("=Light").is_err());
+    }
+
+    #[test]
+    fn test_parse_archive_commands() {
+        let arguments =
+            CliArguments::try_parse_from(["hncli", "--profile", "work", "export"]).unwrap();
+        assert!(matches!(
+            arguments.command,
+            Some(CliCommand::Export { path: None })
+        ));
+
+        let arguments =
+            CliArguments::try_parse_from(["hncli", "import", "hncli.json", "--mode", "replace"])
+                .unwrap();
+        assert!(matches!(
+            arguments.command,
+            Some(CliCommand::Import {
+                mode: CliImportMode::Replace,
+                ..
+            })
+        ));
+        let arguments = CliArguments::try_parse_from(["hncli", "import", "hncli.json"]).unwrap();
+        assert!(matches!(
+            arguments.command,
+            Some(CliCommand::Import {
+                mode: CliImportMode::Merge,
+                ..
+            })
+        ));
+        assert!(CliArguments::try_parse_from(["hncli", "import"]).is_err());
+    }
+}
@@ -1,23 +1,92 @@
 use std::{
-    fs::{create_dir_all, read_to_string, write},
-    path::PathBuf,
+    collections::BTreeMap,
+    fmt::Display,
+    fs::{copy, create_dir_all, metadata, read_to_string},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
 };
 
 use directories::ProjectDirs;
-use log::warn;
-use serde::{Deserialize, Serialize};
+use log::{info, warn};
+use serde::{Serialize, de::DeserializeOwned};
+use toml_edit::DocumentMut;
 
 use crate::{
+    api::client::HnStoriesSorting,
+    app::killfile::{Killfile, KillfileConfiguration},
+    archive::ImportMode,
     errors::{HnCliError, Result},
-    ui::theme::UiTheme,
+    ui::{
+        components::navigation::{ALWAYS_VISIBLE_TABS_TITLES, TABS_TITLES},
+        keybindings::{KeyBindings, KeyBindingsConfiguration, KeyBindingsPreset},
+        theme::{UiTheme, UiThemePalette, UiThemePaletteConfiguration, parse_color},
+    },
 };
 
+use document::{parse_document, update_table};
+use migrations::{CONFIG_SCHEMA_VERSION, get_schema_version, migrate_document, set_schema_version};
+pub use overrides::{ConfigOverrides, ConfigValueSource};
+pub use profiles::{DEFAULT_PROFILE, get_profile_directory, list_profiles, validate_profile_name};
+pub use storage::{FileLock, write_atomically};
+
+mod document;
+mod migrations;
+mod overrides;
+mod profiles;
+mod storage;
+
 pub const HNCLI_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const ENABLE_GLOBAL_SUB_SCREEN_QUIT_SHORTCUT_DEFAULT: bool = true;
 pub const DISPLAY_COMMENTS_PANEL_BY_DEFAULT_DEFAULT: bool = false;
+pub const DISPLAY_STORY_PREVIEW_PANEL_DEFAULT: bool = true;
 pub const DISPLAY_MAIN_ITEMS_LIST_ITEM_META: bool = false;
+pub const COMPACT_LISTS_DEFAULT: bool = false;
 pub const SHOW_CONTEXTUAL_HELP_DEFAULT: bool = true;
+pub const ENABLE_MOUSE_DEFAULT: bool = true;
+pub const DEFAULT_STORIES_SORTING_DEFAULT: HnStoriesSorting = HnStoriesSorting::Top;
+pub const MAX_DISPLAYED_STORIES_DEFAULT: usize = 50;
+pub const MAX_DISPLAYED_STORIES_RANGE: RangeInclusive<usize> = 10..=500;
+pub const MAX_DISPLAYED_STORIES_STEP: usize = 10;
+pub const STORIES_REFRESH_INTERVAL_SECS_DEFAULT: u64 = 180;
+pub const COMMENTS_REFRESH_INTERVAL_SECS_DEFAULT: u64 = 180;
+/// NB: the upper bound must fit in the UI ticks counters.
+pub const REFRESH_INTERVAL_SECS_RANGE: RangeInclusive<u64> = 30..=3600;
+pub const REFRESH_INTERVAL_SECS_STEP: u64 = 30;
+pub const HTTP_TIMEOUT_SECS_DEFAULT: u64 = 10;
+pub const HTTP_TIMEOUT_SECS_RANGE: RangeInclusive<u64> = 1..=120;
+pub const HTTP_TIMEOUT_SECS_STEP: u64 = 1;
+pub const BROWSING_HISTORY_MAX_ENTRIES_DEFAULT: usize = 1000;
+pub const BROWSING_HISTORY_MAX_ENTRIES_RANGE: RangeInclusive<usize> = 50..=10000;
+pub const BROWSING_HISTORY_MAX_ENTRIES_STEP: usize = 50;
+pub const BROWSING_HISTORY_RETENTION_DAYS_DEFAULT: u64 = 30;
+pub const BROWSING_HISTORY_RETENTION_DAYS_RANGE: RangeInclusive<u64> = 1..=365;
+pub const BROWSING_HISTORY_RETENTION_DAYS_STEP: u64 = 1;
+
+/// Keys of the configuration options, which can be overridden from the environment
+/// variables or the command-line.
+pub const CONFIG_OPTIONS_KEYS: &[&str] = &[
+    "theme",
+    "enable_global_sub_screen_quit_shortcut",
+    "display_comments_panel_by_default",
+    "display_story_preview_panel",
+    "display_main_items_list_item_meta",
+    "compact_lists",
+    "hidden_tabs",
+    "show_contextual_help",
+    "enable_mouse",
+    "default_stories_sorting",
+    "max_displayed_stories",
+    "stories_refresh_interval_secs",
+    "comments_refresh_interval_secs",
+    "http_timeout_secs",
+    "browsing_history_max_entries",
+    "browsing_history_retention_days",
+    "custom_theme",
+    "killfile",
+    "keybindings",
+];
 
 /// Persisted, global application configuration.
 #[derive(Debug, Serialize)]
@@ -28,10 +97,88 @@ pub struct AppConfiguration {
     enable_global_sub_screen_quit_shortcut: bool,
     /// On the item details page, should we display the comments panel by default or not?
     display_comments_panel_by_default: bool,
+    /// On the home screen of wide terminals, should we preview the selected story next to the list?
+    display_story_preview_panel: bool,
     /// On the main items list (home screen), should we display the items' metadata (score, number of comments, etc.)?
     display_main_items_list_item_meta: bool,
+    /// Should the lists display a single line per entry, to fit more of them?
+    compact_lists: bool,
+    /// Titles of the navigation tabs to hide, *e.g.* `["Jobs", "Hiring"]`.
+    hidden_tabs: Vec<String>,
     /// Show the global contextual help?
     show_contextual_help: bool,
+    /// Capture the mouse events, to click and scroll through the application?
+    enable_mouse: bool,
+    /// Sorting of the home screen stories on launch.
+    default_stories_sorting: HnStoriesSorting,
+    /// Maximum number of stories displayed in the stories lists.
+    max_displayed_stories: usize,
+    /// Interval between two automatic refreshes of the stories lists.
+    stories_refresh_interval_secs: u64,
+    /// Interval between two automatic refreshes of the comments.
+    comments_refresh_interval_secs: u64,
+    /// Timeout of the Hacker News API requests.
+    http_timeout_secs: u64,
+    /// Maximum number of entries kept in the browsing history.
+    browsing_history_max_entries: usize,
+    /// Number of days the browsing history entries are kept for.
+    browsing_history_retention_days: u64,
+    /// User-defined palette, used by the `Custom` theme.
+    ///
+    /// NB: must come after the plain values since serialized as a TOML table.
+    custom_theme: Option<UiThemePaletteConfiguration>,
+    /// Rules hiding the matching stories and comments.
+    ///
+    /// NB: must come after the plain values since serialized as a TOML table.
+    killfile: Option<KillfileConfiguration>,
+    /// Key bindings preset and user-defined key bindings.
+    ///
+    /// NB: must stay the last field since serialized as a TOML table.
+    keybindings: KeyBindingsConfiguration,
+    /// Effective key bindings, computed from `keybindings`.
+    #[serde(skip)]
+    key_bindings: KeyBindings,
+    /// Error in the `keybindings` table, if any, in which case the preset only is used.
+    #[serde(skip)]
+    key_bindings_error: Option<String>,
+    /// Effective theme palette, computed from `theme` and `custom_theme`.
+    #[serde(skip)]
+    palette: UiThemePalette,
+    /// Error in the `custom_theme` table, if any, in which case the default theme is used.
+    #[serde(skip)]
+    palette_error: Option<String>,
+    /// Effective killfile rules, compiled from `killfile`.
+    #[serde(skip)]
+    killfile_rules: Killfile,
+    /// Error in the `killfile` table, if any, in which case no rule applies.
+    #[serde(skip)]
+    killfile_error: Option<String>,
+    /// Errors in the options (invalid or out of range values), if any, in which case their
+    /// default values are used.
+    #[serde(skip)]
+    options_error: Option<String>,
+    /// Name of the profile the configuration belongs to.
+    #[serde(skip)]
+    profile: String,
+    /// Path of the config file, if it can be determined.
+    #[serde(skip)]
+    file_path: Option<PathBuf>,
+    /// Modification time of the config file when last loaded or saved, used for hot-reloading.
+    #[serde(skip)]
+    synchronized_at: Option<SystemTime>,
+    /// The configuration serialized when last loaded or saved, to only save the options
+    /// changed since then and keep the ones changed in the meantime by other hncli instances.
+    #[serde(skip)]
+    synchronized_raw: Option<String>,
+    /// Incremented each time the configuration is reloaded from the file.
+    #[serde(skip)]
+    revision: u64,
+    /// Values overriding the config file ones, from the environment variables or the command-line.
+    #[serde(skip)]
+    overrides: ConfigOverrides,
+    /// Source of the effective value of each option.
+    #[serde(skip)]
+    value_sources: BTreeMap<&'static str, ConfigValueSource>,
 }
 
 impl Default for AppConfiguration {
@@ -40,52 +187,291 @@ impl Default for AppConfiguration {
             theme: UiTheme::default(),
             enable_global_sub_screen_quit_shortcut: ENABLE_GLOBAL_SUB_SCREEN_QUIT_SHORTCUT_DEFAULT,
             display_comments_panel_by_default: DISPLAY_COMMENTS_PANEL_BY_DEFAULT_DEFAULT,
+            display_story_preview_panel: DISPLAY_STORY_PREVIEW_PANEL_DEFAULT,
             display_main_items_list_item_meta: DISPLAY_MAIN_ITEMS_LIST_ITEM_META,
+            compact_lists: COMPACT_LISTS_DEFAULT,
+            hidden_tabs: vec![],
             show_contextual_help: SHOW_CONTEXTUAL_HELP_DEFAULT,
+            enable_mouse: ENABLE_MOUSE_DEFAULT,
+            default_stories_sorting: DEFAULT_STORIES_SORTING_DEFAULT,
+            max_displayed_stories: MAX_DISPLAYED_STORIES_DEFAULT,
+            stories_refresh_interval_secs: STORIES_REFRESH_INTERVAL_SECS_DEFAULT,
+            comments_refresh_interval_secs: COMMENTS_REFRESH_INTERVAL_SECS_DEFAULT,
+            http_timeout_secs: HTTP_TIMEOUT_SECS_DEFAULT,
+            browsing_history_max_entries: BROWSING_HISTORY_MAX_ENTRIES_DEFAULT,
+            browsing_history_retention_days: BROWSING_HISTORY_RETENTION_DAYS_DEFAULT,
+            custom_theme: None,
+            killfile: None,
+            keybindings: KeyBindingsConfiguration::default(),
+            key_bindings: KeyBindings::default(),
+            key_bindings_error: None,
+            palette: UiThemePalette::default(),
+            palette_error: None,
+            killfile_rules: Killfile::default(),
+            killfile_error: None,
+            options_error: None,
+            profile: DEFAULT_PROFILE.into(),
+            file_path: None,
+            synchronized_at: None,
+            synchronized_raw: None,
+            revision: 0,
+            overrides: ConfigOverrides::default(),
+            value_sources: BTreeMap::new(),
         }
     }
 }
 
 /// Intermediate structure used solely for deserialization.
 ///
-/// This is needed due to potentially missing values in the TOML configuration,
-/// for instance when adding a new configuration option.
-#[derive(Debug, Deserialize)]
+/// This is needed due to potentially missing or invalid values in the TOML configuration,
+/// for instance when adding a new configuration option or when one is mistyped.
+#[derive(Debug)]
 struct DeserializableAppConfiguration {
     theme: Option<UiTheme>,
     enable_global_sub_screen_quit_shortcut: Option<bool>,
     display_comments_panel_by_default: Option<bool>,
+    display_story_preview_panel: Option<bool>,
     display_main_items_list_item_meta: Option<bool>,
+    compact_lists: Option<bool>,
+    hidden_tabs: Option<Vec<String>>,
     show_contextual_help: Option<bool>,
+    enable_mouse: Option<bool>,
+    default_stories_sorting: Option<HnStoriesSorting>,
+    max_displayed_stories: Option<usize>,
+    stories_refresh_interval_secs: Option<u64>,
+    comments_refresh_interval_secs: Option<u64>,
+    http_timeout_secs: Option<u64>,
+    browsing_history_max_entries: Option<usize>,
+    browsing_history_retention_days: Option<u64>,
+    custom_theme: Option<UiThemePaletteConfiguration>,
+    killfile: Option<KillfileConfiguration>,
+    keybindings: Option<KeyBindingsConfiguration>,
+}
+
+impl DeserializableAppConfiguration {
+    /// Deserialize each option on its own, an invalid one being reported then skipped
+    /// instead of invalidating the whole configuration.
+    fn from_table(table: &toml::Table, errors: &mut Vec<String>) -> Self {
+        Self {
+            theme: deserialize_option(table, "theme", errors),
+            enable_global_sub_screen_quit_shortcut: deserialize_option(
+                table,
+                "enable_global_sub_screen_quit_shortcut",
+                errors,
+            ),
+            display_comments_panel_by_default: deserialize_option(
+                table,
+                "display_comments_panel_by_default",
+                errors,
+            ),
+            display_story_preview_panel: deserialize_option(
+                table,
+                "display_story_preview_panel",
+                errors,
+            ),
+            display_main_items_list_item_meta: deserialize_option(
+                table,
+                "display_main_items_list_item_meta",
+                errors,
+            ),
+            compact_lists: deserialize_option(table, "compact_lists", errors),
+            hidden_tabs: deserialize_option(table, "hidden_tabs", errors),
+            show_contextual_help: deserialize_option(table, "show_contextual_help", errors),
+            enable_mouse: deserialize_option(table, "enable_mouse", errors),
+            default_stories_sorting: deserialize_option(table, "default_stories_sorting", errors),
+            max_displayed_stories: deserialize_option(table, "max_displayed_stories", errors),
+            stories_refresh_interval_secs: deserialize_option(
+                table,
+                "stories_refresh_interval_secs",
+                errors,
+            ),
+            comments_refresh_interval_secs: deserialize_option(
+                table,
+                "comments_refresh_interval_secs",
+                errors,
+            ),
+            http_timeout_secs: deserialize_option(table, "http_timeout_secs", errors),
+            browsing_history_max_entries: deserialize_option(
+                table,
+                "browsing_history_max_entries",
+                errors,
+            ),
+            browsing_history_retention_days: deserialize_option(
+                table,
+                "browsing_history_retention_days",
+                errors,
+            ),
+            custom_theme: deserialize_option(table, "custom_theme", errors),
+            killfile: deserialize_option(table, "killfile", errors),
+            keybindings: deserialize_option(table, "keybindings", errors),
+        }
+    }
 }
 
 impl AppConfiguration {
-    pub fn from_file_or_defaults() -> Self {
-        match Self::from_file_or_environment() {
+    /// Load the configuration from the given file, or from the one of the given profile in
+    /// the OS configuration directory, with the given overrides on top of it.
+    ///
+    /// The precedence order is: command-line > environment variables > config file > defaults.
+    pub fn from_file_or_defaults(
+        custom_file_path: Option<PathBuf>,
+        profile: &str,
+        overrides: ConfigOverrides,
+    ) -> Self {
+        let config_filepath = match custom_file_path {
+            Some(file_path) => Ok(file_path),
+            None => Self::get_profile_config_file_path(profile),
+        };
+        let mut config = match config_filepath
+            .and_then(|config_filepath| Self::from_file_or_environment(config_filepath, &overrides))
+        {
             Ok(config) => config,
             Err(why) => {
                 warn!("AppConfiguration loading error, using defaults. {why}");
-                Self::default()
+                Self::from_defaults(&overrides)
             }
-        }
+        };
+        config.profile = profile.into();
+        config
+    }
+
+    /// Load the configuration of another profile, created if needed, keeping the current overrides.
+    pub fn switch_to_profile(&self, profile: &str) -> Result<Self> {
+        validate_profile_name(profile).map_err(HnCliError::InvalidOptionError)?;
+        let mut config = Self::from_file_or_defaults(None, profile, self.overrides.clone());
+        config.revision = self.revision + 1;
+        Ok(config)
     }
 
-    pub fn get_theme(&self) -> &UiTheme {
-        &self.theme
+    /// Get the name of the profile the configuration belongs to.
+    pub fn get_profile(&self) -> &str {
+        &self.profile
     }
 
-    pub fn set_theme_to_next_value(&mut self) {
-        self.theme = self.theme.next_value();
+    /// Get the source of the effective value of a configuration option.
+    pub fn get_value_source(&self, key: &str) -> ConfigValueSource {
+        self.value_sources
+            .get(key)
+            .copied()
+            .unwrap_or(ConfigValueSource::Default)
+    }
+
+    /// Save an option changed by the user, which takes precedence over its override if any.
+    fn save_option_to_file(&mut self, key: &str) {
+        self.overrides.remove(key);
         self.save_to_file_warn_if_fail();
     }
 
+    pub fn get_ui_theme(&self) -> UiTheme {
+        self.theme
+    }
+
+    /// Get the palette of the current theme.
+    pub fn get_theme(&self) -> &UiThemePalette {
+        &self.palette
+    }
+
+    pub fn set_theme(&mut self, theme: UiTheme) {
+        self.theme = theme;
+        (self.palette, self.palette_error) =
+            Self::resolve_palette(self.theme, self.custom_theme.as_ref());
+        self.save_option_to_file("theme");
+    }
+
+    /// Get the user-defined palette, if any.
+    pub fn get_custom_theme(&self) -> Option<&UiThemePaletteConfiguration> {
+        self.custom_theme.as_ref()
+    }
+
+    /// Set the built-in theme the custom palette is based upon.
+    pub fn set_custom_theme_base(&mut self, base: UiTheme) -> Result<()> {
+        if base == UiTheme::Custom {
+            return Err(HnCliError::InvalidOptionError(
+                "the custom theme base must be a built-in theme".into(),
+            ));
+        }
+        self.custom_theme.get_or_insert_default().base = base;
+        self.save_custom_theme_to_file();
+        Ok(())
+    }
+
+    /// Set, or unset, a color of the custom palette, *e.g.* "main" to "#ff6600".
+    pub fn set_custom_theme_color(&mut self, name: &str, raw: Option<&str>) -> Result<()> {
+        if let Some(raw) = raw {
+            parse_color(raw)?;
+        }
+        let mut custom_theme = self.custom_theme.clone().unwrap_or_default();
+        let color = custom_theme
+            .get_color_mut(name)
+            .ok_or_else(|| HnCliError::ThemeError(format!("unknown color \"{name}\"")))?;
+        *color = raw.map(String::from);
+        self.custom_theme = Some(custom_theme);
+        self.save_custom_theme_to_file();
+        Ok(())
+    }
+
+    fn save_custom_theme_to_file(&mut self) {
+        (self.palette, self.palette_error) =
+            Self::resolve_palette(self.theme, self.custom_theme.as_ref());
+        self.save_option_to_file("custom_theme");
+    }
+
+    /// Get the error found in the custom theme at load time, if any.
+    pub fn get_theme_error(&self) -> Option<&str> {
+        self.palette_error.as_deref()
+    }
+
+    /// Compute the effective theme palette, falling back to the default theme on error.
+    fn resolve_palette(
+        theme: UiTheme,
+        custom_theme: Option<&UiThemePaletteConfiguration>,
+    ) -> (UiThemePalette, Option<String>) {
+        if theme != UiTheme::Custom {
+            return (theme.get_builtin_palette(), None);
+        }
+        match custom_theme.map(|custom_theme| custom_theme.to_palette()) {
+            Some(Ok(palette)) => (palette, None),
+            Some(Err(why)) => {
+                warn!("AppConfiguration: invalid custom theme, using the default one. {why}");
+                (UiThemePalette::default(), Some(why.to_string()))
+            }
+            None => {
+                let why = "no custom_theme table defined";
+                warn!("AppConfiguration: invalid custom theme, using the default one. {why}");
+                (UiThemePalette::default(), Some(why.into()))
+            }
+        }
+    }
+
+    /// Get the effective killfile rules.
+    pub fn get_killfile(&self) -> &Killfile {
+        &self.killfile_rules
+    }
+
+    /// Get the error found in the killfile at load time, if any.
+    pub fn get_killfile_error(&self) -> Option<&str> {
+        self.killfile_error.as_deref()
+    }
+
+    /// Compile the killfile rules, ignoring all of them on error.
+    fn resolve_killfile(killfile: Option<&KillfileConfiguration>) -> (Killfile, Option<String>) {
+        match killfile.map(|killfile| killfile.to_killfile()) {
+            None => (Killfile::default(), None),
+            Some(Ok(killfile_rules)) => (killfile_rules, None),
+            Some(Err(why)) => {
+                warn!("AppConfiguration: invalid killfile, ignoring it. {why}");
+                (Killfile::default(), Some(why.to_string()))
+            }
+        }
+    }
+
     pub fn get_enable_global_sub_screen_quit_shortcut(&self) -> bool {
         self.enable_global_sub_screen_quit_shortcut
     }
 
     pub fn toggle_enable_global_sub_screen_quit_shortcut(&mut self) {
         self.enable_global_sub_screen_quit_shortcut = !self.enable_global_sub_screen_quit_shortcut;
-        self.save_to_file_warn_if_fail();
+        self.save_option_to_file("enable_global_sub_screen_quit_shortcut");
     }
 
     pub fn get_display_comments_panel_by_default(&self) -> bool {
@@ -94,7 +480,16 @@ impl AppConfiguration {
 
     pub fn toggle_display_comments_panel_by_default(&mut self) {
         self.display_comments_panel_by_default = !self.display_comments_panel_by_default;
-        self.save_to_file_warn_if_fail();
+        self.save_option_to_file("display_comments_panel_by_default");
+    }
+
+    pub fn get_display_story_preview_panel(&self) -> bool {
+        self.display_story_preview_panel
+    }
+
+    pub fn toggle_display_story_preview_panel(&mut self) {
+        self.display_story_preview_panel = !self.display_story_preview_panel;
+        self.save_option_to_file("display_story_preview_panel");
     }
 
     pub fn get_display_main_items_list_item_meta(&self) -> bool {
@@ -103,7 +498,21 @@ impl AppConfiguration {
 
     pub fn toggle_display_main_items_list_item_meta(&mut self) {
         self.display_main_items_list_item_meta = !self.display_main_items_list_item_meta;
-        self.save_to_file_warn_if_fail();
+        self.save_option_to_file("display_main_items_list_item_meta");
+    }
+
+    pub fn get_compact_lists(&self) -> bool {
+        self.compact_lists
+    }
+
+    pub fn toggle_compact_lists(&mut self) {
+        self.compact_lists = !self.compact_lists;
+        self.save_option_to_file("compact_lists");
+    }
+
+    /// Get the titles of the hidden navigation tabs.
+    pub fn get_hidden_tabs(&self) -> &[String] {
+        &self.hidden_tabs
     }
 
     pub fn get_show_contextual_help(&self) -> bool {
@@ -112,14 +521,276 @@ impl AppConfiguration {
 
     pub fn toggle_show_contextual_help(&mut self) {
         self.show_contextual_help = !self.show_contextual_help;
-        self.save_to_file_warn_if_fail();
+        self.save_option_to_file("show_contextual_help");
+    }
+
+    pub fn get_enable_mouse(&self) -> bool {
+        self.enable_mouse
+    }
+
+    pub fn toggle_enable_mouse(&mut self) {
+        self.enable_mouse = !self.enable_mouse;
+        self.save_option_to_file("enable_mouse");
     }
 
-    fn save_to_file(&self) -> Result<()> {
-        let config_filepath = Self::get_config_file_path()?;
+    pub fn get_default_stories_sorting(&self) -> HnStoriesSorting {
+        self.default_stories_sorting
+    }
+
+    pub fn set_default_stories_sorting(&mut self, sorting: HnStoriesSorting) {
+        self.default_stories_sorting = sorting;
+        self.save_option_to_file("default_stories_sorting");
+    }
+
+    pub fn get_max_displayed_stories(&self) -> usize {
+        self.max_displayed_stories
+    }
+
+    pub fn set_max_displayed_stories(&mut self, value: usize) -> Result<()> {
+        self.max_displayed_stories =
+            check_option_range("max_displayed_stories", value, MAX_DISPLAYED_STORIES_RANGE)
+                .map_err(HnCliError::InvalidOptionError)?;
+        self.save_option_to_file("max_displayed_stories");
+        Ok(())
+    }
+
+    pub fn get_stories_refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.stories_refresh_interval_secs)
+    }
+
+    pub fn set_stories_refresh_interval_secs(&mut self, value: u64) -> Result<()> {
+        self.stories_refresh_interval_secs = check_option_range(
+            "stories_refresh_interval_secs",
+            value,
+            REFRESH_INTERVAL_SECS_RANGE,
+        )
+        .map_err(HnCliError::InvalidOptionError)?;
+        self.save_option_to_file("stories_refresh_interval_secs");
+        Ok(())
+    }
+
+    pub fn get_comments_refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.comments_refresh_interval_secs)
+    }
+
+    pub fn set_comments_refresh_interval_secs(&mut self, value: u64) -> Result<()> {
+        self.comments_refresh_interval_secs = check_option_range(
+            "comments_refresh_interval_secs",
+            value,
+            REFRESH_INTERVAL_SECS_RANGE,
+        )
+        .map_err(HnCliError::InvalidOptionError)?;
+        self.save_option_to_file("comments_refresh_interval_secs");
+        Ok(())
+    }
+
+    pub fn get_http_timeout(&self) -> Duration {
+        Duration::from_secs(self.http_timeout_secs)
+    }
+
+    pub fn set_http_timeout_secs(&mut self, value: u64) -> Result<()> {
+        self.http_timeout_secs =
+            check_option_range("http_timeout_secs", value, HTTP_TIMEOUT_SECS_RANGE)
+                .map_err(HnCliError::InvalidOptionError)?;
+        self.save_option_to_file("http_timeout_secs");
+        Ok(())
+    }
+
+    pub fn get_browsing_history_max_entries(&self) -> usize {
+        self.browsing_history_max_entries
+    }
+
+    pub fn set_browsing_history_max_entries(&mut self, value: usize) -> Result<()> {
+        self.browsing_history_max_entries = check_option_range(
+            "browsing_history_max_entries",
+            value,
+            BROWSING_HISTORY_MAX_ENTRIES_RANGE,
+        )
+        .map_err(HnCliError::InvalidOptionError)?;
+        self.save_option_to_file("browsing_history_max_entries");
+        Ok(())
+    }
+
+    pub fn get_browsing_history_retention_days(&self) -> u64 {
+        self.browsing_history_retention_days
+    }
+
+    pub fn set_browsing_history_retention_days(&mut self, value: u64) -> Result<()> {
+        self.browsing_history_retention_days = check_option_range(
+            "browsing_history_retention_days",
+            value,
+            BROWSING_HISTORY_RETENTION_DAYS_RANGE,
+        )
+        .map_err(HnCliError::InvalidOptionError)?;
+        self.save_option_to_file("browsing_history_retention_days");
+        Ok(())
+    }
+
+    /// Reset a top-level option to its default value.
+    pub fn reset_option(&mut self, key: &str) {
+        let defaults = Self::default();
+        match key {
+            "theme" => self.theme = defaults.theme,
+            "enable_global_sub_screen_quit_shortcut" => {
+                self.enable_global_sub_screen_quit_shortcut =
+                    defaults.enable_global_sub_screen_quit_shortcut
+            }
+            "display_comments_panel_by_default" => {
+                self.display_comments_panel_by_default = defaults.display_comments_panel_by_default
+            }
+            "display_story_preview_panel" => {
+                self.display_story_preview_panel = defaults.display_story_preview_panel
+            }
+            "display_main_items_list_item_meta" => {
+                self.display_main_items_list_item_meta = defaults.display_main_items_list_item_meta
+            }
+            "compact_lists" => self.compact_lists = defaults.compact_lists,
+            "hidden_tabs" => self.hidden_tabs = defaults.hidden_tabs,
+            "show_contextual_help" => self.show_contextual_help = defaults.show_contextual_help,
+            "enable_mouse" => self.enable_mouse = defaults.enable_mouse,
+            "default_stories_sorting" => {
+                self.default_stories_sorting = defaults.default_stories_sorting
+            }
+            "max_displayed_stories" => self.max_displayed_stories = defaults.max_displayed_stories,
+            "stories_refresh_interval_secs" => {
+                self.stories_refresh_interval_secs = defaults.stories_refresh_interval_secs
+            }
+            "comments_refresh_interval_secs" => {
+                self.comments_refresh_interval_secs = defaults.comments_refresh_interval_secs
+            }
+            "http_timeout_secs" => self.http_timeout_secs = defaults.http_timeout_secs,
+            "browsing_history_max_entries" => {
+                self.browsing_history_max_entries = defaults.browsing_history_max_entries
+            }
+            "browsing_history_retention_days" => {
+                self.browsing_history_retention_days = defaults.browsing_history_retention_days
+            }
+            "custom_theme" => self.custom_theme = defaults.custom_theme,
+            "killfile" => self.killfile = defaults.killfile,
+            "keybindings" => self.keybindings = defaults.keybindings,
+            _ => {
+                warn!("AppConfiguration: cannot reset unknown option \"{key}\"");
+                return;
+            }
+        }
+        (self.palette, self.palette_error) =
+            Self::resolve_palette(self.theme, self.custom_theme.as_ref());
+        (self.killfile_rules, self.killfile_error) = Self::resolve_killfile(self.killfile.as_ref());
+        (self.key_bindings, self.key_bindings_error) =
+            Self::resolve_key_bindings(&self.keybindings);
+        self.save_option_to_file(key);
+    }
+
+    /// Get the errors found in the overriding environment variables, if any.
+    pub fn get_environment_overrides_error(&self) -> Option<&str> {
+        self.overrides.get_environment_error()
+    }
+
+    /// Get the errors found in the numeric options at load time, if any.
+    pub fn get_options_error(&self) -> Option<&str> {
+        self.options_error.as_deref()
+    }
+
+    pub fn get_key_bindings(&self) -> &KeyBindings {
+        &self.key_bindings
+    }
+
+    /// Get the error found in the user-defined key bindings at load time, if any.
+    pub fn get_key_bindings_error(&self) -> Option<&str> {
+        self.key_bindings_error.as_deref()
+    }
+
+    pub fn get_key_bindings_preset(&self) -> KeyBindingsPreset {
+        self.keybindings.preset
+    }
+
+    /// Set the key bindings preset, on top of which the user-defined key bindings still apply.
+    pub fn set_key_bindings_preset(&mut self, preset: KeyBindingsPreset) {
+        self.keybindings.preset = preset;
+        (self.key_bindings, self.key_bindings_error) =
+            Self::resolve_key_bindings(&self.keybindings);
+        self.save_option_to_file("keybindings");
+    }
+
+    /// Compute the effective key bindings, falling back to the preset ones on error.
+    fn resolve_key_bindings(
+        keybindings: &KeyBindingsConfiguration,
+    ) -> (KeyBindings, Option<String>) {
+        match keybindings.to_key_bindings() {
+            Ok(key_bindings) => (key_bindings, None),
+            Err(why) => {
+                warn!("AppConfiguration: invalid key bindings, using the preset ones. {why}");
+                (
+                    KeyBindings::from_preset(keybindings.preset),
+                    Some(why.to_string()),
+                )
+            }
+        }
+    }
+
+    fn save_to_file(&mut self) -> Result<()> {
+        let config_filepath = self.get_file_path()?;
+        let _lock = Self::lock_file(&config_filepath)?;
+        let is_modified_by_others =
+            Self::get_config_file_modified_at(&config_filepath) != self.synchronized_at;
+
+        // update the existing document to keep the user's formatting and comments
+        let mut document = read_to_string(&config_filepath)
+            .ok()
+            .and_then(|existing_raw| parse_document(&existing_raw).ok())
+            .unwrap_or_default();
+        if get_schema_version(&document)? > CONFIG_SCHEMA_VERSION {
+            return Err(HnCliError::ConfigSynchronizationError(format!(
+                "config file ({}) was written by a newer hncli version",
+                config_filepath.display()
+            )));
+        }
+        set_schema_version(&mut document, CONFIG_SCHEMA_VERSION);
+        let config_raw = toml::to_string(self).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!("cannot serialize config: {err}"))
+        })?;
+        // the overridden values must not be persisted, and the unset ones are removed
+        let mut serialized_document = parse_document(&config_raw)?;
+        let synchronized_document = self
+            .synchronized_raw
+            .as_deref()
+            .and_then(|synchronized_raw| parse_document(synchronized_raw).ok());
+        for key in CONFIG_OPTIONS_KEYS {
+            // the options unchanged here may have been changed by another instance
+            let is_unchanged = synchronized_document.as_ref().is_some_and(|document| {
+                document.get(key).map(ToString::to_string)
+                    == serialized_document.get(key).map(ToString::to_string)
+            });
+            if self.overrides.contains(key) || is_unchanged {
+                serialized_document.remove(key);
+            } else if !serialized_document.contains_key(key) {
+                document.remove(key);
+            }
+        }
+        update_table(document.as_table_mut(), serialized_document.as_table());
+
+        write_atomically(&config_filepath, &document.to_string()).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!(
+                "cannot save config file ({}): {}",
+                config_filepath.display(),
+                err
+            ))
+        })?;
+        // our own changes must not trigger a reload, unlike the merged ones of other instances
+        if !is_modified_by_others {
+            self.synchronized_at = Self::get_config_file_modified_at(&config_filepath);
+        }
+        self.synchronized_raw = Some(config_raw);
+        self.value_sources = self.overrides.get_value_sources(&document);
+
+        Ok(())
+    }
+
+    /// Create the config directory if needed, then lock the config file.
+    fn lock_file(config_filepath: &Path) -> Result<FileLock> {
         let config_directory = config_filepath
             .parent()
-            .expect("AppConfiguration.save_to_file: config filepath parent folder can be read");
+            .expect("AppConfiguration.lock_file: config filepath parent folder can be read");
         create_dir_all(config_directory).map_err(|err| {
             HnCliError::ConfigSynchronizationError(format!(
                 "cannot create config directory ({}): {}",
@@ -127,21 +798,16 @@ impl AppConfiguration {
                 err
             ))
         })?;
-
-        let config_raw = toml::to_string(self).map_err(|err| {
-            HnCliError::ConfigSynchronizationError(format!("cannot serialize config: {err}"))
-        })?;
-
-        write(&config_filepath, config_raw).map_err(|err| {
+        FileLock::acquire(config_filepath).map_err(|err| {
             HnCliError::ConfigSynchronizationError(format!(
-                "cannot save config file ({}): {}",
+                "cannot lock config file ({}): {}",
                 config_filepath.display(),
                 err
             ))
         })
     }
 
-    fn save_to_file_warn_if_fail(&self) {
+    fn save_to_file_warn_if_fail(&mut self) {
         match self.save_to_file() {
             Ok(()) => (),
             Err(why) => {
@@ -150,11 +816,16 @@ impl AppConfiguration {
         }
     }
 
-    fn from_file_or_environment() -> Result<Self> {
-        let config_filepath = Self::get_config_file_path()?;
-
+    fn from_file_or_environment(
+        config_filepath: PathBuf,
+        overrides: &ConfigOverrides,
+    ) -> Result<Self> {
+        let with_file_path = |mut config: Self| {
+            config.file_path = Some(config_filepath.clone());
+            config
+        };
         let load_defaults_and_save = || {
-            let default_config = Self::default();
+            let mut default_config = with_file_path(Self::from_defaults(overrides));
             if let Err(why) = default_config.save_to_file() {
                 warn!("{why}");
             }
@@ -195,47 +866,509 @@ impl AppConfiguration {
             Ok(raw) => raw,
             Err(why) => {
                 warn!("{why}");
-                return Ok(Self::default());
+                return Ok(with_file_path(Self::from_defaults(overrides)));
+            }
+        };
+
+        // Migrate
+        let config_raw = match Self::migrate_file(&config_filepath, &config_raw) {
+            Ok(Some(migrated_raw)) => migrated_raw,
+            Ok(None) => config_raw,
+            Err(why) => {
+                warn!("{why}");
+                config_raw
             }
         };
 
         // Deserialize
-        let deserializable_config: DeserializableAppConfiguration =
-            match toml::from_str(&config_raw).map_err(|err| {
-                HnCliError::ConfigSynchronizationError(format!("cannot deserialize config: {err}"))
-            }) {
-                Ok(read_config) => read_config,
-                Err(why) => {
-                    warn!("{why}");
-                    return Ok(Self::default());
-                }
-            };
+        let mut config = match Self::from_raw(&config_raw, overrides) {
+            Ok(config) => config,
+            Err(why) => {
+                warn!("{why}");
+                return Ok(with_file_path(Self::from_defaults(overrides)));
+            }
+        };
+        config.synchronized_at = Self::get_config_file_modified_at(&config_filepath);
+        config.synchronized_raw = toml::to_string(&config).ok();
+
+        Ok(with_file_path(config))
+    }
+
+    /// Migrate the config file to the current schema version if any of its options
+    /// changed, after backing up the previous one, and returns the migrated raw configuration.
+    ///
+    /// NB: a file whose version only changed is left as is, until its next save.
+    fn migrate_file(config_filepath: &Path, config_raw: &str) -> Result<Option<String>> {
+        let mut document = parse_document(config_raw)?;
+        let Some(previous_version) = migrate_document(&mut document)? else {
+            return Ok(None);
+        };
+
+        let backup_filepath =
+            config_filepath.with_extension(format!("toml.v{previous_version}.bak"));
+        copy(config_filepath, &backup_filepath).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!(
+                "cannot back up config file ({}): {}",
+                backup_filepath.display(),
+                err
+            ))
+        })?;
+        let migrated_raw = document.to_string();
+        write_atomically(config_filepath, &migrated_raw).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!(
+                "cannot save migrated config file ({}): {}",
+                config_filepath.display(),
+                err
+            ))
+        })?;
+        info!(
+            "config file migrated from schema version {previous_version} to {CONFIG_SCHEMA_VERSION}, previous one backed up to {}",
+            backup_filepath.display()
+        );
+
+        Ok(Some(migrated_raw))
+    }
+
+    /// Reload the configuration if the config file was modified since the last
+    /// load or save, and returns true if so.
+    ///
+    /// On error, the current configuration is kept as is.
+    pub fn reload_from_file_if_modified(&mut self) -> Result<bool> {
+        let config_filepath = self.get_file_path()?;
+        let modified_at = Self::get_config_file_modified_at(&config_filepath);
+        if modified_at.is_none() || modified_at == self.synchronized_at {
+            return Ok(false);
+        }
+        // do not retry until the next modification
+        self.synchronized_at = modified_at;
+
+        let config_raw = read_to_string(&config_filepath).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!(
+                "cannot open config file ({}): {}",
+                config_filepath.display(),
+                err
+            ))
+        })?;
+        let revision = self.revision + 1;
+        let reloaded = Self::from_raw(&config_raw, &self.overrides)?;
+        *self = Self {
+            profile: std::mem::take(&mut self.profile),
+            ..reloaded
+        };
+        self.file_path = Some(config_filepath);
+        self.synchronized_at = modified_at;
+        self.synchronized_raw = toml::to_string(self).ok();
+        self.revision = revision;
+
+        Ok(true)
+    }
+
+    /// Get the revision of the configuration, incremented on each reload from the file.
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Read the raw config file, with the user's formatting and comments, to export it.
+    pub fn export_file_raw(&self) -> Result<String> {
+        let config_filepath = self.get_file_path()?;
+        read_to_string(&config_filepath).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!(
+                "cannot open config file ({}): {}",
+                config_filepath.display(),
+                err
+            ))
+        })
+    }
+
+    /// Import a raw config file exported by another hncli installation, once validated
+    /// and migrated, either merged into the config file or replacing it.
+    ///
+    /// When merging, the imported options take precedence over the existing ones.
+    /// The configuration itself is left as is, the config file being reloaded on next launch.
+    pub fn import_file_raw(&self, imported_raw: &str, mode: ImportMode) -> Result<()> {
+        let mut imported_document = parse_document(imported_raw)?;
+        migrate_document(&mut imported_document)?;
+        Self::from_document(&imported_document, &ConfigOverrides::default())?;
+
+        let config_filepath = self.get_file_path()?;
+        let _lock = Self::lock_file(&config_filepath)?;
+        let document = match mode {
+            ImportMode::Merge => {
+                let mut document = read_to_string(&config_filepath)
+                    .ok()
+                    .and_then(|existing_raw| parse_document(&existing_raw).ok())
+                    .unwrap_or_default();
+                migrate_document(&mut document)?;
+                update_table(document.as_table_mut(), imported_document.as_table());
+                document
+            }
+            ImportMode::Replace => imported_document,
+        };
+        write_atomically(&config_filepath, &document.to_string()).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!(
+                "cannot save config file ({}): {}",
+                config_filepath.display(),
+                err
+            ))
+        })
+    }
+
+    fn get_config_file_modified_at(config_filepath: &Path) -> Option<SystemTime> {
+        metadata(config_filepath)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// Parse the configuration from the raw TOML, migrated to the current schema version,
+    /// using the default values for the missing ones.
+    fn from_raw(config_raw: &str, overrides: &ConfigOverrides) -> Result<Self> {
+        let mut document = parse_document(config_raw)?;
+        migrate_document(&mut document)?;
+        Self::from_document(&document, overrides)
+    }
+
+    /// Get the default configuration, with the given overrides on top of it.
+    fn from_defaults(overrides: &ConfigOverrides) -> Self {
+        Self::from_document(&DocumentMut::new(), overrides).unwrap_or_else(|why| {
+            warn!("AppConfiguration: cannot apply the overrides, skipping. {why}");
+            Self::default()
+        })
+    }
+
+    fn from_document(file_document: &DocumentMut, overrides: &ConfigOverrides) -> Result<Self> {
+        let value_sources = overrides.get_value_sources(file_document);
+        let mut document = file_document.clone();
+        overrides.apply(&mut document);
+
+        let table: toml::Table = toml::from_str(&document.to_string()).map_err(|err| {
+            HnCliError::ConfigSynchronizationError(format!("cannot deserialize config: {err}"))
+        })?;
+        let mut options_errors = vec![];
+        let deserializable_config =
+            DeserializableAppConfiguration::from_table(&table, &mut options_errors);
+        for why in &options_errors {
+            warn!("AppConfiguration: invalid option, using the default value. {why}");
+        }
+
+        let keybindings = deserializable_config.keybindings.unwrap_or_default();
+        let (key_bindings, key_bindings_error) = Self::resolve_key_bindings(&keybindings);
+        let theme = deserializable_config.theme.unwrap_or_default();
+        let custom_theme = deserializable_config.custom_theme;
+        let (palette, palette_error) = Self::resolve_palette(theme, custom_theme.as_ref());
+        let killfile = deserializable_config.killfile;
+        let (killfile_rules, killfile_error) = Self::resolve_killfile(killfile.as_ref());
+
+        let max_displayed_stories = validate_option_range(
+            "max_displayed_stories",
+            deserializable_config.max_displayed_stories,
+            MAX_DISPLAYED_STORIES_RANGE,
+            MAX_DISPLAYED_STORIES_DEFAULT,
+            &mut options_errors,
+        );
+        let stories_refresh_interval_secs = validate_option_range(
+            "stories_refresh_interval_secs",
+            deserializable_config.stories_refresh_interval_secs,
+            REFRESH_INTERVAL_SECS_RANGE,
+            STORIES_REFRESH_INTERVAL_SECS_DEFAULT,
+            &mut options_errors,
+        );
+        let comments_refresh_interval_secs = validate_option_range(
+            "comments_refresh_interval_secs",
+            deserializable_config.comments_refresh_interval_secs,
+            REFRESH_INTERVAL_SECS_RANGE,
+            COMMENTS_REFRESH_INTERVAL_SECS_DEFAULT,
+            &mut options_errors,
+        );
+        let http_timeout_secs = validate_option_range(
+            "http_timeout_secs",
+            deserializable_config.http_timeout_secs,
+            HTTP_TIMEOUT_SECS_RANGE,
+            HTTP_TIMEOUT_SECS_DEFAULT,
+            &mut options_errors,
+        );
+        let browsing_history_max_entries = validate_option_range(
+            "browsing_history_max_entries",
+            deserializable_config.browsing_history_max_entries,
+            BROWSING_HISTORY_MAX_ENTRIES_RANGE,
+            BROWSING_HISTORY_MAX_ENTRIES_DEFAULT,
+            &mut options_errors,
+        );
+        let browsing_history_retention_days = validate_option_range(
+            "browsing_history_retention_days",
+            deserializable_config.browsing_history_retention_days,
+            BROWSING_HISTORY_RETENTION_DAYS_RANGE,
+            BROWSING_HISTORY_RETENTION_DAYS_DEFAULT,
+            &mut options_errors,
+        );
+        let hidden_tabs = validate_hidden_tabs(
+            deserializable_config.hidden_tabs.unwrap_or_default(),
+            &mut options_errors,
+        );
+        let options_error = (!options_errors.is_empty()).then(|| options_errors.join(", "));
 
         Ok(Self {
-            theme: deserializable_config.theme.unwrap_or_default(),
+            theme,
             enable_global_sub_screen_quit_shortcut: deserializable_config
                 .enable_global_sub_screen_quit_shortcut
                 .unwrap_or(ENABLE_GLOBAL_SUB_SCREEN_QUIT_SHORTCUT_DEFAULT),
             display_comments_panel_by_default: deserializable_config
                 .display_comments_panel_by_default
                 .unwrap_or(DISPLAY_COMMENTS_PANEL_BY_DEFAULT_DEFAULT),
+            display_story_preview_panel: deserializable_config
+                .display_story_preview_panel
+                .unwrap_or(DISPLAY_STORY_PREVIEW_PANEL_DEFAULT),
             display_main_items_list_item_meta: deserializable_config
                 .display_main_items_list_item_meta
                 .unwrap_or(DISPLAY_MAIN_ITEMS_LIST_ITEM_META),
+            compact_lists: deserializable_config
+                .compact_lists
+                .unwrap_or(COMPACT_LISTS_DEFAULT),
+            hidden_tabs,
             show_contextual_help: deserializable_config
                 .show_contextual_help
                 .unwrap_or(SHOW_CONTEXTUAL_HELP_DEFAULT),
+            enable_mouse: deserializable_config
+                .enable_mouse
+                .unwrap_or(ENABLE_MOUSE_DEFAULT),
+            default_stories_sorting: deserializable_config
+                .default_stories_sorting
+                .unwrap_or(DEFAULT_STORIES_SORTING_DEFAULT),
+            max_displayed_stories,
+            stories_refresh_interval_secs,
+            comments_refresh_interval_secs,
+            http_timeout_secs,
+            browsing_history_max_entries,
+            browsing_history_retention_days,
+            custom_theme,
+            killfile,
+            keybindings,
+            key_bindings,
+            key_bindings_error,
+            palette,
+            palette_error,
+            killfile_rules,
+            killfile_error,
+            options_error,
+            profile: DEFAULT_PROFILE.into(),
+            file_path: None,
+            synchronized_at: None,
+            synchronized_raw: None,
+            revision: 0,
+            overrides: overrides.clone(),
+            value_sources,
+        })
+    }
+
+    fn get_file_path(&self) -> Result<PathBuf> {
+        self.file_path.clone().ok_or_else(|| {
+            HnCliError::ConfigSynchronizationError("cannot get the config file path".into())
         })
     }
 
-    fn get_config_file_path() -> Result<PathBuf> {
-        get_project_os_directory().map(|directory| directory.join("hncli.toml"))
+    fn get_profile_config_file_path(profile: &str) -> Result<PathBuf> {
+        get_profile_directory(profile).map(|directory| directory.join("hncli.toml"))
+    }
+}
+
+/// Deserialize a top-level option, if defined, reporting it as invalid otherwise.
+fn deserialize_option<T: DeserializeOwned>(
+    table: &toml::Table,
+    key: &str,
+    errors: &mut Vec<String>,
+) -> Option<T> {
+    match table.get(key)?.clone().try_into() {
+        Ok(value) => Some(value),
+        Err(err) => {
+            errors.push(format!("{key} is invalid ({})", err.message().trim()));
+            None
+        }
+    }
+}
+
+/// Check the value of a numeric option is within its range.
+fn check_option_range<T: PartialOrd + Display>(
+    key: &str,
+    value: T,
+    range: RangeInclusive<T>,
+) -> std::result::Result<T, String> {
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!(
+            "{key} must be between {} and {} (got {value})",
+            range.start(),
+            range.end()
+        ))
+    }
+}
+
+/// Check the value of a numeric option is within its range, falling back to its default
+/// value otherwise.
+fn validate_option_range<T: PartialOrd + Display>(
+    key: &str,
+    value: Option<T>,
+    range: RangeInclusive<T>,
+    default: T,
+    errors: &mut Vec<String>,
+) -> T {
+    match value.map(|value| check_option_range(key, value, range)) {
+        Some(Ok(value)) => value,
+        Some(Err(why)) => {
+            warn!("AppConfiguration: invalid option, using the default value. {why}");
+            errors.push(why);
+            default
+        }
+        None => default,
     }
 }
 
+/// Check the hidden navigation tabs exist and can be hidden, the other ones being skipped.
+///
+/// The titles are matched case-insensitively, then normalized.
+fn validate_hidden_tabs(hidden_tabs: Vec<String>, errors: &mut Vec<String>) -> Vec<String> {
+    hidden_tabs
+        .into_iter()
+        .filter_map(|hidden_tab| {
+            let Some(title) = TABS_TITLES
+                .iter()
+                .find(|title| title.eq_ignore_ascii_case(hidden_tab.trim()))
+            else {
+                let why = format!("hidden_tabs: unknown tab \"{hidden_tab}\"");
+                warn!("AppConfiguration: invalid option, skipping the tab. {why}");
+                errors.push(why);
+                return None;
+            };
+            if ALWAYS_VISIBLE_TABS_TITLES.contains(title) {
+                let why = format!("hidden_tabs: the \"{title}\" tab cannot be hidden");
+                warn!("AppConfiguration: invalid option, skipping the tab. {why}");
+                errors.push(why);
+                return None;
+            }
+            Some(title.to_string())
+        })
+        .collect()
+}
+
 pub fn get_project_os_directory() -> Result<PathBuf> {
     let project_directories = ProjectDirs::from("", "pierreyoda", "hncli").ok_or_else(|| {
         HnCliError::ConfigSynchronizationError("cannot get hncli config directory from OS".into())
     })?;
     Ok(project_directories.config_dir().to_path_buf())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::theme::UiTheme;
+
+    use super::{
+        AppConfiguration, ConfigOverrides, HTTP_TIMEOUT_SECS_DEFAULT,
+        MAX_DISPLAYED_STORIES_DEFAULT, SHOW_CONTEXTUAL_HELP_DEFAULT,
+    };
+
+    #[test]
+    fn test_config_from_raw() {
+        let config = AppConfiguration::from_raw(
+            r#"
+            theme = "Light"
+            display_comments_panel_by_default = true
+            "#,
+            &ConfigOverrides::default(),
+        )
+        .unwrap();
+        assert_eq!(config.get_ui_theme(), UiTheme::Light);
+        assert!(config.get_display_comments_panel_by_default());
+        assert_eq!(
+            config.get_show_contextual_help(),
+            SHOW_CONTEXTUAL_HELP_DEFAULT
+        );
+
+        assert!(AppConfiguration::from_raw("theme = ", &ConfigOverrides::default()).is_err());
+        assert!(AppConfiguration::from_raw("version = 999", &ConfigOverrides::default()).is_err());
+    }
+
+    #[test]
+    fn test_config_invalid_options() {
+        // the invalid options are reported and skipped, the valid ones being kept
+        let config = AppConfiguration::from_raw(
+            r#"
+            theme = 42
+            max_displayed_stories = "100"
+            show_contextual_help = false
+            keybindings = "Vim"
+            "#,
+            &ConfigOverrides::default(),
+        )
+        .unwrap();
+        assert_eq!(config.get_ui_theme(), UiTheme::default());
+        assert_eq!(
+            config.get_max_displayed_stories(),
+            MAX_DISPLAYED_STORIES_DEFAULT
+        );
+        assert!(!config.get_show_contextual_help());
+        let options_error = config.get_options_error().unwrap();
+        for key in ["theme", "max_displayed_stories", "keybindings"] {
+            assert!(
+                options_error.contains(&format!("{key} is invalid")),
+                "{key}"
+            );
+        }
+        assert!(!options_error.contains("show_contextual_help"));
+    }
+
+    #[test]
+    fn test_config_hidden_tabs() {
+        let config = AppConfiguration::from_raw(
+            r#"
+            compact_lists = true
+            hidden_tabs = ["jobs", " Hiring ", "Settings", "Unknown"]
+            "#,
+            &ConfigOverrides::default(),
+        )
+        .unwrap();
+        assert!(config.get_compact_lists());
+        assert_eq!(config.get_hidden_tabs(), ["Jobs", "Hiring"]);
+        let options_error = config.get_options_error().unwrap();
+        assert!(options_error.contains("\"Settings\" tab cannot be hidden"));
+        assert!(options_error.contains("unknown tab \"Unknown\""));
+    }
+
+    #[test]
+    fn test_config_options_validation() {
+        let config = AppConfiguration::from_raw(
+            r#"
+            max_displayed_stories = 100
+            http_timeout_secs = 0
+            browsing_history_retention_days = 90
+            "#,
+            &ConfigOverrides::default(),
+        )
+        .unwrap();
+        assert_eq!(config.get_max_displayed_stories(), 100);
+        assert_eq!(config.get_browsing_history_retention_days(), 90);
+        assert_eq!(
+            config.get_http_timeout().as_secs(),
+            HTTP_TIMEOUT_SECS_DEFAULT
+        );
+        assert!(
+            config
+                .get_options_error()
+                .is_some_and(|why| why.starts_with("http_timeout_secs"))
+        );
+
+        let mut config = config;
+        assert!(config.set_max_displayed_stories(5).is_err());
+        assert!(
+            config
+                .set_custom_theme_color("main", Some("not-a-color"))
+                .is_err()
+        );
+        assert!(
+            config
+                .set_custom_theme_color("unknown", Some("red"))
+                .is_err()
+        );
+        assert!(config.set_custom_theme_base(UiTheme::Custom).is_err());
+        assert_eq!(config.get_max_displayed_stories(), 100);
+        assert!(config.get_custom_theme().is_none());
+    }
+}
@@ -0,0 +1,115 @@
+use toml_edit::{DocumentMut, Item, Table, Value};
+
+use crate::errors::{HnCliError, Result};
+
+/// Parse a configuration document, keeping its formatting and comments.
+pub fn parse_document(config_raw: &str) -> Result<DocumentMut> {
+    config_raw.parse().map_err(|err| {
+        HnCliError::ConfigSynchronizationError(format!("cannot parse config: {err}"))
+    })
+}
+
+/// Update a TOML table with the values of another one, keeping the formatting
+/// and comments of the unchanged values.
+///
+/// NB: the keys missing from the `source` table are kept as is, to preserve the unknown
+/// ones, unlike in the nested tables.
+pub fn update_table(target: &mut Table, source: &Table) {
+    update_table_with(target, source, false);
+}
+
+fn update_table_with(target: &mut Table, source: &Table, remove_missing_keys: bool) {
+    if remove_missing_keys {
+        target.retain(|key, _| source.contains_key(key));
+    }
+    for (key, source_item) in source.iter() {
+        match (target.get_mut(key), source_item) {
+            (Some(Item::Table(target_table)), Item::Table(source_table)) => {
+                update_table_with(target_table, source_table, true)
+            }
+            (Some(Item::Value(target_value)), Item::Value(source_value)) => {
+                if !are_values_equal(target_value, source_value) {
+                    let decor = target_value.decor().clone();
+                    *target_value = source_value.clone();
+                    *target_value.decor_mut() = decor;
+                }
+            }
+            (Some(target_item), _) => *target_item = source_item.clone(),
+            (None, _) => {
+                target.insert(key, source_item.clone());
+            }
+        }
+    }
+}
+
+/// Compare two TOML values, regardless of their formatting.
+fn are_values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.value() == b.value(),
+        (Value::Integer(a), Value::Integer(b)) => a.value() == b.value(),
+        (Value::Float(a), Value::Float(b)) => a.value() == b.value(),
+        (Value::Boolean(a), Value::Boolean(b)) => a.value() == b.value(),
+        (Value::Datetime(a), Value::Datetime(b)) => a.value() == b.value(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| are_values_equal(a, b))
+        }
+        (Value::InlineTable(a), Value::InlineTable(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| are_values_equal(a, b)))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_document, update_table};
+
+    #[test]
+    fn test_update_table() {
+        let mut target = parse_document(
+            r#"# hncli configuration
+theme = "Light" # for daytime
+show_contextual_help = true
+unknown = 1
+
+# vim, but with my own help key
+[keybindings]
+preset = 'Vim'
+toggle_help = [ "?" ]
+navigate_up = "k"
+"#,
+        )
+        .unwrap();
+        let source = parse_document(
+            r#"theme = "Blue"
+show_contextual_help = true
+display_comments_panel_by_default = false
+
+[keybindings]
+preset = "Vim"
+toggle_help = ["?"]
+quit = "ctrl+c"
+"#,
+        )
+        .unwrap();
+        update_table(target.as_table_mut(), source.as_table());
+
+        assert_eq!(
+            target.to_string(),
+            r#"# hncli configuration
+theme = "Blue" # for daytime
+show_contextual_help = true
+unknown = 1
+display_comments_panel_by_default = false
+
+# vim, but with my own help key
+[keybindings]
+preset = 'Vim'
+toggle_help = [ "?" ]
+quit = "ctrl+c"
+"#
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use toml_edit::{DocumentMut, Item, value};
+
+use crate::errors::{HnCliError, Result};
+
+/// Current version of the configuration schema.
+///
+/// Must be bumped alongside each new migration.
+pub const CONFIG_SCHEMA_VERSION: i64 = 2;
+
+/// Schema version of the configuration files written before versioning.
+const UNVERSIONED_SCHEMA_VERSION: i64 = 1;
+
+const VERSION_KEY: &str = "version";
+
+/// A migration updates, in place, a configuration document from one schema
+/// version to the next one, and returns whether it changed any option.
+type Migration = fn(&mut DocumentMut) -> bool;
+
+/// Ordered migrations pipeline, the first one migrating from the unversioned schema.
+const MIGRATIONS: &[Migration] = &[migrate_unversioned_to_v2];
+
+const _: () = assert!(
+    UNVERSIONED_SCHEMA_VERSION + MIGRATIONS.len() as i64 == CONFIG_SCHEMA_VERSION,
+    "each schema version must have its migration"
+);
+
+/// Get the schema version of a configuration document.
+pub fn get_schema_version(document: &DocumentMut) -> Result<i64> {
+    match document.get(VERSION_KEY) {
+        None => Ok(UNVERSIONED_SCHEMA_VERSION),
+        Some(item) => item
+            .as_integer()
+            .filter(|version| *version >= UNVERSIONED_SCHEMA_VERSION)
+            .ok_or_else(|| {
+                HnCliError::ConfigSynchronizationError(format!(
+                    "invalid config schema version: {}",
+                    item.to_string().trim()
+                ))
+            }),
+    }
+}
+
+/// Set the schema version of a configuration document, keeping its formatting.
+pub fn set_schema_version(document: &mut DocumentMut, version: i64) {
+    match document.get_mut(VERSION_KEY) {
+        Some(Item::Value(current)) => {
+            let decor = current.decor().clone();
+            *current = version.into();
+            *current.decor_mut() = decor;
+        }
+        _ => {
+            document.insert(VERSION_KEY, value(version));
+        }
+    }
+}
+
+/// Migrate a configuration document to the current schema version.
+///
+/// Returns the schema version it was migrated from, if any migration changed its options,
+/// *i.e.* not only its version.
+pub fn migrate_document(document: &mut DocumentMut) -> Result<Option<i64>> {
+    let version = get_schema_version(document)?;
+    if version > CONFIG_SCHEMA_VERSION {
+        return Err(HnCliError::ConfigSynchronizationError(format!(
+            "config schema version {version} is newer than the supported one ({CONFIG_SCHEMA_VERSION}), please update hncli"
+        )));
+    }
+    if version == CONFIG_SCHEMA_VERSION {
+        return Ok(None);
+    }
+
+    let pending_migrations = MIGRATIONS
+        .iter()
+        .skip((version - UNVERSIONED_SCHEMA_VERSION) as usize);
+    let mut changed = false;
+    for (migration, migrated_version) in pending_migrations.zip(version + 1..) {
+        changed |= migration(document);
+        set_schema_version(document, migrated_version);
+    }
+
+    Ok(changed.then_some(version))
+}
+
+/// The unversioned schema is the same as the version 2 one, which only introduced
+/// the `version` key itself.
+fn migrate_unversioned_to_v2(_document: &mut DocumentMut) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use toml_edit::DocumentMut;
+
+    use super::{CONFIG_SCHEMA_VERSION, get_schema_version, migrate_document};
+
+    #[test]
+    fn test_migrate_document() {
+        let mut unversioned: DocumentMut = "# my theme\ntheme = \"Light\" # for daytime\n"
+            .parse()
+            .unwrap();
+        // only the version changed
+        assert_eq!(migrate_document(&mut unversioned).unwrap(), None);
+        assert_eq!(
+            get_schema_version(&unversioned).unwrap(),
+            CONFIG_SCHEMA_VERSION
+        );
+        assert!(
+            unversioned
+                .to_string()
+                .starts_with("# my theme\ntheme = \"Light\" # for daytime\nversion = ")
+        );
+
+        let mut current: DocumentMut = format!("version = {CONFIG_SCHEMA_VERSION}")
+            .parse()
+            .unwrap();
+        assert_eq!(migrate_document(&mut current).unwrap(), None);
+
+        let mut newer: DocumentMut = format!("version = {}", CONFIG_SCHEMA_VERSION + 1)
+            .parse()
+            .unwrap();
+        assert!(migrate_document(&mut newer).is_err());
+        let mut invalid: DocumentMut = "version = \"2\"".parse().unwrap();
+        assert!(migrate_document(&mut invalid).is_err());
+    }
+}
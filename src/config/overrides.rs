@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use log::warn;
+use toml_edit::{DocumentMut, Item, Value};
+
+use crate::errors::{HnCliError, Result};
+
+use super::{CONFIG_OPTIONS_KEYS, DeserializableAppConfiguration};
+
+/// Prefix of the environment variables overriding the configuration options,
+/// for instance `HNCLI_THEME=Light`.
+pub const ENVIRONMENT_VARIABLES_PREFIX: &str = "HNCLI_";
+
+/// Where the effective value of a configuration option comes from, by increasing precedence.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigValueSource {
+    Default,
+    File,
+    Environment,
+    CommandLine,
+}
+
+impl ConfigValueSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::File => "config file",
+            Self::Environment => "environment",
+            Self::CommandLine => "command-line",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ConfigOverride {
+    value: Value,
+    source: ConfigValueSource,
+}
+
+/// Configuration values overriding the config file ones, from the environment
+/// variables and the command-line.
+///
+/// NB: they are never saved to the config file.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigOverrides {
+    overrides: BTreeMap<String, ConfigOverride>,
+    /// Errors in the environment variables, if any, in which case they are ignored.
+    environment_error: Option<String>,
+}
+
+impl ConfigOverrides {
+    /// Build the overrides from the environment variables and the command-line `KEY=VALUE`
+    /// settings, the latter taking precedence.
+    ///
+    /// Each value is parsed as a TOML value, or as a string if invalid: `HNCLI_THEME=Light`,
+    /// `HNCLI_SHOW_CONTEXTUAL_HELP=false` or `HNCLI_KEYBINDINGS='{ preset = "Vim" }'`.
+    ///
+    /// An invalid command-line setting is an error, while an invalid environment variable
+    /// is only reported then ignored, like an invalid config file value.
+    pub fn new(
+        environment_variables: impl IntoIterator<Item = (String, String)>,
+        command_line_settings: &[(String, String)],
+    ) -> Result<Self> {
+        let mut overrides = Self::default();
+        let mut environment_errors = vec![];
+        for (name, raw_value) in environment_variables {
+            let Some(key) = name.strip_prefix(ENVIRONMENT_VARIABLES_PREFIX) else {
+                continue;
+            };
+            let key = key.to_lowercase();
+            if !CONFIG_OPTIONS_KEYS.contains(&key.as_str()) {
+                warn!("ConfigOverrides: ignoring unknown configuration option {name}");
+                continue;
+            }
+            if let Err(why) = overrides.insert(key, &raw_value, ConfigValueSource::Environment) {
+                warn!("ConfigOverrides: ignoring invalid environment variable {name}: {why}");
+                environment_errors.push(format!("{name}: {why}"));
+            }
+        }
+        overrides.environment_error =
+            (!environment_errors.is_empty()).then(|| environment_errors.join(", "));
+        for (key, raw_value) in command_line_settings {
+            if !CONFIG_OPTIONS_KEYS.contains(&key.as_str()) {
+                return Err(HnCliError::ConfigOverrideError(format!(
+                    "unknown configuration option \"{key}\""
+                )));
+            }
+            overrides
+                .insert(key.clone(), raw_value, ConfigValueSource::CommandLine)
+                .map_err(|why| HnCliError::ConfigOverrideError(format!("--set {key}: {why}")))?;
+        }
+        Ok(overrides)
+    }
+
+    /// Build the overrides from the current process environment and the command-line settings.
+    pub fn from_environment(command_line_settings: &[(String, String)]) -> Result<Self> {
+        Self::new(std::env::vars(), command_line_settings)
+    }
+
+    /// Insert an override, after checking its value is valid for the option.
+    fn insert(
+        &mut self,
+        key: String,
+        raw_value: &str,
+        source: ConfigValueSource,
+    ) -> std::result::Result<(), String> {
+        let value = raw_value
+            .trim()
+            .parse::<Value>()
+            .unwrap_or_else(|_| raw_value.into());
+
+        let mut document = DocumentMut::new();
+        document.insert(&key, Item::Value(value.clone()));
+        let table: toml::Table =
+            toml::from_str(&document.to_string()).map_err(|err| err.message().to_string())?;
+        let mut errors = vec![];
+        DeserializableAppConfiguration::from_table(&table, &mut errors);
+        if !errors.is_empty() {
+            return Err(errors.join(", "));
+        }
+
+        self.overrides.insert(key, ConfigOverride { value, source });
+        Ok(())
+    }
+
+    /// Get the errors found in the environment variables, if any.
+    pub fn get_environment_error(&self) -> Option<&str> {
+        self.environment_error.as_deref()
+    }
+
+    /// Remove the override of an option, for instance when changed from the settings screen.
+    pub fn remove(&mut self, key: &str) {
+        self.overrides.remove(key);
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.overrides.contains_key(key)
+    }
+
+    /// Apply the overrides on top of a configuration document.
+    pub fn apply(&self, document: &mut DocumentMut) {
+        for (key, config_override) in &self.overrides {
+            document.insert(key, Item::Value(config_override.value.clone()));
+        }
+    }
+
+    /// Get the source of the effective value of each option, given the config file document
+    /// the configuration was loaded from.
+    pub fn get_value_sources(
+        &self,
+        file_document: &DocumentMut,
+    ) -> BTreeMap<&'static str, ConfigValueSource> {
+        CONFIG_OPTIONS_KEYS
+            .iter()
+            .map(|key| {
+                let source = match self.overrides.get(*key) {
+                    Some(config_override) => config_override.source,
+                    None if file_document.contains_key(key) => ConfigValueSource::File,
+                    None => ConfigValueSource::Default,
+                };
+                (*key, source)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use toml_edit::DocumentMut;
+
+    use super::{ConfigOverrides, ConfigValueSource};
+
+    fn variables(variables: &[(&str, &str)]) -> Vec<(String, String)> {
+        variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_config_overrides_precedence() {
+        let overrides = ConfigOverrides::new(
+            variables(&[
+                ("HNCLI_THEME", "Light"),
+                ("HNCLI_SHOW_CONTEXTUAL_HELP", "false"),
+                ("HNCLI_UNKNOWN", "1"),
+                ("HOME", "/home/hncli"),
+            ]),
+            &variables(&[("theme", "Blue")]),
+        )
+        .unwrap();
+        let mut document: DocumentMut = "theme = \"Magenta\"\nshow_contextual_help = true\ndisplay_comments_panel_by_default = true\n"
+            .parse()
+            .unwrap();
+        let sources = overrides.get_value_sources(&document);
+        overrides.apply(&mut document);
+
+        assert_eq!(document["theme"].as_str(), Some("Blue"));
+        assert_eq!(document["show_contextual_help"].as_bool(), Some(false));
+        assert_eq!(sources["theme"], ConfigValueSource::CommandLine);
+        assert_eq!(
+            sources["show_contextual_help"],
+            ConfigValueSource::Environment
+        );
+        assert_eq!(
+            sources["display_comments_panel_by_default"],
+            ConfigValueSource::File
+        );
+        assert_eq!(
+            sources["enable_global_sub_screen_quit_shortcut"],
+            ConfigValueSource::Default
+        );
+
+        // invalid environment variables are ignored, but not invalid command-line settings
+        let overrides = ConfigOverrides::new(
+            variables(&[("HNCLI_THEME", "Purple"), ("HNCLI_ENABLE_MOUSE", "false")]),
+            &[],
+        )
+        .unwrap();
+        assert!(!overrides.contains("theme"));
+        assert!(overrides.contains("enable_mouse"));
+        assert!(
+            overrides
+                .get_environment_error()
+                .is_some_and(|why| why.starts_with("HNCLI_THEME"))
+        );
+        assert!(ConfigOverrides::new(vec![], &variables(&[("theme", "Purple")])).is_err());
+        assert!(ConfigOverrides::new(vec![], &variables(&[("unknown", "1")])).is_err());
+        assert!(
+            ConfigOverrides::new(
+                variables(&[("HNCLI_KEYBINDINGS", "{ preset = \"Vim\" }")]),
+                &[]
+            )
+            .is_ok()
+        );
+    }
+}
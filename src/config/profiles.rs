@@ -0,0 +1,120 @@
+use std::{
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
+
+use log::warn;
+
+use crate::errors::{HnCliError, Result};
+
+use super::get_project_os_directory;
+
+/// Name of the profile using the config and history files at the root of the config
+/// directory, as before profiles were introduced.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Sub-directory of the config directory holding the other profiles, one directory each.
+const PROFILES_DIRECTORY: &str = "profiles";
+
+/// Maximum length of a profile name.
+const MAX_PROFILE_NAME_LENGTH: usize = 32;
+
+/// Check a profile name can be used as a directory name, *e.g.* "work" or "home-2".
+pub fn validate_profile_name(name: &str) -> std::result::Result<(), String> {
+    let is_valid = !name.is_empty()
+        && name.len() <= MAX_PROFILE_NAME_LENGTH
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if is_valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid profile name \"{name}\": expected up to {MAX_PROFILE_NAME_LENGTH} letters, digits, '-' or '_'"
+        ))
+    }
+}
+
+/// Get the directory holding the config and history files of a profile.
+pub fn get_profile_directory(profile: &str) -> Result<PathBuf> {
+    get_project_os_directory().and_then(|directory| get_profile_directory_in(&directory, profile))
+}
+
+fn get_profile_directory_in(config_directory: &Path, profile: &str) -> Result<PathBuf> {
+    validate_profile_name(profile).map_err(HnCliError::InvalidOptionError)?;
+    Ok(if profile == DEFAULT_PROFILE {
+        config_directory.to_path_buf()
+    } else {
+        config_directory.join(PROFILES_DIRECTORY).join(profile)
+    })
+}
+
+/// List the existing profiles, the default one first and the others by name.
+pub fn list_profiles() -> Vec<String> {
+    match get_project_os_directory() {
+        Ok(directory) => list_profiles_in(&directory),
+        Err(why) => {
+            warn!("Profiles: cannot list the profiles. {why}");
+            vec![DEFAULT_PROFILE.into()]
+        }
+    }
+}
+
+fn list_profiles_in(config_directory: &Path) -> Vec<String> {
+    let mut profiles: Vec<String> = read_dir(config_directory.join(PROFILES_DIRECTORY))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| name != DEFAULT_PROFILE && validate_profile_name(name).is_ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    profiles.sort();
+    profiles.insert(0, DEFAULT_PROFILE.into());
+    profiles
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::{create_dir_all, remove_dir_all},
+        path::Path,
+    };
+
+    use super::{
+        DEFAULT_PROFILE, get_profile_directory_in, list_profiles_in, validate_profile_name,
+    };
+
+    #[test]
+    fn test_profiles() {
+        assert!(validate_profile_name("work").is_ok());
+        assert!(validate_profile_name("home_2-b").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../work").is_err());
+        assert!(validate_profile_name("my work").is_err());
+
+        let root = Path::new("/hncli");
+        assert_eq!(
+            get_profile_directory_in(root, DEFAULT_PROFILE).unwrap(),
+            root
+        );
+        assert_eq!(
+            get_profile_directory_in(root, "work").unwrap(),
+            root.join("profiles").join("work")
+        );
+
+        let config_directory = std::env::temp_dir().join("hncli_test_profiles");
+        let _ = remove_dir_all(&config_directory);
+        assert_eq!(list_profiles_in(&config_directory), vec![DEFAULT_PROFILE]);
+        for profile in ["work", "home", "not valid"] {
+            create_dir_all(config_directory.join("profiles").join(profile)).unwrap();
+        }
+        assert_eq!(
+            list_profiles_in(&config_directory),
+            vec![DEFAULT_PROFILE, "home", "work"]
+        );
+        remove_dir_all(&config_directory).unwrap();
+    }
+}
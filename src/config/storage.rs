@@ -0,0 +1,94 @@
+use std::{
+    fs::{File, OpenOptions, remove_file, rename},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+/// Exclusive advisory lock on a local data file, shared between the hncli instances.
+///
+/// The lock is held on a sibling `.lock` file, so that the data file itself can be
+/// atomically replaced, and is released when dropped.
+#[derive(Debug)]
+pub struct FileLock {
+    _lock_file: File,
+}
+
+impl FileLock {
+    /// Wait for, then acquire, the exclusive lock on the given data file.
+    pub fn acquire(file_path: &Path) -> io::Result<Self> {
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(with_appended_extension(file_path, "lock"))?;
+        lock_file.lock()?;
+        Ok(Self {
+            _lock_file: lock_file,
+        })
+    }
+}
+
+/// Write the given contents to the file, replacing it atomically: a crash mid-write
+/// leaves either the previous or the new version of the file, never a truncated one.
+pub fn write_atomically(file_path: &Path, contents: &str) -> io::Result<()> {
+    // unique per process, for the (unlocked) writes to never clobber each other
+    let temporary_path = with_appended_extension(file_path, &format!("{}.tmp", process::id()));
+    let written = File::create(&temporary_path).and_then(|mut temporary_file| {
+        temporary_file.write_all(contents.as_bytes())?;
+        temporary_file.sync_all()
+    });
+    match written.and_then(|()| rename(&temporary_path, file_path)) {
+        Ok(()) => Ok(()),
+        Err(why) => {
+            let _ = remove_file(&temporary_path);
+            Err(why)
+        }
+    }
+}
+
+/// `hncli.toml` becomes `hncli.toml.<extension>`.
+fn with_appended_extension(file_path: &Path, extension: &str) -> PathBuf {
+    let mut file_name = file_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(extension);
+    file_path.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env::temp_dir,
+        fs::{create_dir_all, read_dir, read_to_string, remove_dir_all},
+        process,
+    };
+
+    use super::{FileLock, with_appended_extension, write_atomically};
+
+    #[test]
+    fn test_write_atomically() {
+        let directory = temp_dir().join(format!("hncli-storage-test-{}", process::id()));
+        create_dir_all(&directory).unwrap();
+        let file_path = directory.join("history.json");
+        assert_eq!(
+            with_appended_extension(&file_path, "lock"),
+            directory.join("history.json.lock")
+        );
+
+        {
+            let _lock = FileLock::acquire(&file_path).unwrap();
+            write_atomically(&file_path, "first").unwrap();
+            write_atomically(&file_path, "second").unwrap();
+        }
+        assert_eq!(read_to_string(&file_path).unwrap(), "second");
+        // no temporary file is left behind
+        let mut entries: Vec<_> = read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        entries.sort();
+        assert_eq!(entries, vec!["history.json", "history.json.lock"]);
+
+        remove_dir_all(&directory).unwrap();
+    }
+}
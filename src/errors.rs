@@ -23,14 +23,30 @@ pub enum HnCliError {
     UiError(String),
     #[error("Config synchronization error: {0}")]
     ConfigSynchronizationError(String),
+    #[error("Config override error: {0}")]
+    ConfigOverrideError(String),
+    #[error("Invalid option: {0}")]
+    InvalidOptionError(String),
+    #[error("Key bindings error: {0}")]
+    KeyBindingsError(String),
+    #[error("Theme error: {0}")]
+    ThemeError(String),
+    #[error("Killfile error: {0}")]
+    KillfileError(String),
     #[error("History synchronization error: {0}")]
     HistorySynchronizationError(String),
+    #[error("Archive error: {0}")]
+    ArchiveError(String),
     #[error("URL parsing error")]
     UrlParsingError(#[from] ParseError),
     #[error("The HN item with ID {0} was not found")]
     ItemNotFound(HnItemIdScalar),
     #[error("The HN user with ID {0} was not found")]
     UserNotFound(String),
+    #[error("The HN thread \"{0}\" was not found")]
+    ThreadNotFound(String),
+    #[error("Network access is disabled in offline mode")]
+    OfflineError,
     #[error("The HN item with ID {0} could not be processed")]
     HnItemProcessingError(String),
 }
@@ -3,15 +3,22 @@ use std::{fs::File, io};
 extern crate log;
 extern crate simplelog;
 
+use clap::Parser;
 use simplelog::{Config, WriteLogger};
 
-use api::HnClient;
+use api::{HnClient, client::HnStoriesSections};
+use app::{AppStartup, history::AppHistory};
+use archive::{export_local_state, import_local_state};
+use cli::{CliArguments, CliCommand};
+use config::{AppConfiguration, ConfigOverrides};
 use errors::HnCliError;
 use ratatui::{Terminal, backend::CrosstermBackend};
-use ui::UserInterface;
+use ui::{UserInterface, displayable_item::DisplayableHackerNewsItem, router::AppRoute};
 
 mod api;
 mod app;
+mod archive;
+mod cli;
 mod config;
 mod errors;
 mod ui;
@@ -19,16 +26,56 @@ mod ui;
 // TODO: set terminal title (dynamically if possible)
 #[tokio::main]
 async fn main() -> Result<(), HnCliError> {
+    let arguments = CliArguments::parse();
+
     // File logger setup (mainly used for development purposes)
-    WriteLogger::init(
-        log::LevelFilter::Info,
-        Config::default(),
-        File::create("hncli_log.txt").map_err(HnCliError::IoError)?,
-    )
-    .expect("logging to file should be properly initialized");
+    if let Some(log_file) = &arguments.log_file {
+        WriteLogger::init(
+            log::LevelFilter::Info,
+            Config::default(),
+            File::create(log_file).map_err(HnCliError::IoError)?,
+        )
+        .expect("logging to file should be properly initialized");
+    }
+
+    // Configuration setup
+    let overrides = ConfigOverrides::from_environment(&arguments.settings)?;
+    let config =
+        AppConfiguration::from_file_or_defaults(arguments.config, &arguments.profile, overrides);
+
+    // Local state export & import, instead of the TUI
+    if let Some(command) = arguments.command {
+        let mut history = AppHistory::restored(config.get_profile());
+        return match command {
+            CliCommand::Export { path } => export_local_state(&config, &history, path.as_deref()),
+            CliCommand::Import { path, mode } => {
+                import_local_state(&config, &mut history, &path, mode.into())
+            }
+        };
+    }
 
     // HackerNews client setup
-    let client = HnClient::new()?;
+    let client = HnClient::new(arguments.offline, config.get_http_timeout())?;
+
+    // Startup navigation setup
+    let route = if let Some(item_id) = arguments.item {
+        let item = client.get_thread_root_item(item_id).await?;
+        Some(AppRoute::ItemDetails(DisplayableHackerNewsItem::try_from(
+            item,
+        )?))
+    } else if let Some(username) = arguments.user {
+        Some(AppRoute::UserProfile(username))
+    } else {
+        arguments.search.map(AppRoute::SearchResults)
+    };
+    let startup = AppStartup {
+        section: arguments
+            .section
+            .map(Into::into)
+            .unwrap_or(HnStoriesSections::Home),
+        route,
+        offline: arguments.offline,
+    };
 
     // TUI setup
     let stdout = io::stdout();
@@ -36,7 +83,7 @@ async fn main() -> Result<(), HnCliError> {
     let terminal = Terminal::new(backend).map_err(HnCliError::IoError)?;
 
     // UI setup & run
-    let mut ui = UserInterface::new(terminal, client)?;
+    let mut ui = UserInterface::new(terminal, client, config, startup)?;
     let events_receiver = ui.setup()?;
     ui.run(events_receiver).await
 }
@@ -7,9 +7,11 @@ use std::{
 };
 
 use crossterm::{
-    event::{self, Event, KeyEvent},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEvent, MouseEvent},
+    execute,
     terminal::{disable_raw_mode, enable_raw_mode},
 };
+use log::warn;
 use ratatui::{
     Terminal,
     backend::CrosstermBackend,
@@ -17,12 +19,25 @@ use ratatui::{
 };
 
 use common::{UiComponent, UiComponentId, UiTickScalar};
-use components::{help::Help, navigation::Navigation, options::Options, stories::StoriesPanel};
+use components::{
+    bookmarks::{filters::BookmarksFilters, list::BookmarksList},
+    browsing_history::{entries::BrowsingHistoryEntries, filter::BrowsingHistoryFilter},
+    date_picker::DatePicker,
+    help::Help,
+    navigation::Navigation,
+    options::Options,
+    stories::StoriesPanel,
+    story_preview::StoryPreview,
+    who_is_hiring::{
+        filters::WhoIsHiringFilters, posting_details::WhoIsHiringPostingDetails,
+        postings::WhoIsHiringPostings,
+    },
+};
 use utils::breakpoints::Breakpoints;
 
 use crate::{
     api::HnClient,
-    app::App,
+    app::{App, AppStartup},
     config::AppConfiguration,
     errors::{HnCliError, Result},
 };
@@ -52,6 +67,7 @@ pub mod displayable_item;
 pub mod flash;
 pub mod handlers;
 mod helper;
+pub mod keybindings;
 mod panels;
 pub mod router;
 pub mod screens;
@@ -63,6 +79,7 @@ type TerminalUi = Terminal<CrosstermBackend<Stdout>>;
 #[derive(Clone, Debug)]
 pub enum UserInterfaceEvent {
     KeyEvent(KeyEvent),
+    MouseEvent(MouseEvent),
     Tick,
 }
 
@@ -89,14 +106,27 @@ pub struct UserInterface {
     app: App,
     /// Components registry.
     components: HashMap<UiComponentId, ComponentWrapper>,
+    /// Are the mouse events currently captured?
+    mouse_captured: bool,
 }
 
 /** A UI tick is as close as possible to 100ms. */
 pub const UI_TICK_RATE_MS: u16 = 100;
 
+/// Check for changes in the config file every second.
+const CONFIG_RELOAD_CHECK_INTERVAL_TICKS: UiTickScalar = 10;
+
+/// Check for watched stories due for a snapshot every minute.
+const WATCHED_ITEMS_SNAPSHOT_CHECK_INTERVAL_TICKS: UiTickScalar = 600;
+
 impl UserInterface {
     /// Create a new `UserInterface` instance and prepare the terminal for it.
-    pub fn new(mut terminal: TerminalUi, client: HnClient) -> Result<Self> {
+    pub fn new(
+        mut terminal: TerminalUi,
+        client: HnClient,
+        config: AppConfiguration,
+        startup: AppStartup,
+    ) -> Result<Self> {
         enable_raw_mode()
             .map_err(|_| HnCliError::CrosstermError("enable_raw_mode error".into()))?;
         terminal
@@ -106,12 +136,12 @@ impl UserInterface {
             .hide_cursor()
             .map_err(|_| HnCliError::CrosstermError("hide_cursor error".into()))?;
 
-        let config = AppConfiguration::from_file_or_defaults();
         Ok(Self {
             terminal,
             client,
-            app: App::new(config),
+            app: App::new(config, startup),
             components: HashMap::new(),
+            mouse_captured: false,
         })
     }
 
@@ -127,10 +157,16 @@ impl UserInterface {
                     .checked_sub(last_tick.elapsed())
                     .unwrap_or_else(|| Duration::from_secs(0));
 
-                if event::poll(timeout).expect("event polling works")
-                    && let Event::Key(key_event) = event::read().unwrap()
-                {
-                    tx.send(UserInterfaceEvent::KeyEvent(key_event)).unwrap();
+                if event::poll(timeout).expect("event polling works") {
+                    match event::read().unwrap() {
+                        Event::Key(key_event) => {
+                            tx.send(UserInterfaceEvent::KeyEvent(key_event)).unwrap()
+                        }
+                        Event::Mouse(mouse_event) => tx
+                            .send(UserInterfaceEvent::MouseEvent(mouse_event))
+                            .unwrap(),
+                        _ => (),
+                    }
                 }
 
                 if last_tick.elapsed() >= tick_rate && tx.send(UserInterfaceEvent::Tick).is_ok() {
@@ -145,6 +181,7 @@ impl UserInterface {
         self.register_component(Navigation::default());
         self.register_component(Search::default());
         self.register_component(StoriesPanel::default());
+        self.register_component(StoryPreview::default());
         self.register_component(ItemDetails::default());
         self.register_component(ItemSummary::default());
         self.register_component(ItemTopLevelComments::default());
@@ -155,6 +192,14 @@ impl UserInterface {
         self.register_component(AlgoliaHelp::default());
         self.register_component(UserProfile::default());
         self.register_component(Options::default());
+        self.register_component(DatePicker::default());
+        self.register_component(WhoIsHiringFilters::default());
+        self.register_component(WhoIsHiringPostings::default());
+        self.register_component(WhoIsHiringPostingDetails::default());
+        self.register_component(BrowsingHistoryFilter::default());
+        self.register_component(BrowsingHistoryEntries::default());
+        self.register_component(BookmarksFilters::default());
+        self.register_component(BookmarksList::default());
 
         for component_wrapper in self.components.values_mut() {
             component_wrapper
@@ -170,10 +215,17 @@ impl UserInterface {
         self.terminal
             .hide_cursor()
             .map_err(|_| HnCliError::CrosstermError("hide_cursor error".into()))?;
+        self.refresh_mouse_capture()?;
 
         // Flash message setup
         let mut flash_message_elapsed_ticks: UiTickScalar = 0;
 
+        // Config hot-reload setup
+        let mut config_reload_elapsed_ticks: UiTickScalar = 0;
+
+        // Watched stories snapshots setup, a first check being done right away
+        let mut watched_items_snapshot_elapsed_ticks = WATCHED_ITEMS_SNAPSHOT_CHECK_INTERVAL_TICKS;
+
         // Contextual help setup
         let contextual_helper = ContextualHelper::default();
         let breakpoints_default = Breakpoints::new("ui_default", &[92, 8]);
@@ -238,11 +290,12 @@ impl UserInterface {
 
                     // render flash message
                     let mut clear_flash = false;
+                    let theme = app.get_context().get_theme().clone();
                     if let Some(flash) = app.get_context().get_state_mut().get_flash_message_mut() {
                         flash.update(flash_message_elapsed_ticks);
                         flash_message_elapsed_ticks = 0;
                         if flash.is_active() {
-                            flash.render(frame, global_layout_chunks[1]);
+                            flash.render(frame, global_layout_chunks[1], &theme);
                         } else {
                             clear_flash = true;
                         }
@@ -274,6 +327,7 @@ impl UserInterface {
                     let inputs = app_context.get_inputs();
                     // TODO: errors on quit should be logged but not panic
                     if inputs.is_active(&ApplicationAction::Quit) {
+                        self.set_mouse_capture(false)?;
                         disable_raw_mode().map_err(|_| {
                             HnCliError::CrosstermError("disable_raw_mode error".into())
                         })?;
@@ -285,6 +339,7 @@ impl UserInterface {
                     if inputs.is_active(&ApplicationAction::QuitShortcut)
                         && self.can_quit_via_shortcut()
                     {
+                        self.set_mouse_capture(false)?;
                         disable_raw_mode().map_err(|_| {
                             HnCliError::CrosstermError("crossterm disable_raw_mode error".into())
                         })?;
@@ -296,17 +351,99 @@ impl UserInterface {
                     if self.app.handle_inputs() && !self.handle_inputs().await? {
                         self.app.update_latest_interacted_with_component(None);
                     }
+                    self.app.refresh_key_bindings();
+                }
+                UserInterfaceEvent::MouseEvent(event) => {
+                    if app.pump_mouse_event(event)
+                        && self.app.handle_inputs()
+                        && !self.handle_inputs().await?
+                    {
+                        self.app.update_latest_interacted_with_component(None);
+                    }
+                    self.app.refresh_key_bindings();
                 }
                 UserInterfaceEvent::Tick => {
                     flash_message_elapsed_ticks += 1;
+                    config_reload_elapsed_ticks += 1;
+                    if config_reload_elapsed_ticks >= CONFIG_RELOAD_CHECK_INTERVAL_TICKS {
+                        config_reload_elapsed_ticks = 0;
+                        self.app.reload_config_if_modified();
+                    }
+                    watched_items_snapshot_elapsed_ticks += 1;
+                    if watched_items_snapshot_elapsed_ticks
+                        >= WATCHED_ITEMS_SNAPSHOT_CHECK_INTERVAL_TICKS
+                    {
+                        watched_items_snapshot_elapsed_ticks = 0;
+                        self.snapshot_watched_items().await;
+                    }
+                    // retried on the next tick if a request is pending
+                    let http_timeout = self.app.get_context().get_config().get_http_timeout();
+                    if http_timeout != self.client.get_timeout() {
+                        self.client.try_set_timeout(http_timeout);
+                    }
                     self.update().await?;
+                    self.app.refresh_key_bindings();
+                    // the option may have been toggled from the settings, or the config file
+                    self.refresh_mouse_capture()?;
                 }
             }
         }
+        self.app.persist_history();
 
         Ok(())
     }
 
+    /// Snapshot the score, comments count and top stories rank of the watched stories due for it,
+    /// whatever the screen currently displayed.
+    async fn snapshot_watched_items(&mut self) {
+        let mut app_context = self.app.get_context();
+        let items_to_snapshot = app_context.get_history().get_items_to_snapshot();
+        if items_to_snapshot.is_empty() {
+            return;
+        }
+
+        match self
+            .client
+            .get_items_with_top_stories_rank(&items_to_snapshot)
+            .await
+        {
+            Ok(ranked_items) => {
+                let history = app_context.get_history_mut();
+                for (item, rank) in ranked_items {
+                    history.record_item_snapshot(
+                        item.get_id(),
+                        item.get_score(),
+                        item.get_descendants(),
+                        rank,
+                    );
+                }
+                history.persist();
+            }
+            Err(HnCliError::OfflineError) => (),
+            Err(why) => warn!("UserInterface: cannot snapshot the watched stories: {why}"),
+        }
+    }
+
+    /// Capture the mouse events or not, according to the configuration.
+    fn refresh_mouse_capture(&mut self) -> Result<()> {
+        let enable_mouse = self.app.get_context().get_config().get_enable_mouse();
+        self.set_mouse_capture(enable_mouse)
+    }
+
+    fn set_mouse_capture(&mut self, enabled: bool) -> Result<()> {
+        if enabled == self.mouse_captured {
+            return Ok(());
+        }
+        let result = if enabled {
+            execute!(self.terminal.backend_mut(), EnableMouseCapture)
+        } else {
+            execute!(self.terminal.backend_mut(), DisableMouseCapture)
+        };
+        result.map_err(|_| HnCliError::CrosstermError("mouse capture error".into()))?;
+        self.mouse_captured = enabled;
+        Ok(())
+    }
+
     /// Check all active components for any necessary update.
     async fn update(&mut self) -> Result<()> {
         let mut app_context = self.app.get_context();
@@ -363,6 +500,9 @@ impl UserInterface {
         {
             return false;
         }
+        if app_context.get_state().is_text_input_active() {
+            return false;
+        }
         // Check configuration first
         if app_context
             .get_config()
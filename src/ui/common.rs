@@ -1,11 +1,22 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
 use ratatui::{Frame, layout::Rect};
 
 use crate::{api::HnClient, app::AppContext, errors::Result};
 
+use super::UI_TICK_RATE_MS;
+
 /// A `tick` is a UI update, in the order of the hundred milliseconds.
 pub type UiTickScalar = u16;
 
+/// Get the approximate number of ticks in the given duration.
+pub fn get_ticks_in_duration(duration: Duration) -> UiTickScalar {
+    (duration.as_millis() / UI_TICK_RATE_MS as u128)
+        .try_into()
+        .unwrap_or(UiTickScalar::MAX)
+}
+
 /// A hashable type for application-unique component IDs.
 pub type UiComponentId = &'static str;
 
@@ -1,4 +1,7 @@
+pub mod bookmarks;
+pub mod browsing_history;
 pub mod common;
+pub mod date_picker;
 pub mod help;
 pub mod help_search;
 pub mod item_comments;
@@ -9,5 +12,7 @@ pub mod options;
 pub mod search;
 pub mod settings;
 pub mod stories;
+pub mod story_preview;
 pub mod user_profile;
+pub mod who_is_hiring;
 pub mod widgets;
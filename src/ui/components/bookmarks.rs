@@ -0,0 +1,47 @@
+//! Components of the screen listing the bookmarked stories and comments.
+
+pub mod filters;
+pub mod list;
+
+/// The editable fields of a bookmark.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BookmarkField {
+    Tags,
+    Notes,
+}
+
+impl BookmarkField {
+    pub fn get_label(&self) -> &str {
+        match self {
+            Self::Tags => "Tags (comma-separated)",
+            Self::Notes => "Notes",
+        }
+    }
+}
+
+/// The available orders of the bookmarks list.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BookmarksSorting {
+    #[default]
+    Newest,
+    Oldest,
+    Title,
+}
+
+impl BookmarksSorting {
+    pub fn get_label(&self) -> &str {
+        match self {
+            Self::Newest => "newest first",
+            Self::Oldest => "oldest first",
+            Self::Title => "by title",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Newest => Self::Oldest,
+            Self::Oldest => Self::Title,
+            Self::Title => Self::Newest,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::HnClient,
+    app::AppContext,
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::widgets::text_input::TextInputWidget,
+        handlers::ApplicationAction,
+    },
+};
+
+/// The Bookmarks Filters component displays and changes the tag filter
+/// and the sorting applied to the bookmarks.
+///
+/// NB: typing in the bookmark tags or notes is handled by the screen itself.
+#[derive(Debug, Default)]
+pub struct BookmarksFilters {}
+
+pub const BOOKMARKS_FILTERS_ID: UiComponentId = "bookmarks_filters";
+
+impl BookmarksFilters {
+    /// Filter by the next used tag, alphabetically, then by none after the last one.
+    fn cycle_tag_filter(ctx: &mut AppContext) {
+        let tags = ctx.get_history().get_bookmarks_tags();
+        let next_tag = match ctx.get_state().get_bookmarks_tag_filter() {
+            Some(current_tag) => tags
+                .iter()
+                .skip_while(|tag| *tag != current_tag)
+                .nth(1)
+                .map(|tag| tag.to_string()),
+            None => tags.first().map(|tag| tag.to_string()),
+        };
+        ctx.get_state_mut().set_bookmarks_tag_filter(next_tag);
+    }
+}
+
+#[async_trait]
+impl UiComponent for BookmarksFilters {
+    fn id(&self) -> UiComponentId {
+        BOOKMARKS_FILTERS_ID
+    }
+
+    async fn should_update(
+        &mut self,
+        _elapsed_ticks: UiTickScalar,
+        _ctx: &AppContext,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    async fn update(&mut self, _client: &mut HnClient, _ctx: &mut AppContext) -> Result<()> {
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        let inputs = ctx.get_inputs();
+        if inputs.is_active(&ApplicationAction::BookmarksCycleTagFilter) {
+            Self::cycle_tag_filter(ctx);
+        } else if inputs.is_active(&ApplicationAction::BookmarksToggleSorting) {
+            let state = ctx.get_state_mut();
+            let next_sorting = state.get_bookmarks_sorting().next();
+            state.set_bookmarks_sorting(next_sorting);
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+        let state = ctx.get_state();
+
+        let key =
+            |action: ApplicationAction| ctx.get_inputs().get_key_bindings_representation(&action);
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .border_type(BorderType::Thick)
+            .borders(Borders::ALL)
+            .title(format!(
+                "Filters ({}: tag, {}: sorting, {}: edit tags, {}: edit notes, {}: remove)",
+                key(ApplicationAction::BookmarksCycleTagFilter),
+                key(ApplicationAction::BookmarksToggleSorting),
+                key(ApplicationAction::BookmarksEditTags),
+                key(ApplicationAction::BookmarksEditNotes),
+                key(ApplicationAction::BookmarksRemove),
+            ));
+
+        // Editing case
+        if let Some((_, field, input)) = state.get_bookmark_input() {
+            let label = format!("{}: ", field.get_label());
+            let block_inner = block.inner(inside);
+            f.render_widget(block, inside);
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(label.len() as u16), Constraint::Min(1)])
+                .split(block_inner);
+            f.render_widget(Paragraph::new(label), chunks[0]);
+            let input_widget = TextInputWidget::with_state(input)
+                .style(Style::default().fg(theme.get_accent_color()))
+                .cursor_style(Style::default().bg(theme.get_highlight_color()));
+            f.render_widget(input_widget, chunks[1]);
+            return Ok(());
+        }
+
+        // General case
+        let tag_filter = state.get_bookmarks_tag_filter();
+        let text = vec![Line::from(vec![
+            Span::raw("Tag: "),
+            Span::styled(
+                tag_filter.map_or("any", String::as_str),
+                Style::default().fg(if tag_filter.is_some() {
+                    theme.get_main_color()
+                } else {
+                    theme.get_muted_color()
+                }),
+            ),
+            Span::raw(" | Sorted "),
+            Span::styled(
+                state.get_bookmarks_sorting().get_label(),
+                Style::default().fg(theme.get_main_color()),
+            ),
+        ])];
+        let paragraph = Paragraph::new(text).block(block);
+        f.render_widget(paragraph, inside);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use async_trait::async_trait;
+use chrono::Local;
+use ratatui::{
+    layout::{HorizontalAlignment, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::{HnClient, types::HnItemIdScalar},
+    app::{
+        AppContext,
+        history::{Bookmark, BookmarkedItem},
+    },
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::{
+            common::open_story,
+            widgets::{
+                custom_list::{CustomList, CustomListState},
+                text_input::TextInputState,
+            },
+        },
+        flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
+        handlers::ApplicationAction,
+        utils::{ItemWithId, loader::Loader, open_browser_tab},
+    },
+};
+
+use super::{BookmarkField, BookmarksSorting};
+
+impl ItemWithId<HnItemIdScalar> for Bookmark {
+    fn get_id(&self) -> HnItemIdScalar {
+        self.get_item().get_id()
+    }
+}
+
+/// The Bookmarks List component lists the (filtered and sorted) bookmarks,
+/// opens them back and edits or removes them.
+#[derive(Debug)]
+pub struct BookmarksList {
+    /// Bookmarks, tag filter and sorting used to build the currently displayed bookmarks, if built.
+    last_refresh: Option<(Vec<Bookmark>, Option<String>, BookmarksSorting)>,
+    list_state: CustomListState<HnItemIdScalar, Bookmark>,
+    /// ID of the story to fetch and open at the next update, if any.
+    story_to_open: Option<HnItemIdScalar>,
+    loader: Loader,
+}
+
+impl Default for BookmarksList {
+    fn default() -> Self {
+        Self {
+            last_refresh: None,
+            list_state: CustomListState::with_items(vec![]),
+            story_to_open: None,
+            loader: Loader::default(),
+        }
+    }
+}
+
+pub const BOOKMARKS_LIST_ID: UiComponentId = "bookmarks_list";
+
+impl BookmarksList {
+    /// Rebuild the displayed bookmarks list from the current tag filter and sorting.
+    fn refresh_displayed_bookmarks(&mut self, ctx: &AppContext) {
+        let bookmarks = ctx.get_history().get_bookmarks().to_vec();
+        let state = ctx.get_state();
+        let (tag_filter, sorting) = (
+            state.get_bookmarks_tag_filter().cloned(),
+            *state.get_bookmarks_sorting(),
+        );
+        let displayed_bookmarks = filtered_and_sorted(&bookmarks, tag_filter.as_deref(), sorting);
+
+        self.list_state.replace_items(displayed_bookmarks);
+        if self.list_state.is_empty() {
+            self.list_state.select(None);
+        } else if self
+            .list_state
+            .selected()
+            .is_none_or(|index| index >= self.list_state.get_items().len())
+        {
+            self.list_state.select(Some(0));
+        }
+        self.last_refresh = Some((bookmarks, tag_filter, sorting));
+    }
+
+    fn get_selected_bookmark(&self) -> Option<&Bookmark> {
+        self.list_state
+            .selected()
+            .and_then(|index| self.list_state.get_items().get(index))
+    }
+}
+
+/// The bookmarks tagged with the given tag, if any, in the given order.
+fn filtered_and_sorted(
+    bookmarks: &[Bookmark],
+    tag_filter: Option<&str>,
+    sorting: BookmarksSorting,
+) -> Vec<Bookmark> {
+    let mut displayed_bookmarks: Vec<Bookmark> = bookmarks
+        .iter()
+        .filter(|bookmark| {
+            tag_filter.is_none_or(|tag| bookmark.get_tags().iter().any(|other| other == tag))
+        })
+        .cloned()
+        .collect();
+    match sorting {
+        BookmarksSorting::Newest => displayed_bookmarks.reverse(),
+        BookmarksSorting::Oldest => (),
+        BookmarksSorting::Title => displayed_bookmarks
+            .sort_by_cached_key(|bookmark| describe_item(bookmark.get_item()).1.to_lowercase()),
+    }
+    displayed_bookmarks
+}
+
+/// Kind and description of a bookmarked item.
+fn describe_item(item: &BookmarkedItem) -> (&'static str, String) {
+    match item {
+        BookmarkedItem::Story { title, .. } => ("story", title.clone()),
+        BookmarkedItem::Comment {
+            by_username,
+            story_title,
+            ..
+        } => ("comment", format!("{story_title} (by {by_username})")),
+    }
+}
+
+#[async_trait]
+impl UiComponent for BookmarksList {
+    fn id(&self) -> UiComponentId {
+        BOOKMARKS_LIST_ID
+    }
+
+    fn before_unmount(&mut self) {
+        self.loader.stop();
+    }
+
+    async fn should_update(
+        &mut self,
+        _elapsed_ticks: UiTickScalar,
+        ctx: &AppContext,
+    ) -> Result<bool> {
+        self.loader.update();
+        let state = ctx.get_state();
+        let changed = self
+            .last_refresh
+            .as_ref()
+            .is_none_or(|(bookmarks, tag_filter, sorting)| {
+                bookmarks.as_slice() != ctx.get_history().get_bookmarks()
+                    || tag_filter.as_ref() != state.get_bookmarks_tag_filter()
+                    || sorting != state.get_bookmarks_sorting()
+            });
+        Ok(changed || self.story_to_open.is_some())
+    }
+
+    async fn update(&mut self, client: &mut HnClient, ctx: &mut AppContext) -> Result<()> {
+        if let Some(story_id) = self.story_to_open.take() {
+            open_story(client, ctx, story_id).await;
+            return Ok(());
+        }
+        self.refresh_displayed_bookmarks(ctx);
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        if self.story_to_open.is_some() {
+            return Ok(false);
+        }
+        let Some(selected) = self.get_selected_bookmark().cloned() else {
+            return Ok(false);
+        };
+        let selected_id = selected.get_item().get_id();
+
+        let inputs = ctx.get_inputs();
+        if inputs.is_active(&ApplicationAction::NavigateUp) {
+            self.list_state.previous();
+        } else if inputs.is_active(&ApplicationAction::NavigateDown) {
+            self.list_state.next();
+        } else if inputs.is_active(&ApplicationAction::SelectItem) {
+            match selected.get_item() {
+                BookmarkedItem::Story { id, .. } => self.story_to_open = Some(*id),
+                BookmarkedItem::Comment {
+                    story_id,
+                    comments_chain,
+                    ..
+                } => {
+                    // the comments chain is restored when opening the story
+                    ctx.get_history_mut()
+                        .persist_comments_chain_for_story(*story_id, comments_chain);
+                    self.story_to_open = Some(*story_id);
+                }
+            }
+        } else if inputs.is_active(&ApplicationAction::OpenHackerNewsLink)
+            || inputs.is_active(&ApplicationAction::OpenExternalOrHackerNewsLink)
+        {
+            open_browser_tab(&format!(
+                "https://news.ycombinator.com/item?id={selected_id}"
+            ));
+        } else if inputs.is_active(&ApplicationAction::BookmarksEditTags) {
+            let input = TextInputState::from_string(&selected.get_tags().join(", "));
+            ctx.get_state_mut()
+                .set_bookmark_input(Some((selected_id, BookmarkField::Tags, input)));
+        } else if inputs.is_active(&ApplicationAction::BookmarksEditNotes) {
+            let input = TextInputState::from_string(selected.get_notes());
+            ctx.get_state_mut().set_bookmark_input(Some((
+                selected_id,
+                BookmarkField::Notes,
+                input,
+            )));
+        } else if inputs.is_active(&ApplicationAction::BookmarksRemove) {
+            let history = ctx.get_history_mut();
+            history.remove_bookmark(selected_id);
+            history.persist();
+            ctx.get_state_mut().set_flash_message(FlashMessage::new(
+                "Bookmark removed.",
+                FlashMessageType::Info,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+
+        // Loading case
+        if self.story_to_open.is_some() {
+            let block = Block::default()
+                .style(Style::default().fg(theme.get_block_color()))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded);
+
+            let text = vec![Line::from(""), Line::from(self.loader.text())];
+            let paragraph = Paragraph::new(text)
+                .block(block)
+                .alignment(HorizontalAlignment::Center);
+            f.render_widget(paragraph, inside);
+            return Ok(());
+        }
+
+        // General case
+        let block_title = format!(
+            "Bookmarks ({}/{})",
+            self.list_state.get_items().len(),
+            ctx.get_history().get_bookmarks().len(),
+        );
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(block_title);
+
+        let custom_list_bookmarks = CustomList::new(
+            &mut self.list_state,
+            |rect, buf, bookmark, is_selected| {
+                let (kind, description) = describe_item(bookmark.get_item());
+                let style = Style::default().fg(if is_selected {
+                    theme.get_accent_color()
+                } else {
+                    theme.get_text_color()
+                });
+                buf.set_stringn(
+                    rect.x,
+                    rect.y,
+                    format!("{kind:<7}  {description}"),
+                    rect.width as usize,
+                    style,
+                );
+                if rect.height < 2 {
+                    return;
+                }
+                let mut details = format!(
+                    "{:<7}  saved {}",
+                    "",
+                    bookmark
+                        .get_datetime()
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d")
+                );
+                if !bookmark.get_tags().is_empty() {
+                    details.push_str(&format!(" | tags: {}", bookmark.get_tags().join(", ")));
+                }
+                if !bookmark.get_notes().is_empty() {
+                    details.push_str(&format!(" | {}", bookmark.get_notes()));
+                }
+                buf.set_stringn(
+                    rect.x,
+                    rect.y + 1,
+                    details,
+                    rect.width as usize,
+                    Style::default().fg(theme.get_muted_color()),
+                );
+            },
+            |_| 2,
+        )
+        .block(block)
+        .style(Style::default().fg(theme.get_text_color()))
+        .highlight_symbol(">> ")
+        .compact(ctx.get_config().get_compact_lists())
+        .highlight_style(Style::default().fg(theme.get_accent_color()));
+
+        f.render_widget(custom_list_bookmarks, inside);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+//! Components of the screen listing the recently opened stories, comments and user profiles.
+
+pub mod entries;
+pub mod filter;
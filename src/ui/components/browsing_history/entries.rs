@@ -0,0 +1,323 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDate};
+use ratatui::{
+    layout::{HorizontalAlignment, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::{HnClient, types::HnItemIdScalar},
+    app::{
+        AppContext,
+        history::{BrowsedItem, BrowsingHistoryEntry},
+    },
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::{
+            common::open_story,
+            widgets::custom_list::{CustomList, CustomListState},
+        },
+        handlers::ApplicationAction,
+        router::AppRoute,
+        utils::{ItemWithId, loader::Loader},
+    },
+};
+
+/// A browsing history entry, as displayed in the list.
+#[derive(Clone, Debug)]
+struct DisplayedBrowsingHistoryEntry {
+    /// Index of the entry in the browsing history, used as its ID.
+    index: usize,
+    entry: BrowsingHistoryEntry,
+    /// Label of the day the entry belongs to, only set for the first entry of the day.
+    day_label: Option<String>,
+}
+
+impl ItemWithId<usize> for DisplayedBrowsingHistoryEntry {
+    fn get_id(&self) -> usize {
+        self.index
+    }
+}
+
+/// The Browsing History Entries component lists the (filtered) recently opened
+/// stories, comments and user profiles, grouped by day, and opens them back.
+#[derive(Debug)]
+pub struct BrowsingHistoryEntries {
+    /// Filter used to build the currently displayed entries, if built.
+    filter_for_last_refresh: Option<String>,
+    list_state: CustomListState<usize, DisplayedBrowsingHistoryEntry>,
+    /// ID of the story to fetch and open at the next update, if any.
+    story_to_open: Option<HnItemIdScalar>,
+    loader: Loader,
+}
+
+impl Default for BrowsingHistoryEntries {
+    fn default() -> Self {
+        Self {
+            filter_for_last_refresh: None,
+            list_state: CustomListState::with_items(vec![]),
+            story_to_open: None,
+            loader: Loader::default(),
+        }
+    }
+}
+
+pub const BROWSING_HISTORY_ENTRIES_ID: UiComponentId = "browsing_history_entries";
+
+/// Width of the day column.
+const DAY_LABEL_WIDTH: usize = 10;
+
+impl BrowsingHistoryEntries {
+    /// Rebuild the displayed entries list, newest first, from the current filter.
+    fn refresh_displayed_entries(&mut self, ctx: &mut AppContext) {
+        let config = ctx.get_config();
+        let (max_entries, retention_days) = (
+            config.get_browsing_history_max_entries(),
+            config.get_browsing_history_retention_days(),
+        );
+        ctx.get_history_mut()
+            .enforce_browsing_history_limits(max_entries, retention_days);
+
+        let filter = ctx.get_state().get_browsing_history_filter().to_string();
+        let today = Local::now().date_naive();
+        let mut previous_day = None;
+        let displayed_entries = ctx
+            .get_history()
+            .get_browsing_history()
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, entry)| matches_filter(entry.get_item(), &filter))
+            .map(|(index, entry)| {
+                let day = entry.get_datetime().with_timezone(&Local).date_naive();
+                let day_label = (previous_day != Some(day)).then(|| day_label(day, today));
+                previous_day = Some(day);
+                DisplayedBrowsingHistoryEntry {
+                    index,
+                    entry: entry.clone(),
+                    day_label,
+                }
+            })
+            .collect();
+
+        self.list_state.replace_items(displayed_entries);
+        if self.list_state.selected().is_none() && !self.list_state.is_empty() {
+            self.list_state.select(Some(0));
+        }
+        self.filter_for_last_refresh = Some(filter);
+    }
+}
+
+/// Does the browsed item match the (case-insensitive) filter on its title or user?
+fn matches_filter(item: &BrowsedItem, filter: &str) -> bool {
+    let filter = filter.to_lowercase();
+    let contains = |value: &str| value.to_lowercase().contains(&filter);
+    match item {
+        BrowsedItem::Story { title, .. } => contains(title),
+        BrowsedItem::Comment {
+            by_username,
+            story_title,
+            ..
+        } => contains(by_username) || contains(story_title),
+        BrowsedItem::UserProfile { username } => contains(username),
+    }
+}
+
+/// Human-readable label of a day, relative to today.
+fn day_label(day: NaiveDate, today: NaiveDate) -> String {
+    if day == today {
+        "Today".into()
+    } else if day == today - Duration::days(1) {
+        "Yesterday".into()
+    } else {
+        day.format("%Y-%m-%d").to_string()
+    }
+}
+
+/// Kind and description of a browsed item.
+fn describe_item(item: &BrowsedItem) -> (&'static str, String) {
+    match item {
+        BrowsedItem::Story { title, .. } => ("story", title.clone()),
+        BrowsedItem::Comment {
+            by_username,
+            story_title,
+            ..
+        } => ("comment", format!("{by_username} on \"{story_title}\"")),
+        BrowsedItem::UserProfile { username } => ("user", username.clone()),
+    }
+}
+
+#[async_trait]
+impl UiComponent for BrowsingHistoryEntries {
+    fn id(&self) -> UiComponentId {
+        BROWSING_HISTORY_ENTRIES_ID
+    }
+
+    fn before_unmount(&mut self) {
+        // the history will have changed when coming back
+        self.filter_for_last_refresh = None;
+        self.loader.stop();
+    }
+
+    async fn should_update(
+        &mut self,
+        _elapsed_ticks: UiTickScalar,
+        ctx: &AppContext,
+    ) -> Result<bool> {
+        self.loader.update();
+        let filter_changed = self.filter_for_last_refresh.as_deref()
+            != Some(ctx.get_state().get_browsing_history_filter());
+        Ok(filter_changed || self.story_to_open.is_some())
+    }
+
+    async fn update(&mut self, client: &mut HnClient, ctx: &mut AppContext) -> Result<()> {
+        if let Some(story_id) = self.story_to_open.take() {
+            open_story(client, ctx, story_id).await;
+            return Ok(());
+        }
+        self.refresh_displayed_entries(ctx);
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        if self.story_to_open.is_some() || self.list_state.is_empty() {
+            return Ok(false);
+        }
+
+        let inputs = ctx.get_inputs();
+        if inputs.is_active(&ApplicationAction::NavigateUp) {
+            self.list_state.previous();
+        } else if inputs.is_active(&ApplicationAction::NavigateDown) {
+            self.list_state.next();
+        } else if inputs.is_active(&ApplicationAction::SelectItem) {
+            let Some(selected) = self
+                .list_state
+                .selected()
+                .and_then(|index| self.list_state.get_items().get(index))
+                .cloned()
+            else {
+                return Ok(false);
+            };
+            match selected.entry.get_item() {
+                BrowsedItem::Story { id, .. } => self.story_to_open = Some(*id),
+                BrowsedItem::Comment {
+                    story_id,
+                    comments_chain,
+                    ..
+                } => {
+                    // the comments chain is restored when opening the story
+                    ctx.get_history_mut()
+                        .persist_comments_chain_for_story(*story_id, comments_chain);
+                    self.story_to_open = Some(*story_id);
+                }
+                BrowsedItem::UserProfile { username } => {
+                    ctx.router_push_navigation_stack(AppRoute::UserProfile(username.clone()));
+                }
+            }
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+
+        // Loading case
+        if self.story_to_open.is_some() {
+            let block = Block::default()
+                .style(Style::default().fg(theme.get_block_color()))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded);
+
+            let text = vec![Line::from(""), Line::from(self.loader.text())];
+            let paragraph = Paragraph::new(text)
+                .block(block)
+                .alignment(HorizontalAlignment::Center);
+            f.render_widget(paragraph, inside);
+            return Ok(());
+        }
+
+        // General case
+        let block_title = format!(
+            "History ({}/{})",
+            self.list_state.get_items().len(),
+            ctx.get_history().get_browsing_history().len(),
+        );
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(block_title);
+
+        let custom_list_entries = CustomList::new(
+            &mut self.list_state,
+            |rect, buf, displayed_entry, is_selected| {
+                let (kind, description) = describe_item(displayed_entry.entry.get_item());
+                let time = displayed_entry
+                    .entry
+                    .get_datetime()
+                    .with_timezone(&Local)
+                    .format("%H:%M");
+                let line = format!(
+                    "{:<DAY_LABEL_WIDTH$} {time}  {kind:<7}  {description}",
+                    displayed_entry.day_label.as_deref().unwrap_or_default(),
+                );
+                let style = Style::default().fg(if is_selected {
+                    theme.get_accent_color()
+                } else {
+                    theme.get_text_color()
+                });
+                buf.set_stringn(rect.x, rect.y, &line, rect.width as usize, style);
+            },
+            |_| 1,
+        )
+        .block(block)
+        .style(Style::default().fg(theme.get_text_color()))
+        .highlight_symbol(">> ")
+        .compact(ctx.get_config().get_compact_lists())
+        .highlight_style(Style::default().fg(theme.get_accent_color()));
+
+        f.render_widget(custom_list_entries, inside);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::app::history::BrowsedItem;
+
+    use super::{day_label, matches_filter};
+
+    #[test]
+    fn test_browsing_history_filtering_and_grouping() {
+        let comment = BrowsedItem::Comment {
+            id: 2,
+            by_username: "dang".into(),
+            story_id: 1,
+            story_title: "Show HN: hncli".into(),
+            comments_chain: vec![2],
+        };
+        assert!(matches_filter(&comment, ""));
+        assert!(matches_filter(&comment, "DANG"));
+        assert!(matches_filter(&comment, "show hn"));
+        assert!(!matches_filter(&comment, "rust"));
+
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(day_label(today, today), "Today");
+        assert_eq!(
+            day_label(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(), today),
+            "Yesterday"
+        );
+        assert_eq!(
+            day_label(NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(), today),
+            "2024-02-28"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::HnClient,
+    app::AppContext,
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::widgets::text_input::{TextInputState, TextInputWidget},
+        handlers::ApplicationAction,
+    },
+};
+
+/// The Browsing History Filter component displays and starts editing the filter
+/// applied to the browsing history entries.
+///
+/// NB: typing in the filter is handled by the screen itself.
+#[derive(Debug, Default)]
+pub struct BrowsingHistoryFilter {}
+
+pub const BROWSING_HISTORY_FILTER_ID: UiComponentId = "browsing_history_filter";
+
+const FILTER_LABEL: &str = "Title or user: ";
+
+#[async_trait]
+impl UiComponent for BrowsingHistoryFilter {
+    fn id(&self) -> UiComponentId {
+        BROWSING_HISTORY_FILTER_ID
+    }
+
+    async fn should_update(
+        &mut self,
+        _elapsed_ticks: UiTickScalar,
+        _ctx: &AppContext,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    async fn update(&mut self, _client: &mut HnClient, _ctx: &mut AppContext) -> Result<()> {
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        if !ctx
+            .get_inputs()
+            .is_active(&ApplicationAction::BrowsingHistoryEditFilter)
+        {
+            return Ok(false);
+        }
+
+        let state = ctx.get_state_mut();
+        let input = TextInputState::from_string(state.get_browsing_history_filter());
+        state.set_browsing_history_filter_input(Some(input));
+        Ok(true)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+        let state = ctx.get_state();
+
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .border_type(BorderType::Thick)
+            .borders(Borders::ALL)
+            .title(format!(
+                "Filter ({}: edit)",
+                ctx.get_inputs()
+                    .get_key_bindings_representation(&ApplicationAction::BrowsingHistoryEditFilter),
+            ));
+
+        // Editing case
+        if let Some(input) = state.get_browsing_history_filter_input() {
+            let block_inner = block.inner(inside);
+            f.render_widget(block, inside);
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Length(FILTER_LABEL.len() as u16),
+                    Constraint::Min(1),
+                ])
+                .split(block_inner);
+            f.render_widget(Paragraph::new(FILTER_LABEL), chunks[0]);
+            let input_widget = TextInputWidget::with_state(input)
+                .style(Style::default().fg(theme.get_accent_color()))
+                .cursor_style(Style::default().bg(theme.get_highlight_color()));
+            f.render_widget(input_widget, chunks[1]);
+            return Ok(());
+        }
+
+        // General case
+        let filter = state.get_browsing_history_filter();
+        let text = vec![Line::from(vec![
+            Span::raw(FILTER_LABEL),
+            Span::styled(
+                if filter.is_empty() { "any" } else { filter },
+                Style::default().fg(if filter.is_empty() {
+                    theme.get_muted_color()
+                } else {
+                    theme.get_main_color()
+                }),
+            ),
+        ])];
+        let paragraph = Paragraph::new(text).block(block);
+        f.render_widget(paragraph, inside);
+
+        Ok(())
+    }
+}
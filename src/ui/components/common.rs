@@ -5,9 +5,24 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 
-use crate::ui::{common::RenderFrame, theme::UiTheme};
+use crate::{
+    api::{HnClient, types::HnItemIdScalar},
+    app::{AppContext, history::BookmarkedItem},
+    ui::{
+        common::RenderFrame,
+        displayable_item::DisplayableHackerNewsItem,
+        flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
+        router::AppRoute,
+        theme::UiThemePalette,
+    },
+};
 
-pub fn render_text_message(f: &mut RenderFrame, inside: Rect, message: &str, theme: &UiTheme) {
+pub fn render_text_message(
+    f: &mut RenderFrame,
+    inside: Rect,
+    message: &str,
+    theme: &UiThemePalette,
+) {
     let block = Block::default()
         .style(Style::default().fg(theme.get_block_color()))
         .borders(Borders::ALL)
@@ -19,3 +34,57 @@ pub fn render_text_message(f: &mut RenderFrame, inside: Rect, message: &str, the
         .alignment(HorizontalAlignment::Center);
     f.render_widget(paragraph, inside);
 }
+
+/// Fetch the given story and open it, flashing an error if it cannot be fetched.
+pub async fn open_story(client: &HnClient, ctx: &mut AppContext<'_>, story_id: HnItemIdScalar) {
+    let story = match client.get_thread_root_item(story_id).await {
+        Ok(item) => DisplayableHackerNewsItem::try_from(item),
+        Err(why) => Err(why),
+    };
+    match story {
+        Ok(story) => {
+            ctx.get_state_mut()
+                .set_currently_viewed_item(Some(story.clone()));
+            ctx.router_push_navigation_stack(AppRoute::ItemDetails(story));
+        }
+        Err(why) => {
+            ctx.get_state_mut().set_flash_message(FlashMessage::new(
+                format!("Could not open the story: {why}"),
+                FlashMessageType::Error,
+                FLASH_MESSAGE_DEFAULT_DURATION_MS,
+            ));
+        }
+    }
+}
+
+/// Bookmark the given item, or remove its bookmark, and persist the change.
+pub fn toggle_bookmark(ctx: &mut AppContext, item: BookmarkedItem) {
+    let history = ctx.get_history_mut();
+    let is_bookmarked = history.toggle_bookmark(item);
+    history.persist();
+    ctx.get_state_mut().set_flash_message(FlashMessage::new(
+        if is_bookmarked {
+            "Bookmarked."
+        } else {
+            "Bookmark removed."
+        },
+        FlashMessageType::Info,
+        FLASH_MESSAGE_DEFAULT_DURATION_MS,
+    ));
+}
+
+/// Watch the given story, or stop watching it, and persist the change.
+pub fn toggle_item_watched(ctx: &mut AppContext, item_id: HnItemIdScalar) {
+    let history = ctx.get_history_mut();
+    let is_watched = history.toggle_item_watched(item_id);
+    history.persist();
+    ctx.get_state_mut().set_flash_message(FlashMessage::new(
+        if is_watched {
+            "Watching the story score and rank."
+        } else {
+            "Story no longer watched."
+        },
+        FlashMessageType::Info,
+        FLASH_MESSAGE_DEFAULT_DURATION_MS,
+    ));
+}
@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use chrono::{Days, Utc};
+use ratatui::{
+    layout::{HorizontalAlignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::HnClient,
+    app::AppContext,
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::widgets::text_input::{TextInputState, TextInputWidget},
+        handlers::ApplicationAction,
+    },
+};
+
+/// Format of the day, both displayed and typed in.
+pub const DATE_PICKER_INPUT_FORMAT: &str = "%Y-%m-%d";
+
+/// The Date Picker component controls which day is viewed on the past front page screen,
+/// either by browsing day by day or by typing in a date.
+#[derive(Debug, Default)]
+pub struct DatePicker {}
+
+pub const DATE_PICKER_ID: UiComponentId = "date_picker";
+
+#[async_trait]
+impl UiComponent for DatePicker {
+    fn id(&self) -> UiComponentId {
+        DATE_PICKER_ID
+    }
+
+    async fn should_update(
+        &mut self,
+        _elapsed_ticks: UiTickScalar,
+        _ctx: &AppContext,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    async fn update(&mut self, _client: &mut HnClient, _ctx: &mut AppContext) -> Result<()> {
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        let inputs = ctx.get_inputs();
+        let current_day = *ctx.get_state().get_past_front_page_day();
+        let new_day = if inputs.is_active(&ApplicationAction::PastFrontPagePreviousDay) {
+            current_day.checked_sub_days(Days::new(1))
+        } else if inputs.is_active(&ApplicationAction::PastFrontPageNextDay) {
+            current_day
+                .checked_add_days(Days::new(1))
+                .filter(|day| day <= &Utc::now().date_naive())
+        } else if inputs.is_active(&ApplicationAction::PastFrontPageEditDay) {
+            let formatted_day = current_day.format(DATE_PICKER_INPUT_FORMAT).to_string();
+            ctx.get_state_mut()
+                .set_past_front_page_day_input(Some(TextInputState::from_string(&formatted_day)));
+            return Ok(true);
+        } else {
+            return Ok(false);
+        };
+
+        if let Some(day) = new_day {
+            let state = ctx.get_state_mut();
+            state.set_past_front_page_day(day);
+            state.set_main_stories_loading(true);
+        }
+        Ok(true)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+        let inputs = ctx.get_inputs();
+
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .border_type(BorderType::Thick)
+            .borders(Borders::ALL)
+            .title(format!(
+                "Day ({} / {} to browse, {} to pick)",
+                inputs
+                    .get_key_bindings_representation(&ApplicationAction::PastFrontPagePreviousDay),
+                inputs.get_key_bindings_representation(&ApplicationAction::PastFrontPageNextDay),
+                inputs.get_key_bindings_representation(&ApplicationAction::PastFrontPageEditDay),
+            ));
+
+        // Editing case
+        if let Some(day_input) = ctx.get_state().get_past_front_page_day_input() {
+            let input_area = block.inner(inside);
+            f.render_widget(block, inside);
+            let input_widget = TextInputWidget::with_state(day_input)
+                .style(Style::default().fg(theme.get_accent_color()))
+                .cursor_style(Style::default().bg(theme.get_highlight_color()));
+            f.render_widget(input_widget, input_area);
+            return Ok(());
+        }
+
+        // General case
+        let day = ctx.get_state().get_past_front_page_day();
+        let text = vec![Line::from(vec![
+            Span::styled("◀ ", Style::default().fg(theme.get_muted_color())),
+            Span::styled(
+                day.format("%A, %B %-d, %Y").to_string(),
+                Style::default().fg(theme.get_main_color()),
+            ),
+            Span::styled(" ▶", Style::default().fg(theme.get_muted_color())),
+        ])];
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .alignment(HorizontalAlignment::Center);
+        f.render_widget(paragraph, inside);
+
+        Ok(())
+    }
+}
@@ -11,7 +11,10 @@ use crate::{
     app::AppContext,
     config::HNCLI_VERSION,
     errors::Result,
-    ui::common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        handlers::{ApplicationAction, InputsController},
+    },
 };
 
 /// The About component contains the version number,
@@ -44,14 +47,14 @@ impl UiComponent for Help {
         Ok(false)
     }
 
-    fn render(&mut self, f: &mut RenderFrame, inside: Rect, _ctx: &AppContext) -> Result<()> {
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Percentage(10), Constraint::Percentage(90)].as_ref())
             .split(inside);
 
         Self::render_about_block(f, chunks[0]);
-        Self::render_help_block(f, chunks[1]);
+        Self::render_help_block(f, chunks[1], ctx.get_inputs());
 
         Ok(())
     }
@@ -72,50 +75,224 @@ impl Help {
         f.render_widget(paragraph, inside);
     }
 
-    fn render_help_block(f: &mut RenderFrame, inside: Rect) {
+    fn render_help_block(f: &mut RenderFrame, inside: Rect, inputs: &InputsController) {
+        use ApplicationAction::*;
+        let key = |action: ApplicationAction| inputs.get_key_bindings_representation(&action);
+
         let text = vec![
             Line::from(""),
-            Line::from("Press 'h' to toggle help."),
+            Line::from(format!("Press {} to toggle help.", key(ToggleHelp))),
             Line::from(""),
-            Line::from("Press 'q' to quit (if enabled in the settings)."),
+            Line::from(format!(
+                "Press {} to quit (if enabled in the settings).",
+                key(QuitShortcut)
+            )),
             Line::from(""),
-            Line::from("Go back with 'escape'."),
+            Line::from(format!("Go back with {}.", key(Back))),
             Line::from(""),
-            Line::from("Navigate between screens with the left and right arrow keys."),
+            Line::from(format!(
+                "Navigate between screens with {} and {}.",
+                key(NavigateLeft),
+                key(NavigateRight)
+            )),
             Line::from(""),
-            Line::from("Navigate between stories with the up and down arrow keys."),
+            Line::from(format!(
+                "Navigate between stories with {} and {}.",
+                key(NavigateUp),
+                key(NavigateDown)
+            )),
             Line::from(""),
-            Line::from(
-                "Open a tab in your browser for the selected story with 'o'. Open the selected story page with 'enter'.",
-            ),
+            Line::from(format!(
+                "In lists and comments, jump a page with {} and {}, half a page with {} and {}, and to the first or last one with {} and {}.",
+                key(NavigatePageUp),
+                key(NavigatePageDown),
+                key(NavigateHalfPageUp),
+                key(NavigateHalfPageDown),
+                key(NavigateFirst),
+                key(NavigateLast)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Open a tab in your browser for the selected story with {}. Open the selected story page with {}.",
+                key(OpenExternalOrHackerNewsLink),
+                key(SelectItem)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Opened stories are dimmed: hide them, or show them again, with {}.",
+                key(StoriesToggleHideRead)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Hide the selected story, or show it back, with {}. Stories and comments matching the killfile of the config file are hidden too: reveal them all with {}.",
+                key(StoriesToggleHidden),
+                key(ToggleRevealHidden)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Filter the stories by title, domain or author with {}, as you type. Keep the matching stories with {}, or clear the filter with {}.",
+                key(StoriesEditFilter),
+                key(SelectItem),
+                key(Back)
+            )),
             Line::from(""),
             Line::from(""),
             Line::from("--- On a story page ---"),
             Line::from(""),
-            Line::from(
-                "Open a tab in your browser for the selected story (or its source) with 'o'.",
-            ),
+            Line::from(format!(
+                "Open a tab in your browser for the selected story (or its source) with {}.",
+                key(OpenExternalOrHackerNewsLink)
+            )),
             Line::from(""),
-            Line::from("Toggle comments with 'tab'."),
+            Line::from(format!("Toggle comments with {}.", key(ItemToggleComments))),
             Line::from(""),
-            Line::from(
-                "Navigate comments with the up and down arrow keys, and focus a comment with the 'enter' key.",
-            ),
+            Line::from(format!(
+                "Navigate comments with {} and {}, and focus a comment with {}.",
+                key(NavigateUp),
+                key(NavigateDown),
+                key(ItemExpandFocusedComment)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Comments posted since your last visit are marked as NEW: jump to the next or previous one, across the whole thread, with {} and {}.",
+                key(ItemNextNewComment),
+                key(ItemPreviousNewComment)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Switch to the whole comments tree with {}: collapse or expand the focused comment with {}, go to its parent with {}, its next sibling with {} or the next top-level comment with {}.",
+                key(ItemToggleCommentsTree),
+                key(ItemExpandFocusedComment),
+                key(CommentsTreeGoToParent),
+                key(CommentsTreeNextSibling),
+                key(CommentsTreeNextTopLevel)
+            )),
             Line::from(""),
             Line::from(""),
             Line::from(""),
             Line::from("--- On a comment ---"),
             Line::from(""),
-            Line::from("Open the user profile with 'p'."),
+            Line::from(format!(
+                "Open the user profile with {}.",
+                key(FocusedCommentViewUserProfile)
+            )),
+            Line::from(""),
+            Line::from(""),
+            Line::from("--- On the past front page ---"),
+            Line::from(""),
+            Line::from(format!(
+                "Browse to the previous or next day with {} and {}.",
+                key(PastFrontPagePreviousDay),
+                key(PastFrontPageNextDay)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Pick a day with {}, then type it as YYYY-MM-DD and press {}.",
+                key(PastFrontPageEditDay),
+                key(SelectItem)
+            )),
+            Line::from(""),
+            Line::from(""),
+            Line::from("--- On the \"Who is hiring?\" page ---"),
+            Line::from(""),
+            Line::from(format!(
+                "Toggle the remote only and unseen only filters with {} and {}.",
+                key(WhoIsHiringToggleRemoteOnly),
+                key(WhoIsHiringToggleHideSeen)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Filter by location with {} and by keyword with {}, then press {}.",
+                key(WhoIsHiringEditLocation),
+                key(WhoIsHiringEditKeyword),
+                key(SelectItem)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Mark the selected posting as seen (or not) with {}.",
+                key(WhoIsHiringToggleSeen)
+            )),
+            Line::from(""),
+            Line::from(""),
+            Line::from("--- On the history page ---"),
+            Line::from(""),
+            Line::from(format!(
+                "Filter by title or user with {}: the list updates as you type.",
+                key(BrowsingHistoryEditFilter)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Open back the selected story, comment or user with {}.",
+                key(SelectItem)
+            )),
+            Line::from(""),
+            Line::from(""),
+            Line::from("--- Bookmarks ---"),
+            Line::from(""),
+            Line::from(format!(
+                "Bookmark a story with {}, or the focused comment with {}.",
+                key(ToggleBookmark),
+                key(FocusedCommentToggleBookmark)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Watch a story with {}: its score, comments count and front page rank are then charted on its page, as are the ones of the bookmarked stories.",
+                key(ToggleWatch)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "On the bookmarks page, edit the tags with {} and the notes with {}.",
+                key(BookmarksEditTags),
+                key(BookmarksEditNotes)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Filter by tag with {}, change the sorting with {} and remove with {}.",
+                key(BookmarksCycleTagFilter),
+                key(BookmarksToggleSorting),
+                key(BookmarksRemove)
+            )),
             Line::from(""),
             Line::from(""),
             Line::from("--- On the settings page ---"),
             Line::from(""),
-            Line::from("Navigate between settings with the up and down arrow keys."),
+            Line::from(format!(
+                "Navigate between settings with {} and {}.",
+                key(NavigateUp),
+                key(NavigateDown)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Toggle a setting, or pick its next value, with {}.",
+                key(SettingsToggleControl)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Increase or decrease a value with {} and {}.",
+                key(SettingsIncreaseValue),
+                key(SettingsDecreaseValue)
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "Type in a number or a color with {}, then apply with {} or cancel with {}.",
+                key(SettingsEditValue),
+                key(SelectItem),
+                key(Back)
+            )),
             Line::from(""),
-            Line::from("Toggle a setting with 'tab'."),
+            Line::from(format!(
+                "Reset a setting to its default value with {}.",
+                key(SettingsResetValue)
+            )),
             Line::from(""),
-            Line::from("Go back with 'escape'."),
+            Line::from(format!("Go back with {}.", key(Back))),
+            Line::from(""),
+            Line::from(""),
+            Line::from("--- Key bindings ---"),
+            Line::from(""),
+            Line::from(
+                "Pick a preset (Default, Vim or Emacs) and override bindings in the [keybindings] table of hncli.toml.",
+            ),
         ];
         let paragraph = Paragraph::new(text)
             .block(Self::get_common_block())
@@ -3,6 +3,7 @@ mod common;
 mod corpus_widget;
 mod nested_component;
 mod top_component;
+mod tree_widget;
 
 pub use nested_component::{COMMENT_ITEM_NESTED_COMMENTS_ID, CommentItemNestedComments};
 pub use top_component::{ITEM_TOP_LEVEL_COMMENTS_ID, ItemTopLevelComments};
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use log::warn;
 use ratatui::{
     buffer::Buffer,
@@ -9,11 +11,17 @@ use unicode_width::UnicodeWidthStr;
 
 use crate::{
     api::types::HnItemIdScalar,
-    ui::{displayable_item::DisplayableHackerNewsItemComments, theme::UiTheme},
+    ui::{
+        components::widgets::custom_list::ListJump,
+        displayable_item::DisplayableHackerNewsItemComments, theme::UiThemePalette,
+    },
 };
 
 use super::corpus_widget::CommentWidget;
 
+/// Number of same-level comments skipped by a page-wise navigation, comments being displayed one at a time.
+const COMMENTS_JUMP_PAGE_SIZE: usize = 10;
+
 /// Persistent state of `ItemCommentsWidget`.
 #[derive(Debug, Default)]
 pub struct ItemCommentsWidgetState {
@@ -23,6 +31,8 @@ pub struct ItemCommentsWidgetState {
     focused_same_level_comments_count: usize,
     /// If Some, prepare to restore from history navigation state the focused comment.
     history_should_focus_comment_id: Option<HnItemIdScalar>,
+    /// IDs of the same-level comments hidden by the killfile, skipped when navigating.
+    hidden_comments_ids: HashSet<HnItemIdScalar>,
 }
 
 // TODO: unit tests harness (create new state.rs sub-module)
@@ -41,12 +51,11 @@ impl ItemCommentsWidgetState {
     ) -> &Option<(usize, HnItemIdScalar)> {
         self.focused_same_level_comments_count = parent_item_kids.len();
         if let Some((focused_index, _)) = self.focused_comment {
-            let previous_index = if focused_index == 0 {
-                parent_item_kids.len() - 1
-            } else {
-                focused_index - 1
-            };
-            self.focused_comment = Some((previous_index, parent_item_kids[previous_index]));
+            let count = parent_item_kids.len();
+            self.focused_comment = self.find_visible_comment(
+                parent_item_kids,
+                (1..=count).map(|offset| (focused_index + count - offset) % count),
+            );
             &self.focused_comment
         } else {
             self.reset_focused_comment(parent_item_kids)
@@ -59,14 +68,75 @@ impl ItemCommentsWidgetState {
     ) -> &Option<(usize, HnItemIdScalar)> {
         self.focused_same_level_comments_count = parent_item_kids.len();
         if let Some((focused_index, _)) = self.focused_comment {
-            let next_index = (focused_index + 1) % parent_item_kids.len();
-            self.focused_comment = Some((next_index, parent_item_kids[next_index]));
+            let count = parent_item_kids.len();
+            self.focused_comment = self.find_visible_comment(
+                parent_item_kids,
+                (1..=count).map(|offset| (focused_index + offset) % count),
+            );
             &self.focused_comment
         } else {
             self.reset_focused_comment(parent_item_kids)
         }
     }
 
+    /// Move the focus page-wise among the same-level comments, or to the first or last one.
+    pub fn jump_main_comment(
+        &mut self,
+        parent_item_kids: &[HnItemIdScalar],
+        jump: ListJump,
+    ) -> &Option<(usize, HnItemIdScalar)> {
+        let count = parent_item_kids.len();
+        self.focused_same_level_comments_count = count;
+        if count == 0 {
+            self.focused_comment = None;
+            return &self.focused_comment;
+        }
+        let focused_index = self.focused_comment.map_or(0, |(index, _)| index);
+        let target_index = jump.apply(focused_index, count, COMMENTS_JUMP_PAGE_SIZE);
+        // the nearest visible comment, preferably in the jump direction
+        let indices: Vec<usize> = match jump {
+            ListJump::PageDown | ListJump::HalfPageDown | ListJump::First => (target_index..count)
+                .chain((0..target_index).rev())
+                .collect(),
+            ListJump::PageUp | ListJump::HalfPageUp | ListJump::Last => (0..=target_index)
+                .rev()
+                .chain(target_index + 1..count)
+                .collect(),
+        };
+        self.focused_comment = self.find_visible_comment(parent_item_kids, indices.into_iter());
+        &self.focused_comment
+    }
+
+    /// Hide the given same-level comments, moving the focus away from them if needed.
+    pub fn set_hidden_comments_ids(
+        &mut self,
+        hidden_comments_ids: HashSet<HnItemIdScalar>,
+        parent_item_kids: &[HnItemIdScalar],
+    ) {
+        self.hidden_comments_ids = hidden_comments_ids;
+        let is_focused_comment_hidden = self
+            .focused_comment
+            .is_none_or(|(_, id)| self.hidden_comments_ids.contains(&id));
+        if is_focused_comment_hidden {
+            self.next_main_comment(parent_item_kids);
+        }
+    }
+
+    pub fn get_hidden_comments_count(&self) -> usize {
+        self.hidden_comments_ids.len()
+    }
+
+    /// Find the first comment, among the given indices, not hidden by the killfile.
+    fn find_visible_comment(
+        &self,
+        parent_item_kids: &[HnItemIdScalar],
+        mut indices: impl Iterator<Item = usize>,
+    ) -> Option<(usize, HnItemIdScalar)> {
+        indices
+            .find(|index| !self.hidden_comments_ids.contains(&parent_item_kids[*index]))
+            .map(|index| (index, parent_item_kids[index]))
+    }
+
     pub fn get_focused_comment_id(&self) -> Option<HnItemIdScalar> {
         self.focused_comment.map(|(_, id)| id)
     }
@@ -148,11 +218,8 @@ impl ItemCommentsWidgetState {
         parent_item_kids: &[HnItemIdScalar],
     ) -> &Option<(usize, HnItemIdScalar)> {
         self.focused_same_level_comments_count = parent_item_kids.len();
-        if let Some(first_comment_id) = parent_item_kids.first() {
-            self.focused_comment = Some((0, *first_comment_id));
-        } else {
-            self.focused_comment = None;
-        }
+        self.focused_comment =
+            self.find_visible_comment(parent_item_kids, 0..parent_item_kids.len());
         &self.focused_comment
     }
 }
@@ -161,23 +228,31 @@ impl ItemCommentsWidgetState {
 #[derive(Debug)]
 pub struct ItemCommentsWidget<'a> {
     /// Theme.
-    theme: &'a UiTheme,
+    theme: &'a UiThemePalette,
     /// Persistent state.
     state: &'a ItemCommentsWidgetState,
     /// Comments of the top-level parent item.
     comments: &'a DisplayableHackerNewsItemComments,
+    /// Depth of the focused comment in the thread, 0 being a top-level comment.
+    depth: usize,
+    /// ID of the latest comment seen on the previous visit of the thread, if any.
+    latest_seen_comment_id: Option<HnItemIdScalar>,
 }
 
 impl<'a> ItemCommentsWidget<'a> {
     pub fn with_comments(
-        theme: &'a UiTheme,
+        theme: &'a UiThemePalette,
         state: &'a ItemCommentsWidgetState,
         comments: &'a DisplayableHackerNewsItemComments,
+        depth: usize,
+        latest_seen_comment_id: Option<HnItemIdScalar>,
     ) -> Self {
         Self {
             theme,
             state,
             comments,
+            depth,
+            latest_seen_comment_id,
         }
     }
 }
@@ -210,7 +285,16 @@ impl<'a> Widget for ItemCommentsWidget<'a> {
         };
 
         // Comment rendering
-        let focused_comment_widget = CommentWidget::with_comment(self.theme, focused_comment);
+        let is_new = |comment_id: HnItemIdScalar| {
+            self.latest_seen_comment_id
+                .is_some_and(|latest_seen_id| comment_id > latest_seen_id)
+        };
+        let focused_comment_widget = CommentWidget::with_comment(
+            self.theme,
+            focused_comment,
+            self.depth,
+            is_new(focused_comment_id),
+        );
         focused_comment_widget.render(
             area.inner(Margin {
                 vertical: PADDING,
@@ -227,6 +311,7 @@ impl<'a> Widget for ItemCommentsWidget<'a> {
             area.width,
             FOOTER_HEIGHT,
         );
+        let new_comments_count = self.comments.keys().filter(|id| is_new(**id)).count();
         let footer_text = if focused_comment_kids_count > 0 {
             format!(
                 "Comment {} / {} | {} sub-comment{}",
@@ -246,6 +331,17 @@ impl<'a> Widget for ItemCommentsWidget<'a> {
                 self.state.focused_same_level_comments_count
             )
         };
+        let footer_text = if new_comments_count > 0 {
+            format!("{footer_text} | {new_comments_count} new in the thread")
+        } else {
+            footer_text
+        };
+        let hidden_comments_count = self.state.get_hidden_comments_count();
+        let footer_text = if hidden_comments_count > 0 {
+            format!("{footer_text} | {hidden_comments_count} hidden")
+        } else {
+            footer_text
+        };
         buf.set_string(
             footer_area.left() + (footer_area.width - footer_text.width() as u16) / 2,
             footer_area.y,
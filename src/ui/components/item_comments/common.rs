@@ -1,15 +1,17 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use futures::lock::Mutex;
 use ratatui::layout::Rect;
 
 use crate::{
-    app::{AppContext, state::AppState},
+    api::types::HnItemIdScalar,
+    app::{AppContext, history::BookmarkedItem, state::AppState},
     errors::Result,
     ui::{
         common::{RenderFrame, UiTickScalar},
-        components::common::render_text_message,
+        components::common::{render_text_message, toggle_bookmark},
         displayable_item::{DisplayableHackerNewsItem, DisplayableHackerNewsItemComments},
+        flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
         utils::{debouncer::Debouncer, loader::Loader},
     },
 };
@@ -59,6 +61,49 @@ impl ItemCommentsCommon {
         F: FnOnce() -> Option<String>,
     {
         let theme = ctx.get_theme();
+        let state = ctx.get_state();
+        let Some(viewed_item_comments) = self.render_status_message(f, inside, ctx) else {
+            return Ok(());
+        };
+
+        // Specific error cases
+        if let Some(error_message) = specific_error_handler() {
+            render_text_message(f, inside, &error_message, theme);
+            return Ok(());
+        }
+        if self.widget_state.get_focused_comment_id().is_none()
+            && self.widget_state.get_hidden_comments_count() > 0
+        {
+            render_text_message(f, inside, "All the comments are hidden.", theme);
+            return Ok(());
+        }
+
+        // Widget rendering
+        let depth = state
+            .get_currently_viewed_item_comments_chain()
+            .len()
+            .saturating_sub(1);
+        let widget = ItemCommentsWidget::with_comments(
+            theme,
+            &self.widget_state,
+            viewed_item_comments,
+            depth,
+            state.get_previous_visit_latest_seen_comment_id(),
+        );
+        f.render_widget(widget, inside);
+
+        Ok(())
+    }
+
+    /// Render the loading and common error messages, if applicable,
+    /// otherwise return the cached comments to render.
+    pub(super) fn render_status_message(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        ctx: &AppContext,
+    ) -> Option<&DisplayableHackerNewsItemComments> {
+        let theme = ctx.get_theme();
 
         // (Initial) loading case
         let state = ctx.get_state();
@@ -67,52 +112,35 @@ impl ItemCommentsCommon {
             || state.get_currently_viewed_item_switched()
         {
             render_text_message(f, inside, &self.loader.text(), theme);
-            return Ok(());
+            return None;
         }
 
         // Unavailable comments cache case
-        let viewed_item_comments = if let Some(cached_comments) = &self.cached_comments {
-            cached_comments
-        } else {
+        let Some(viewed_item_comments) = &self.cached_comments else {
             render_text_message(
                 f,
                 inside,
                 "Comments fetching issue. Please retry later.",
                 theme,
             );
-            return Ok(());
+            return None;
         };
 
         // Common error cases
-        if ctx
-            .get_state()
-            .get_currently_viewed_item_comments_chain()
-            .is_empty()
-        {
+        if state.get_currently_viewed_item_comments_chain().is_empty() {
             render_text_message(
                 f,
                 inside,
                 "An error has occurred on this thread. Please retry later.",
                 theme,
             );
-            return Ok(());
+            return None;
         } else if viewed_item_comments.is_empty() {
             render_text_message(f, inside, "No comments yet.", theme);
-            return Ok(());
-        }
-
-        // Specific error cases
-        if let Some(error_message) = specific_error_handler() {
-            render_text_message(f, inside, &error_message, theme);
-            return Ok(());
+            return None;
         }
 
-        // Widget rendering
-        let widget =
-            ItemCommentsWidget::with_comments(theme, &self.widget_state, viewed_item_comments);
-        f.render_widget(widget, inside);
-
-        Ok(())
+        Some(viewed_item_comments)
     }
 
     /// Try to retrieve a reference to the currently focused comment, if any.
@@ -134,4 +162,257 @@ impl ItemCommentsCommon {
             })
             .await
     }
+
+    /// Mark all the currently cached comments of the viewed item as seen, for the next visits.
+    pub(super) fn mark_cached_comments_as_seen(&self, ctx: &mut AppContext) {
+        let item_id = if let Some(item) = ctx.get_state().get_currently_viewed_item() {
+            item.id
+        } else {
+            return;
+        };
+        if let Some(latest_comment_id) = self
+            .cached_comments
+            .as_ref()
+            .and_then(|comments| comments.keys().max().copied())
+        {
+            ctx.get_history_mut()
+                .mark_comments_as_seen(item_id, latest_comment_id);
+        }
+    }
+
+    /// Hide the same-level comments killed by the killfile, unless revealed.
+    pub(super) fn refresh_hidden_comments(
+        &mut self,
+        ctx: &AppContext,
+        parent_item_kids: &[HnItemIdScalar],
+    ) {
+        let killfile = ctx.get_config().get_killfile();
+        let hidden_comments_ids = match &self.cached_comments {
+            Some(comments) if !ctx.get_state().get_reveal_hidden_items() => parent_item_kids
+                .iter()
+                .filter(|id| {
+                    comments
+                        .get(id)
+                        .is_some_and(|comment| killfile.kills_comment(comment))
+                })
+                .copied()
+                .collect(),
+            _ => HashSet::new(),
+        };
+        self.widget_state
+            .set_hidden_comments_ids(hidden_comments_ids, parent_item_kids);
+    }
+
+    /// Reveal, or hide back, the comments killed by the killfile.
+    pub(super) fn toggle_reveal_hidden_comments(
+        &mut self,
+        ctx: &mut AppContext,
+        parent_item_kids: &[HnItemIdScalar],
+    ) {
+        let reveal_hidden = ctx.get_state().get_reveal_hidden_items();
+        ctx.get_state_mut().set_reveal_hidden_items(!reveal_hidden);
+        self.refresh_hidden_comments(ctx, parent_item_kids);
+        let focused_comment_id = self.widget_state.get_focused_comment_id();
+        ctx.get_state_mut()
+            .replace_latest_in_currently_viewed_item_comments_chain(focused_comment_id);
+    }
+
+    /// Bookmark the focused comment, or remove its bookmark, along with its parents chain.
+    pub(super) async fn toggle_focused_comment_bookmark(&self, ctx: &mut AppContext<'_>) -> bool {
+        let Some(focused_comment) = self.get_focused_comment(ctx.get_state()).await else {
+            return false;
+        };
+        let comments_chain = ctx.get_state().get_currently_viewed_item_comments_chain();
+        let comments_chain = if comments_chain.last() == Some(&focused_comment.id) {
+            comments_chain.to_vec()
+        } else {
+            vec![focused_comment.id]
+        };
+        Self::toggle_comment_bookmark(ctx, &focused_comment, comments_chain)
+    }
+
+    /// Bookmark the given comment of the viewed item, or remove its bookmark, along with its parents chain.
+    pub(super) fn toggle_comment_bookmark(
+        ctx: &mut AppContext,
+        comment: &DisplayableHackerNewsItem,
+        comments_chain: Vec<HnItemIdScalar>,
+    ) -> bool {
+        let Some(story) = ctx.get_state().get_currently_viewed_item() else {
+            return false;
+        };
+        let bookmarked_item = BookmarkedItem::Comment {
+            id: comment.id,
+            by_username: comment.by_username.clone(),
+            story_id: story.id,
+            story_title: story.title.clone().unwrap_or_default(),
+            comments_chain,
+        };
+        toggle_bookmark(ctx, bookmarked_item);
+        true
+    }
+
+    /// Jump to the next (or previous) new comment across the whole thread, if any.
+    pub(super) async fn jump_to_new_comment(
+        &self,
+        ctx: &mut AppContext<'_>,
+        forward: bool,
+    ) -> bool {
+        let thread_kids = if let Some(item) = ctx.get_state().get_currently_viewed_item() {
+            item.kids.clone().unwrap_or_default()
+        } else {
+            return false;
+        };
+        let latest_seen_comment_id =
+            if let Some(id) = ctx.get_state().get_previous_visit_latest_seen_comment_id() {
+                id
+            } else {
+                Self::flash_no_new_comments(ctx);
+                return false;
+            };
+
+        let current_chain = ctx
+            .get_state()
+            .get_currently_viewed_item_comments_chain()
+            .to_vec();
+        let jump = ctx
+            .get_state()
+            .use_currently_viewed_item_comments(|comments| {
+                let comments = comments?;
+                let comments_chain = find_new_comment_chain(
+                    &thread_kids,
+                    comments,
+                    &current_chain,
+                    latest_seen_comment_id,
+                    forward,
+                )?;
+                let parent_comments: Vec<_> = comments_chain[..comments_chain.len() - 1]
+                    .iter()
+                    .filter_map(|id| comments.get(id).cloned())
+                    .collect();
+                Some((parent_comments, comments_chain))
+            })
+            .await;
+
+        if let Some((parent_comments, comments_chain)) = jump {
+            ctx.router_jump_to_comment(parent_comments, comments_chain);
+            true
+        } else {
+            Self::flash_no_new_comments(ctx);
+            false
+        }
+    }
+
+    pub(super) fn flash_no_new_comments(ctx: &mut AppContext) {
+        ctx.get_state_mut().set_flash_message(FlashMessage::new(
+            "No new comments since the last visit.",
+            FlashMessageType::Info,
+            FLASH_MESSAGE_DEFAULT_DURATION_MS,
+        ));
+    }
+}
+
+/// Find the chain, starting at its top-level comment, of the next (or previous) new comment
+/// after the one of the given chain, in the display order of the whole thread and wrapping around.
+pub(super) fn find_new_comment_chain(
+    thread_kids: &[HnItemIdScalar],
+    comments: &DisplayableHackerNewsItemComments,
+    current_chain: &[HnItemIdScalar],
+    latest_seen_comment_id: HnItemIdScalar,
+    forward: bool,
+) -> Option<Vec<HnItemIdScalar>> {
+    let mut chains = vec![];
+    collect_comments_chains(thread_kids, comments, &mut vec![], &mut chains);
+    let count = chains.len();
+    if count == 0 {
+        return None;
+    }
+
+    let current_index = chains
+        .iter()
+        .position(|chain| chain == current_chain)
+        .unwrap_or(if forward { count - 1 } else { 0 });
+    (1..=count)
+        .map(|offset| {
+            if forward {
+                (current_index + offset) % count
+            } else {
+                (current_index + count - offset) % count
+            }
+        })
+        .map(|index| &chains[index])
+        .find(|chain| chain.last().is_some_and(|id| *id > latest_seen_comment_id))
+        .cloned()
+}
+
+/// Depth-first collection of the chains of all the (cached) comments under the given ones.
+fn collect_comments_chains(
+    kids: &[HnItemIdScalar],
+    comments: &DisplayableHackerNewsItemComments,
+    parents_chain: &mut Vec<HnItemIdScalar>,
+    chains: &mut Vec<Vec<HnItemIdScalar>>,
+) {
+    for kid_id in kids {
+        let kid = if let Some(comment) = comments.get(kid_id) {
+            comment
+        } else {
+            continue;
+        };
+        parents_chain.push(*kid_id);
+        chains.push(parents_chain.clone());
+        if let Some(kid_kids) = &kid.kids {
+            collect_comments_chains(kid_kids, comments, parents_chain, chains);
+        }
+        parents_chain.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::types::HnItemIdScalar,
+        ui::displayable_item::{DisplayableHackerNewsItem, DisplayableHackerNewsItemComments},
+    };
+
+    use super::find_new_comment_chain;
+
+    #[test]
+    fn test_find_new_comment_chain() {
+        // 10
+        // |- 11
+        //    |- 30 (new)
+        // 20 (new)
+        // |- 25 (new)
+        let mut comments = DisplayableHackerNewsItemComments::new();
+        for (id, kids) in [
+            (10, &[11][..]),
+            (11, &[30]),
+            (30, &[]),
+            (20, &[25]),
+            (25, &[]),
+        ] {
+            comments.insert(
+                id,
+                DisplayableHackerNewsItem {
+                    kids: Some(kids.to_vec()),
+                    ..DisplayableHackerNewsItem::test_item(id)
+                },
+            );
+        }
+        let thread_kids = [10, 20];
+
+        let find = |current_chain: &[HnItemIdScalar], forward| {
+            find_new_comment_chain(&thread_kids, &comments, current_chain, 15, forward)
+        };
+        assert_eq!(find(&[10], true), Some(vec![10, 11, 30]));
+        assert_eq!(find(&[10, 11, 30], true), Some(vec![20]));
+        assert_eq!(find(&[20], true), Some(vec![20, 25]));
+        assert_eq!(find(&[20, 25], true), Some(vec![10, 11, 30]));
+        assert_eq!(find(&[10], false), Some(vec![20, 25]));
+        assert_eq!(find(&[20], false), Some(vec![10, 11, 30]));
+        assert_eq!(find(&[404], true), Some(vec![10, 11, 30]));
+        assert_eq!(
+            find_new_comment_chain(&thread_kids, &comments, &[10], 30, true),
+            None
+        );
+    }
 }
@@ -1,29 +1,45 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Modifier, Style},
     widgets::Widget,
 };
+use unicode_width::UnicodeWidthStr;
 
 use crate::ui::{
-    displayable_item::DisplayableHackerNewsItem, theme::UiTheme, utils::html_to_plain_text,
+    displayable_item::DisplayableHackerNewsItem, theme::UiThemePalette, utils::html_to_plain_text,
 };
 
 #[derive(Debug)]
 pub struct CommentWidget<'a> {
-    theme: &'a UiTheme,
+    theme: &'a UiThemePalette,
     comment: &'a DisplayableHackerNewsItem,
+    /// Depth of the comment in the thread, 0 being a top-level comment.
+    depth: usize,
+    /// Has the comment been posted since the previous visit of the thread?
+    is_new: bool,
 }
 
 impl<'a> CommentWidget<'a> {
-    pub fn with_comment(theme: &'a UiTheme, comment: &'a DisplayableHackerNewsItem) -> Self {
+    pub fn with_comment(
+        theme: &'a UiThemePalette,
+        comment: &'a DisplayableHackerNewsItem,
+        depth: usize,
+        is_new: bool,
+    ) -> Self {
         assert!(comment.is_comment);
-        Self { theme, comment }
+        Self {
+            theme,
+            comment,
+            depth,
+            is_new,
+        }
     }
 }
 
 pub const PADDING: u16 = 3;
 pub const HEADER_HEIGHT: u16 = 5;
+pub const NEW_BADGE: &str = " NEW ";
 
 impl<'a> Widget for CommentWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -34,8 +50,19 @@ impl<'a> Widget for CommentWidget<'a> {
             header_area.x + PADDING,
             header_area.y,
             &self.comment.by_username,
-            Style::default().fg(self.theme.get_main_color()),
+            Style::default().fg(self.theme.get_comment_depth_color(self.depth)),
         );
+        // -> new badge
+        if self.is_new {
+            buf.set_string(
+                header_area.x + PADDING * 2 + self.comment.by_username.width() as u16,
+                header_area.y,
+                NEW_BADGE,
+                Style::default()
+                    .fg(self.theme.get_highlight_color())
+                    .add_modifier(Modifier::REVERSED | Modifier::BOLD),
+            );
+        }
         // -> posted since
         buf.set_string(
             header_area.right() - self.comment.posted_since.len() as u16 - PADDING,
@@ -64,7 +91,7 @@ impl<'a> Widget for CommentWidget<'a> {
                 PADDING * 2,
                 corpus_area.top() + i as u16,
                 corpus_line,
-                Style::default().fg(Color::White),
+                Style::default().fg(self.theme.get_text_color()),
             );
         }
     }
@@ -9,7 +9,8 @@ use crate::{
     app::{AppContext, state::AppState},
     errors::{HnCliError, Result},
     ui::{
-        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar, get_ticks_in_duration},
+        components::widgets::custom_list::ListJump,
         displayable_item::{
             CachedHackerNewsItemCommentsIds, DisplayableHackerNewsItem,
             DisplayableHackerNewsItemComments,
@@ -21,8 +22,6 @@ use crate::{
 
 use super::common::ItemCommentsCommon;
 
-const MEAN_TICKS_BETWEEN_UPDATES: UiTickScalar = 1800; // approx. every 3 minutes
-
 pub const COMMENT_ITEM_NESTED_COMMENTS_ID: UiComponentId = "item_nested_comments";
 
 /// Sub-main level (= nested) comments component.
@@ -59,8 +58,10 @@ impl UiComponent for CommentItemNestedComments {
             self.was_fetching
         };
 
-        let mut should_update = self.common.ticks_since_last_update >= MEAN_TICKS_BETWEEN_UPDATES
-            || Self::get_parent_comment_id(ctx.get_state()) != self.parent_comment_id;
+        let mut should_update = self.common.ticks_since_last_update
+            >= get_ticks_in_duration(ctx.get_config().get_comments_refresh_interval())
+            || Self::get_parent_comment_id(ctx.get_state()) != self.parent_comment_id
+            || ctx.get_state().get_comment_to_focus_id().is_some();
         self.common.loader.update();
 
         if self.was_fetching && !*self.common.fetching.lock().await {
@@ -84,7 +85,9 @@ impl UiComponent for CommentItemNestedComments {
         }
 
         let mut fetched = false;
-        if let Some(fetched_comments) = self.common.fetched_comments.lock().await.take() {
+        // taken beforehand, not to hold the lock while updating the comments
+        let fetched_comments = self.common.fetched_comments.lock().await.take();
+        if let Some(fetched_comments) = fetched_comments {
             ctx.get_state_mut()
                 .update_currently_viewed_item_comments(Some(fetched_comments))
                 .await;
@@ -106,6 +109,9 @@ impl UiComponent for CommentItemNestedComments {
                     Ok::<(), HnCliError>(())
                 })
                 .await?;
+            self.common
+                .refresh_hidden_comments(ctx, &parent_comment_kids);
+            self.common.mark_cached_comments_as_seen(ctx);
             fetched = true;
         }
 
@@ -162,6 +168,17 @@ impl UiComponent for CommentItemNestedComments {
             ctx.get_state_mut().set_previously_viewed_comment_id(None);
         }
 
+        // Jumped-to comment, if applicable
+        if let Some(comment_id) = ctx.get_state().get_comment_to_focus_id() {
+            self.common.widget_state.restore_focused_comment_id(
+                comment_id,
+                &Self::get_parent_comment_kids(ctx.get_state())
+                    .await
+                    .unwrap_or(vec![]),
+            );
+            ctx.get_state_mut().set_comment_to_focus_id(None);
+        }
+
         Ok(())
     }
 
@@ -172,65 +189,102 @@ impl UiComponent for CommentItemNestedComments {
 
         let inputs = ctx.get_inputs();
         // TODO: refactor with top component usage as much as possible
-        Ok(if inputs.is_active(&ApplicationAction::NavigateUp) {
-            let parent_comment_kids =
-                if let Some(kids) = Self::get_parent_comment_kids(ctx.get_state()).await {
-                    kids
-                } else {
-                    return Ok(false);
-                };
-            let new_focused_id = self
-                .common
-                .widget_state
-                .previous_main_comment(parent_comment_kids.as_slice())
-                .map(|(_, id)| id);
-            ctx.get_state_mut()
-                .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
-            true
-        } else if inputs.is_active(&ApplicationAction::NavigateDown) {
-            let parent_comment_kids =
-                if let Some(kids) = Self::get_parent_comment_kids(ctx.get_state()).await {
-                    kids
+        Ok(
+            if inputs.is_active(&ApplicationAction::ToggleRevealHidden) {
+                let parent_comment_kids =
+                    if let Some(kids) = Self::get_parent_comment_kids(ctx.get_state()).await {
+                        kids
+                    } else {
+                        return Ok(false);
+                    };
+                self.common
+                    .toggle_reveal_hidden_comments(ctx, &parent_comment_kids);
+                true
+            } else if inputs.is_active(&ApplicationAction::NavigateUp) {
+                let parent_comment_kids =
+                    if let Some(kids) = Self::get_parent_comment_kids(ctx.get_state()).await {
+                        kids
+                    } else {
+                        return Ok(false);
+                    };
+                let new_focused_id = self
+                    .common
+                    .widget_state
+                    .previous_main_comment(parent_comment_kids.as_slice())
+                    .map(|(_, id)| id);
+                ctx.get_state_mut()
+                    .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+                true
+            } else if inputs.is_active(&ApplicationAction::NavigateDown) {
+                let parent_comment_kids =
+                    if let Some(kids) = Self::get_parent_comment_kids(ctx.get_state()).await {
+                        kids
+                    } else {
+                        return Ok(false);
+                    };
+                let new_focused_id = self
+                    .common
+                    .widget_state
+                    .next_main_comment(parent_comment_kids.as_slice())
+                    .map(|(_, id)| id);
+                ctx.get_state_mut()
+                    .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+                true
+            } else if let Some(jump) = ListJump::from_inputs(inputs) {
+                let parent_comment_kids =
+                    if let Some(kids) = Self::get_parent_comment_kids(ctx.get_state()).await {
+                        kids
+                    } else {
+                        return Ok(false);
+                    };
+                let new_focused_id = self
+                    .common
+                    .widget_state
+                    .jump_main_comment(parent_comment_kids.as_slice(), jump)
+                    .map(|(_, id)| id);
+                ctx.get_state_mut()
+                    .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+                true
+            } else if inputs.is_active(&ApplicationAction::ItemExpandFocusedComment) {
+                if let Some(focused_comment) =
+                    self.common.get_focused_comment(ctx.get_state()).await
+                {
+                    if focused_comment
+                        .kids
+                        .as_ref()
+                        .is_none_or(|kids| kids.is_empty())
+                    {
+                        // a comment with no sub-comments cannot be focused
+                        return Ok(false);
+                    }
+                    ctx.router_push_navigation_stack(AppRoute::ItemNestedComments(
+                        focused_comment.clone(),
+                    ));
+                    true
                 } else {
-                    return Ok(false);
-                };
-            let new_focused_id = self
-                .common
-                .widget_state
-                .next_main_comment(parent_comment_kids.as_slice())
-                .map(|(_, id)| id);
-            ctx.get_state_mut()
-                .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
-            true
-        } else if inputs.is_active(&ApplicationAction::ItemExpandFocusedComment) {
-            if let Some(focused_comment) = self.common.get_focused_comment(ctx.get_state()).await {
-                if focused_comment
-                    .kids
-                    .as_ref()
-                    .is_none_or(|kids| kids.is_empty())
+                    false
+                }
+            } else if inputs.is_active(&ApplicationAction::ItemNextNewComment) {
+                self.common.jump_to_new_comment(ctx, true).await
+            } else if inputs.is_active(&ApplicationAction::ItemPreviousNewComment) {
+                self.common.jump_to_new_comment(ctx, false).await
+            } else if inputs.is_active(&ApplicationAction::FocusedCommentToggleBookmark) {
+                self.common.toggle_focused_comment_bookmark(ctx).await
+            } else if inputs.is_active(&ApplicationAction::FocusedCommentViewUserProfile) {
+                if let Some(focused_comment) =
+                    self.common.get_focused_comment(ctx.get_state()).await
                 {
-                    // a comment with no sub-comments cannot be focused
-                    return Ok(false);
+                    ctx.router_push_navigation_stack(AppRoute::UserProfile(
+                        focused_comment.by_username.clone(),
+                    ));
+                    true
+                } else {
+                    false
                 }
-                ctx.router_push_navigation_stack(AppRoute::ItemNestedComments(
-                    focused_comment.clone(),
-                ));
-                true
             } else {
                 false
-            }
-        } else if inputs.is_active(&ApplicationAction::FocusedCommentViewUserProfile) {
-            if let Some(focused_comment) = self.common.get_focused_comment(ctx.get_state()).await {
-                ctx.router_push_navigation_stack(AppRoute::UserProfile(
-                    focused_comment.by_username.clone(),
-                ));
-                true
-            } else {
-                false
-            }
-        } else {
-            false
-        })
+            },
+        )
     }
 
     fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
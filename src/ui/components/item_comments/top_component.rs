@@ -1,6 +1,7 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use async_trait::async_trait;
+use log::warn;
 use ratatui::layout::Rect;
 
 use crate::{
@@ -8,7 +9,8 @@ use crate::{
     app::{AppContext, state::AppState},
     errors::{HnCliError, Result},
     ui::{
-        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar, get_ticks_in_duration},
+        components::{common::render_text_message, widgets::custom_list::ListJump},
         displayable_item::{
             CachedHackerNewsItemCommentsIds, DisplayableHackerNewsItem,
             DisplayableHackerNewsItemComments,
@@ -18,9 +20,10 @@ use crate::{
     },
 };
 
-use super::common::ItemCommentsCommon;
-
-const MEAN_TICKS_BETWEEN_UPDATES: UiTickScalar = 1800; // approx. every 3 minutes
+use super::{
+    common::{ItemCommentsCommon, find_new_comment_chain},
+    tree_widget::{CommentsTreeEntry, CommentsTreeState, CommentsTreeWidget},
+};
 
 pub const ITEM_TOP_LEVEL_COMMENTS_ID: UiComponentId = "item_top_comments";
 
@@ -28,6 +31,10 @@ pub const ITEM_TOP_LEVEL_COMMENTS_ID: UiComponentId = "item_top_comments";
 #[derive(Debug, Default)]
 pub struct ItemTopLevelComments {
     common: ItemCommentsCommon,
+    /// Nested comments chain restored from the history, to jump to once the comments are fetched.
+    restored_comments_chain: Option<Vec<HnItemIdScalar>>,
+    /// State of the alternative tree view of the whole thread.
+    tree_state: CommentsTreeState,
 }
 
 #[async_trait]
@@ -50,20 +57,26 @@ impl UiComponent for ItemTopLevelComments {
             self.common.inputs_debouncer.reset();
             self.common.loading = true;
 
-            // history navigation handling
+            // history navigation handling, only when opening the item
             let currently_viewed_item =
                 if let Some(item) = ctx.get_state().get_currently_viewed_item() {
                     item
                 } else {
                     return Ok(true);
                 };
-            if let Some(restored_comment_id) = ctx
-                .get_history()
-                .restored_top_level_comment_id_for_story(currently_viewed_item.id)
+            if !ctx
+                .get_state()
+                .is_previous_visit_captured(currently_viewed_item.id)
+                && let Some(restored_comments_chain) = ctx
+                    .get_history()
+                    .restored_comments_chain_for_story(currently_viewed_item.id)
+                && let Some(restored_comment_id) = restored_comments_chain.first()
             {
                 self.common
                     .widget_state
-                    .history_prepare_focus_on_comment_id(restored_comment_id);
+                    .history_prepare_focus_on_comment_id(*restored_comment_id);
+                self.restored_comments_chain =
+                    (restored_comments_chain.len() > 1).then_some(restored_comments_chain);
             }
 
             return Ok(true);
@@ -72,6 +85,10 @@ impl UiComponent for ItemTopLevelComments {
         self.common.ticks_since_last_update += elapsed_ticks;
         self.common.inputs_debouncer.tick(elapsed_ticks);
 
+        if ctx.get_state().get_comment_to_focus_id().is_some() {
+            return Ok(true);
+        }
+
         let currently_viewed_item = if let Some(item) = ctx.get_state().get_currently_viewed_item()
         {
             item
@@ -86,7 +103,8 @@ impl UiComponent for ItemTopLevelComments {
             .await
             .as_ref()
             .map_or(0, |c| c.len());
-        let should_update = self.common.ticks_since_last_update >= MEAN_TICKS_BETWEEN_UPDATES
+        let should_update = self.common.ticks_since_last_update
+            >= get_ticks_in_duration(ctx.get_config().get_comments_refresh_interval())
             || ctx
                 .get_state()
                 .use_currently_viewed_item_comments(|comments| {
@@ -121,17 +139,43 @@ impl UiComponent for ItemTopLevelComments {
         self.common.loading = true;
         if ctx.get_state().get_currently_viewed_item_switched() {
             self.common.cached_comments = None;
+            self.tree_state = CommentsTreeState::default();
             ctx.get_state_mut()
                 .set_currently_viewed_item_has_switched(false);
         }
 
+        // New comments since the previous visit
+        if let Some(item_id) = ctx
+            .get_state()
+            .get_currently_viewed_item()
+            .map(|item| item.id)
+            && !ctx.get_state().is_previous_visit_captured(item_id)
+        {
+            let latest_seen_comment_id = ctx.get_history().get_latest_seen_comment_id(item_id);
+            ctx.get_state_mut()
+                .set_previous_visit(item_id, latest_seen_comment_id);
+        }
+
+        // Jumped-to comment, if applicable
+        if let Some(comment_id) = ctx.get_state().get_comment_to_focus_id() {
+            self.common.widget_state.restore_focused_comment_id(
+                comment_id,
+                &Self::get_parent_item_kids(ctx.get_state())?,
+            );
+            ctx.get_state_mut().set_comment_to_focus_id(None);
+        }
+
         let mut fetched = false;
-        if let Some(fetched_comments) = self.common.fetched_comments.lock().await.take() {
+        // taken beforehand, not to hold the lock while updating the comments
+        let fetched_comments = self.common.fetched_comments.lock().await.take();
+        if let Some(fetched_comments) = fetched_comments {
             ctx.get_state_mut()
                 .update_currently_viewed_item_comments(Some(fetched_comments))
                 .await;
             ctx.get_state()
                 .use_currently_viewed_item_comments(|comments| {
+                    // TODO: avoid cloning
+                    self.common.cached_comments = comments.cloned();
                     self.common.widget_state.update(
                         &self
                             .common
@@ -140,12 +184,31 @@ impl UiComponent for ItemTopLevelComments {
                             .unwrap_or(&DisplayableHackerNewsItemComments::new()),
                         &Self::get_parent_item_kids(ctx.get_state())?,
                     );
-                    // TODO: avoid cloning
-                    self.common.cached_comments = comments.cloned();
                     Ok::<(), HnCliError>(())
                 })
                 .await?;
+            self.common
+                .refresh_hidden_comments(ctx, &Self::get_parent_item_kids(ctx.get_state())?);
+            self.common.mark_cached_comments_as_seen(ctx);
             fetched = true;
+
+            // keep the focused top-level comment in sync, e.g. once restored from history
+            if ctx
+                .get_state()
+                .get_currently_viewed_item_comments_chain()
+                .len()
+                <= 1
+            {
+                let focused_comment_id = self.common.widget_state.get_focused_comment_id();
+                ctx.get_state_mut()
+                    .replace_latest_in_currently_viewed_item_comments_chain(focused_comment_id);
+            }
+
+            // nested comment restored from history, if any
+            if let Some(comments_chain) = self.restored_comments_chain.take() {
+                self.jump_to_restored_comments_chain(ctx, comments_chain)
+                    .await;
+            }
         }
 
         if !fetched {
@@ -195,62 +258,314 @@ impl UiComponent for ItemTopLevelComments {
         }
 
         let parent_item_kids = Self::get_parent_item_kids(ctx.get_state())?;
-        let inputs = ctx.get_inputs();
-        Ok(if inputs.is_active(&ApplicationAction::NavigateUp) {
-            let new_focused_id = self
-                .common
-                .widget_state
-                .previous_main_comment(&parent_item_kids)
-                .map(|(_, id)| id);
+        if ctx
+            .get_inputs()
+            .is_active(&ApplicationAction::ItemToggleCommentsTree)
+        {
+            let tree_view = !ctx.get_state().get_item_page_comments_tree_view();
             ctx.get_state_mut()
-                .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+                .set_item_page_comments_tree_view(tree_view);
+            if tree_view {
+                let entries = self.build_tree_entries(ctx, &parent_item_kids);
+                self.tree_state
+                    .focus(self.common.widget_state.get_focused_comment_id(), &entries);
+            }
+            return Ok(true);
+        } else if ctx.get_state().get_item_page_comments_tree_view() {
+            return Ok(self.handle_tree_inputs(ctx, &parent_item_kids));
+        }
+
+        let inputs = ctx.get_inputs();
+        Ok(
+            if inputs.is_active(&ApplicationAction::ToggleRevealHidden) {
+                self.common
+                    .toggle_reveal_hidden_comments(ctx, &parent_item_kids);
+                true
+            } else if inputs.is_active(&ApplicationAction::NavigateUp) {
+                let new_focused_id = self
+                    .common
+                    .widget_state
+                    .previous_main_comment(&parent_item_kids)
+                    .map(|(_, id)| id);
+                ctx.get_state_mut()
+                    .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+                true
+            } else if inputs.is_active(&ApplicationAction::NavigateDown) {
+                let new_focused_id = self
+                    .common
+                    .widget_state
+                    .next_main_comment(&parent_item_kids)
+                    .map(|(_, id)| id);
+                ctx.get_state_mut()
+                    .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+                true
+            } else if let Some(jump) = ListJump::from_inputs(inputs) {
+                let new_focused_id = self
+                    .common
+                    .widget_state
+                    .jump_main_comment(&parent_item_kids, jump)
+                    .map(|(_, id)| id);
+                ctx.get_state_mut()
+                    .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+                true
+            } else if inputs.is_active(&ApplicationAction::ItemExpandFocusedComment) {
+                if let Some(focused_comment) =
+                    self.common.get_focused_comment(ctx.get_state()).await
+                {
+                    if focused_comment
+                        .kids
+                        .as_ref()
+                        .is_none_or(|kids| kids.is_empty())
+                    {
+                        // a comment with no sub-comments cannot be focused
+                        return Ok(false);
+                    }
+                    ctx.router_push_navigation_stack(AppRoute::ItemNestedComments(
+                        focused_comment.clone(),
+                    ));
+                    true
+                } else {
+                    false
+                }
+            } else if inputs.is_active(&ApplicationAction::ItemNextNewComment) {
+                self.common.jump_to_new_comment(ctx, true).await
+            } else if inputs.is_active(&ApplicationAction::ItemPreviousNewComment) {
+                self.common.jump_to_new_comment(ctx, false).await
+            } else if inputs.is_active(&ApplicationAction::FocusedCommentToggleBookmark) {
+                self.common.toggle_focused_comment_bookmark(ctx).await
+            } else if inputs.is_active(&ApplicationAction::FocusedCommentViewUserProfile) {
+                if let Some(focused_comment) =
+                    self.common.get_focused_comment(ctx.get_state()).await
+                {
+                    ctx.router_push_navigation_stack(AppRoute::UserProfile(
+                        focused_comment.by_username.clone(),
+                    ));
+                    true
+                } else {
+                    false
+                }
+            } else {
+                false
+            },
+        )
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        if ctx.get_state().get_item_page_comments_tree_view() {
+            self.render_tree(f, inside, ctx)
+        } else {
+            self.common.render(f, inside, ctx, || None)
+        }
+    }
+}
+
+impl ItemTopLevelComments {
+    /// Build the displayed entries of the comments tree, without the comments killed by the killfile unless revealed.
+    fn build_tree_entries(
+        &self,
+        ctx: &AppContext,
+        parent_item_kids: &[HnItemIdScalar],
+    ) -> Vec<CommentsTreeEntry> {
+        let Some(comments) = &self.common.cached_comments else {
+            return vec![];
+        };
+        let killfile = ctx.get_config().get_killfile();
+        let hidden_comments_ids: HashSet<_> = if ctx.get_state().get_reveal_hidden_items() {
+            HashSet::new()
+        } else {
+            comments
+                .iter()
+                .filter(|(_, comment)| killfile.kills_comment(comment))
+                .map(|(id, _)| *id)
+                .collect()
+        };
+        self.tree_state
+            .build_entries(parent_item_kids, comments, &hidden_comments_ids)
+    }
+
+    /// Handle the inputs of the tree view, returning whether one was caught.
+    fn handle_tree_inputs(
+        &mut self,
+        ctx: &mut AppContext,
+        parent_item_kids: &[HnItemIdScalar],
+    ) -> bool {
+        let entries = self.build_tree_entries(ctx, parent_item_kids);
+        let inputs = ctx.get_inputs();
+        let focus_moved = if inputs.is_active(&ApplicationAction::NavigateUp) {
+            self.tree_state.previous(&entries);
             true
         } else if inputs.is_active(&ApplicationAction::NavigateDown) {
-            let new_focused_id = self
-                .common
-                .widget_state
-                .next_main_comment(&parent_item_kids)
-                .map(|(_, id)| id);
-            ctx.get_state_mut()
-                .replace_latest_in_currently_viewed_item_comments_chain(new_focused_id);
+            self.tree_state.next(&entries);
             true
+        } else if let Some(jump) = ListJump::from_inputs(inputs) {
+            self.tree_state.jump(&entries, jump);
+            true
+        } else if inputs.is_active(&ApplicationAction::CommentsTreeGoToParent) {
+            self.tree_state.go_to_parent(&entries)
+        } else if inputs.is_active(&ApplicationAction::CommentsTreeNextSibling) {
+            self.tree_state.next_sibling(&entries)
+        } else if inputs.is_active(&ApplicationAction::CommentsTreeNextTopLevel) {
+            self.tree_state.next_top_level(&entries)
         } else if inputs.is_active(&ApplicationAction::ItemExpandFocusedComment) {
-            if let Some(focused_comment) = self.common.get_focused_comment(ctx.get_state()).await {
-                if focused_comment
-                    .kids
-                    .as_ref()
-                    .is_none_or(|kids| kids.is_empty())
-                {
-                    // a comment with no sub-comments cannot be focused
-                    return Ok(false);
+            return self.tree_state.toggle_collapsed(&entries);
+        } else if inputs.is_active(&ApplicationAction::ToggleRevealHidden) {
+            self.common
+                .toggle_reveal_hidden_comments(ctx, parent_item_kids);
+            let entries = self.build_tree_entries(ctx, parent_item_kids);
+            self.tree_state.reconciliate_focused_comment(&entries);
+            self.sync_tree_focused_top_level_comment(ctx, &entries, parent_item_kids);
+            return true;
+        } else if inputs.is_active(&ApplicationAction::ItemNextNewComment) {
+            self.jump_to_new_comment_in_tree(ctx, &entries, parent_item_kids, true)
+        } else if inputs.is_active(&ApplicationAction::ItemPreviousNewComment) {
+            self.jump_to_new_comment_in_tree(ctx, &entries, parent_item_kids, false)
+        } else if inputs.is_active(&ApplicationAction::FocusedCommentToggleBookmark) {
+            return match self.get_tree_focused_comment(&entries) {
+                Some((comment, comments_chain)) => {
+                    ItemCommentsCommon::toggle_comment_bookmark(ctx, &comment, comments_chain)
                 }
-                ctx.router_push_navigation_stack(AppRoute::ItemNestedComments(
-                    focused_comment.clone(),
-                ));
-                true
-            } else {
-                false
-            }
+                None => false,
+            };
         } else if inputs.is_active(&ApplicationAction::FocusedCommentViewUserProfile) {
-            if let Some(focused_comment) = self.common.get_focused_comment(ctx.get_state()).await {
-                ctx.router_push_navigation_stack(AppRoute::UserProfile(
-                    focused_comment.by_username.clone(),
-                ));
-                true
-            } else {
-                false
+            return match self.get_tree_focused_comment(&entries) {
+                Some((comment, _)) => {
+                    ctx.router_push_navigation_stack(AppRoute::UserProfile(comment.by_username));
+                    true
+                }
+                None => false,
+            };
+        } else {
+            return false;
+        };
+
+        if focus_moved {
+            // the focused comment may not be displayed yet, e.g. once its parents are expanded
+            let entries = self.build_tree_entries(ctx, parent_item_kids);
+            self.sync_tree_focused_top_level_comment(ctx, &entries, parent_item_kids);
+        }
+        focus_moved
+    }
+
+    /// Focused comment of the tree view, along with its comments chain.
+    fn get_tree_focused_comment(
+        &self,
+        entries: &[CommentsTreeEntry],
+    ) -> Option<(DisplayableHackerNewsItem, Vec<HnItemIdScalar>)> {
+        let entry = self.tree_state.get_focused_entry(entries)?;
+        let comment = self.common.cached_comments.as_ref()?.get(&entry.id)?;
+        Some((comment.clone(), entry.get_comments_chain()))
+    }
+
+    /// Keep the top-level comment of the tree view focus in sync, for the history and the classic view.
+    fn sync_tree_focused_top_level_comment(
+        &mut self,
+        ctx: &mut AppContext,
+        entries: &[CommentsTreeEntry],
+        parent_item_kids: &[HnItemIdScalar],
+    ) {
+        let Some(top_level_id) = self
+            .tree_state
+            .get_focused_entry(entries)
+            .map(CommentsTreeEntry::get_top_level_id)
+        else {
+            return;
+        };
+        self.common
+            .widget_state
+            .restore_focused_comment_id(top_level_id, parent_item_kids);
+        ctx.get_state_mut()
+            .replace_latest_in_currently_viewed_item_comments_chain(Some(top_level_id));
+    }
+
+    /// Focus the next (or previous) new comment of the tree view, expanding its parents.
+    fn jump_to_new_comment_in_tree(
+        &mut self,
+        ctx: &mut AppContext,
+        entries: &[CommentsTreeEntry],
+        parent_item_kids: &[HnItemIdScalar],
+        forward: bool,
+    ) -> bool {
+        let comments_chain = match (
+            &self.common.cached_comments,
+            ctx.get_state().get_previous_visit_latest_seen_comment_id(),
+        ) {
+            (Some(comments), Some(latest_seen_comment_id)) => {
+                let current_chain = self
+                    .tree_state
+                    .get_focused_entry(entries)
+                    .map(CommentsTreeEntry::get_comments_chain)
+                    .unwrap_or_default();
+                find_new_comment_chain(
+                    parent_item_kids,
+                    comments,
+                    &current_chain,
+                    latest_seen_comment_id,
+                    forward,
+                )
             }
+            _ => None,
+        };
+
+        if let Some(comments_chain) = comments_chain {
+            self.tree_state.reveal_comments_chain(&comments_chain);
+            true
         } else {
+            ItemCommentsCommon::flash_no_new_comments(ctx);
             false
-        })
+        }
     }
 
-    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
-        self.common.render(f, inside, ctx, || None)
+    fn render_tree(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        if self.common.render_status_message(f, inside, ctx).is_none() {
+            return Ok(());
+        }
+
+        let parent_item_kids = Self::get_parent_item_kids(ctx.get_state())?;
+        let entries = self.build_tree_entries(ctx, &parent_item_kids);
+        if entries.is_empty() {
+            render_text_message(f, inside, "All the comments are hidden.", ctx.get_theme());
+            return Ok(());
+        }
+        self.tree_state.reconciliate_focused_comment(&entries);
+
+        let Some(comments) = &self.common.cached_comments else {
+            return Ok(());
+        };
+        let widget = CommentsTreeWidget::with_entries(
+            ctx.get_theme(),
+            &mut self.tree_state,
+            &entries,
+            comments,
+            ctx.get_state().get_previous_visit_latest_seen_comment_id(),
+        );
+        f.render_widget(widget, inside);
+
+        Ok(())
+    }
+
+    /// Open the nested comments screens leading to the comment restored from history, if still available.
+    async fn jump_to_restored_comments_chain(
+        &self,
+        ctx: &mut AppContext<'_>,
+        comments_chain: Vec<HnItemIdScalar>,
+    ) {
+        let parent_comments = ctx
+            .get_state()
+            .use_currently_viewed_item_comments(|comments| {
+                let comments = comments?;
+                comments_chain[..comments_chain.len() - 1]
+                    .iter()
+                    .map(|id| comments.get(id).cloned())
+                    .collect::<Option<Vec<_>>>()
+            })
+            .await;
+        if let Some(parent_comments) = parent_comments {
+            ctx.router_jump_to_comment(parent_comments, comments_chain);
+        } else {
+            warn!("ItemTopLevelComments: cannot restore the comments chain {comments_chain:?}");
+        }
     }
-}
 
-impl ItemTopLevelComments {
     fn get_parent_item_kids(state: &AppState) -> Result<Vec<HnItemIdScalar>> {
         let parent_item = state.get_currently_viewed_item().ok_or_else(|| {
             HnCliError::UiError(
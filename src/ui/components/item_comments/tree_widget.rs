@@ -0,0 +1,528 @@
+use std::collections::HashSet;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use crate::{
+    api::types::HnItemIdScalar,
+    ui::{
+        components::widgets::custom_list::ListJump,
+        displayable_item::DisplayableHackerNewsItemComments, theme::UiThemePalette,
+        utils::html_to_plain_text,
+    },
+};
+
+use super::corpus_widget::NEW_BADGE;
+
+/// Depth guide drawn for each level of a comment, *i.e.* each of its parents.
+const DEPTH_GUIDE: &str = "│ ";
+
+/// Minimum width of the text of the deepest comments.
+const MIN_TEXT_WIDTH: usize = 20;
+
+/// A displayed comment of the thread tree, in the display order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommentsTreeEntry {
+    pub id: HnItemIdScalar,
+    /// IDs of the parent comments, starting at the top-level one.
+    pub parents_ids: Vec<HnItemIdScalar>,
+    /// Number of (cached and not hidden) comments under this one, collapsed or not.
+    pub descendants_count: usize,
+    pub is_collapsed: bool,
+}
+
+impl CommentsTreeEntry {
+    /// Depth of the comment in the thread, 0 being a top-level comment.
+    pub fn depth(&self) -> usize {
+        self.parents_ids.len()
+    }
+
+    /// ID of the top-level comment this comment belongs to.
+    pub fn get_top_level_id(&self) -> HnItemIdScalar {
+        self.parents_ids.first().copied().unwrap_or(self.id)
+    }
+
+    /// IDs of the successive comments leading to this comment, starting at the top-level one.
+    pub fn get_comments_chain(&self) -> Vec<HnItemIdScalar> {
+        [self.parents_ids.as_slice(), &[self.id]].concat()
+    }
+}
+
+/// Persistent state of `CommentsTreeWidget`.
+///
+/// The displayed entries are not stored but built from the comments when needed,
+/// the comments being updated while the thread is viewed.
+#[derive(Debug, Default)]
+pub struct CommentsTreeState {
+    focused_comment_id: Option<HnItemIdScalar>,
+    /// IDs of the comments whose sub-comments are collapsed.
+    collapsed_comments_ids: HashSet<HnItemIdScalar>,
+    /// Index of the first displayed entry.
+    offset: usize,
+    /// Number of entries fully displayed on the latest rendering.
+    page_size: usize,
+}
+
+impl CommentsTreeState {
+    /// Flatten the (cached) comments tree under the given kids, in display order,
+    /// skipping the collapsed sub-comments and the hidden comments along with their sub-comments.
+    pub fn build_entries(
+        &self,
+        thread_kids: &[HnItemIdScalar],
+        comments: &DisplayableHackerNewsItemComments,
+        hidden_comments_ids: &HashSet<HnItemIdScalar>,
+    ) -> Vec<CommentsTreeEntry> {
+        let mut entries = vec![];
+        self.collect_entries(
+            thread_kids,
+            comments,
+            hidden_comments_ids,
+            &mut vec![],
+            Some(&mut entries),
+        );
+        entries
+    }
+
+    /// Depth-first collection of the entries under the given kids, only counted if no entries are given.
+    ///
+    /// Returns the number of comments under the given kids, themselves included.
+    fn collect_entries(
+        &self,
+        kids: &[HnItemIdScalar],
+        comments: &DisplayableHackerNewsItemComments,
+        hidden_comments_ids: &HashSet<HnItemIdScalar>,
+        parents_ids: &mut Vec<HnItemIdScalar>,
+        mut entries: Option<&mut Vec<CommentsTreeEntry>>,
+    ) -> usize {
+        let mut count = 0;
+        for kid_id in kids {
+            let Some(kid) = comments.get(kid_id) else {
+                continue;
+            };
+            if hidden_comments_ids.contains(kid_id) {
+                continue;
+            }
+            let is_collapsed = self.collapsed_comments_ids.contains(kid_id);
+            let entry_index = entries.as_deref_mut().map(|entries| {
+                entries.push(CommentsTreeEntry {
+                    id: *kid_id,
+                    parents_ids: parents_ids.clone(),
+                    descendants_count: 0,
+                    is_collapsed,
+                });
+                entries.len() - 1
+            });
+
+            parents_ids.push(*kid_id);
+            let descendants_count = self.collect_entries(
+                kid.kids.as_deref().unwrap_or_default(),
+                comments,
+                hidden_comments_ids,
+                parents_ids,
+                if is_collapsed {
+                    None
+                } else {
+                    entries.as_deref_mut()
+                },
+            );
+            parents_ids.pop();
+
+            if let (Some(entries), Some(index)) = (entries.as_deref_mut(), entry_index) {
+                entries[index].descendants_count = descendants_count;
+            }
+            count += 1 + descendants_count;
+        }
+        count
+    }
+
+    /// Get the focused entry, if displayed.
+    pub fn get_focused_entry<'a>(
+        &self,
+        entries: &'a [CommentsTreeEntry],
+    ) -> Option<&'a CommentsTreeEntry> {
+        self.get_focused_index(entries).map(|index| &entries[index])
+    }
+
+    fn get_focused_index(&self, entries: &[CommentsTreeEntry]) -> Option<usize> {
+        let focused_comment_id = self.focused_comment_id?;
+        entries
+            .iter()
+            .position(|entry| entry.id == focused_comment_id)
+    }
+
+    /// Focus the given comment, or the first displayed one if it is not displayed.
+    pub fn focus(&mut self, comment_id: Option<HnItemIdScalar>, entries: &[CommentsTreeEntry]) {
+        self.focused_comment_id = comment_id;
+        self.reconciliate_focused_comment(entries);
+    }
+
+    /// Keep the focus on a displayed comment, the first one by default.
+    pub fn reconciliate_focused_comment(&mut self, entries: &[CommentsTreeEntry]) {
+        if self.get_focused_index(entries).is_none() {
+            self.focused_comment_id = entries.first().map(|entry| entry.id);
+        }
+    }
+
+    /// Focus the previous comment, wrapping around to the last one.
+    pub fn previous(&mut self, entries: &[CommentsTreeEntry]) {
+        let count = entries.len();
+        if let Some(index) = self.get_focused_index(entries) {
+            self.focused_comment_id = Some(entries[(index + count - 1) % count].id);
+        } else {
+            self.reconciliate_focused_comment(entries);
+        }
+    }
+
+    /// Focus the next comment, wrapping around to the first one.
+    pub fn next(&mut self, entries: &[CommentsTreeEntry]) {
+        let count = entries.len();
+        if let Some(index) = self.get_focused_index(entries) {
+            self.focused_comment_id = Some(entries[(index + 1) % count].id);
+        } else {
+            self.reconciliate_focused_comment(entries);
+        }
+    }
+
+    /// Move the focus page-wise, or to the first or last comment.
+    pub fn jump(&mut self, entries: &[CommentsTreeEntry], jump: ListJump) {
+        if entries.is_empty() {
+            return;
+        }
+        let index = self.get_focused_index(entries).unwrap_or(0);
+        let target_index = jump.apply(index, entries.len(), self.page_size.max(1));
+        self.focused_comment_id = Some(entries[target_index].id);
+    }
+
+    /// Focus the parent of the focused comment, returning false if it is a top-level comment.
+    pub fn go_to_parent(&mut self, entries: &[CommentsTreeEntry]) -> bool {
+        let Some(parent_id) = self
+            .get_focused_entry(entries)
+            .and_then(|entry| entry.parents_ids.last().copied())
+        else {
+            return false;
+        };
+        self.focused_comment_id = Some(parent_id);
+        true
+    }
+
+    /// Focus the next comment of the same parent, returning false if there is none.
+    pub fn next_sibling(&mut self, entries: &[CommentsTreeEntry]) -> bool {
+        let Some(index) = self.get_focused_index(entries) else {
+            return false;
+        };
+        let depth = entries[index].depth();
+        // the sub-comments are skipped, up to the end of the parent's ones
+        let sibling = entries[index + 1..]
+            .iter()
+            .take_while(|entry| entry.depth() >= depth)
+            .find(|entry| entry.depth() == depth);
+        if let Some(sibling) = sibling {
+            self.focused_comment_id = Some(sibling.id);
+        }
+        sibling.is_some()
+    }
+
+    /// Focus the next top-level comment, returning false if there is none.
+    pub fn next_top_level(&mut self, entries: &[CommentsTreeEntry]) -> bool {
+        let Some(index) = self.get_focused_index(entries) else {
+            return false;
+        };
+        let next_top_level = entries[index + 1..].iter().find(|entry| entry.depth() == 0);
+        if let Some(entry) = next_top_level {
+            self.focused_comment_id = Some(entry.id);
+        }
+        next_top_level.is_some()
+    }
+
+    /// Collapse, or expand back, the sub-comments of the focused comment, returning false if it has none.
+    pub fn toggle_collapsed(&mut self, entries: &[CommentsTreeEntry]) -> bool {
+        let Some(entry) = self.get_focused_entry(entries) else {
+            return false;
+        };
+        if entry.descendants_count == 0 {
+            return false;
+        }
+        if !self.collapsed_comments_ids.remove(&entry.id) {
+            self.collapsed_comments_ids.insert(entry.id);
+        }
+        true
+    }
+
+    /// Expand the parents of the last comment of the given chain, then focus it.
+    pub fn reveal_comments_chain(&mut self, comments_chain: &[HnItemIdScalar]) {
+        if let Some((comment_id, parents_ids)) = comments_chain.split_last() {
+            for parent_id in parents_ids {
+                self.collapsed_comments_ids.remove(parent_id);
+            }
+            self.focused_comment_id = Some(*comment_id);
+        }
+    }
+}
+
+/// Custom `tui-rs` widget in charge of displaying the whole comments tree of a HackerNews item,
+/// the sub-comments being indented along depth guides.
+#[derive(Debug)]
+pub struct CommentsTreeWidget<'a> {
+    theme: &'a UiThemePalette,
+    /// Persistent state, the scrolling being updated while rendering.
+    state: &'a mut CommentsTreeState,
+    entries: &'a [CommentsTreeEntry],
+    /// Comments of the top-level parent item.
+    comments: &'a DisplayableHackerNewsItemComments,
+    /// ID of the latest comment seen on the previous visit of the thread, if any.
+    latest_seen_comment_id: Option<HnItemIdScalar>,
+}
+
+impl<'a> CommentsTreeWidget<'a> {
+    pub fn with_entries(
+        theme: &'a UiThemePalette,
+        state: &'a mut CommentsTreeState,
+        entries: &'a [CommentsTreeEntry],
+        comments: &'a DisplayableHackerNewsItemComments,
+        latest_seen_comment_id: Option<HnItemIdScalar>,
+    ) -> Self {
+        Self {
+            theme,
+            state,
+            entries,
+            comments,
+            latest_seen_comment_id,
+        }
+    }
+
+    /// Depth guides of the given number of levels.
+    fn build_depth_guides(&self, levels: usize) -> Vec<Span<'static>> {
+        (0..levels)
+            .map(|level| {
+                Span::styled(
+                    DEPTH_GUIDE,
+                    Style::default().fg(self.theme.get_comment_depth_color(level)),
+                )
+            })
+            .collect()
+    }
+
+    /// Lines of the given entry: header, text and a blank separator.
+    fn build_entry_lines(
+        &self,
+        entry: &CommentsTreeEntry,
+        is_focused: bool,
+        width: u16,
+    ) -> Vec<Line<'static>> {
+        let Some(comment) = self.comments.get(&entry.id) else {
+            return vec![];
+        };
+        let depth = entry.depth();
+        let mut lines = vec![];
+
+        // Header
+        let mut header = self.build_depth_guides(depth);
+        header.push(Span::styled(
+            match (entry.descendants_count, entry.is_collapsed) {
+                (0, _) => "• ",
+                (_, false) => "▾ ",
+                (_, true) => "▸ ",
+            },
+            Style::default().fg(self.theme.get_comment_depth_color(depth)),
+        ));
+        let username_style = Style::default().fg(self.theme.get_comment_depth_color(depth));
+        header.push(Span::styled(
+            comment.by_username.clone(),
+            if is_focused {
+                username_style.add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            } else {
+                username_style
+            },
+        ));
+        header.push(Span::styled(
+            format!(" {}", comment.posted_since),
+            Style::default().fg(self.theme.get_block_color()),
+        ));
+        if self
+            .latest_seen_comment_id
+            .is_some_and(|latest_seen_id| entry.id > latest_seen_id)
+        {
+            header.push(Span::raw(" "));
+            header.push(Span::styled(
+                NEW_BADGE,
+                Style::default()
+                    .fg(self.theme.get_highlight_color())
+                    .add_modifier(Modifier::REVERSED | Modifier::BOLD),
+            ));
+        }
+        if entry.is_collapsed && entry.descendants_count > 0 {
+            header.push(Span::styled(
+                format!(
+                    " [{} sub-comment{} collapsed]",
+                    entry.descendants_count,
+                    if entry.descendants_count > 1 { "s" } else { "" }
+                ),
+                Style::default().fg(self.theme.get_muted_color()),
+            ));
+        }
+        lines.push(Line::from(header));
+
+        // Text, along the depth guides of the comment itself
+        let text_width = (width as usize)
+            .saturating_sub(DEPTH_GUIDE.chars().count() * (depth + 1))
+            .max(MIN_TEXT_WIDTH);
+        let text = html_to_plain_text(comment.text.as_deref().unwrap_or_default(), text_width)
+            .unwrap_or_default();
+        for text_line in text.lines() {
+            let mut line = self.build_depth_guides(depth + 1);
+            line.push(Span::styled(
+                text_line.to_string(),
+                Style::default().fg(self.theme.get_text_color()),
+            ));
+            lines.push(Line::from(line));
+        }
+        lines.push(Line::from(self.build_depth_guides(depth)));
+
+        lines
+    }
+}
+
+impl<'a> Widget for CommentsTreeWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(focused_index) = self.state.get_focused_index(self.entries) else {
+            return;
+        };
+        let height = area.height as usize;
+        let build_lines = |index: usize| {
+            self.build_entry_lines(&self.entries[index], index == focused_index, area.width)
+        };
+
+        // Scrolling, for the focused comment to be displayed (from its top, if too long)
+        let mut offset = self.state.offset.min(focused_index);
+        let mut heights: Vec<usize> = (offset..=focused_index)
+            .map(|index| build_lines(index).len())
+            .collect();
+        while offset < focused_index && heights.iter().sum::<usize>() > height {
+            heights.remove(0);
+            offset += 1;
+        }
+
+        // Comments rendering
+        let mut y = area.top();
+        let mut page_size = 0;
+        for index in offset..self.entries.len() {
+            let lines = build_lines(index);
+            let is_fully_displayed = (y - area.top()) as usize + lines.len() <= height;
+            for line in lines {
+                if y >= area.bottom() {
+                    break;
+                }
+                buf.set_line(area.left(), y, &line, area.width);
+                y += 1;
+            }
+            if is_fully_displayed {
+                page_size += 1;
+            }
+            if y >= area.bottom() {
+                break;
+            }
+        }
+
+        self.state.offset = offset;
+        self.state.page_size = page_size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        api::types::HnItemIdScalar,
+        ui::displayable_item::{DisplayableHackerNewsItem, DisplayableHackerNewsItemComments},
+    };
+
+    use super::CommentsTreeState;
+
+    #[test]
+    fn test_comments_tree_navigation() {
+        // 10
+        // |- 11
+        // |  |- 12
+        // |- 13
+        // 20
+        // |- 21
+        // 30
+        let mut comments = DisplayableHackerNewsItemComments::new();
+        for (id, kids) in [
+            (10, &[11, 13][..]),
+            (11, &[12]),
+            (12, &[]),
+            (13, &[]),
+            (20, &[21]),
+            (21, &[]),
+            (30, &[]),
+        ] {
+            comments.insert(
+                id,
+                DisplayableHackerNewsItem {
+                    kids: Some(kids.to_vec()),
+                    ..DisplayableHackerNewsItem::test_item(id)
+                },
+            );
+        }
+        let thread_kids = [10, 20, 30];
+        let mut state = CommentsTreeState::default();
+        let entries = state.build_entries(&thread_kids, &comments, &HashSet::new());
+        let ids = |entries: &[super::CommentsTreeEntry]| -> Vec<HnItemIdScalar> {
+            entries.iter().map(|entry| entry.id).collect()
+        };
+        assert_eq!(ids(&entries), vec![10, 11, 12, 13, 20, 21, 30]);
+        assert_eq!(entries[0].descendants_count, 3);
+        assert_eq!(entries[2].get_comments_chain(), vec![10, 11, 12]);
+
+        // navigation
+        state.focus(None, &entries);
+        assert_eq!(state.focused_comment_id, Some(10));
+        assert!(state.next_sibling(&entries));
+        assert_eq!(state.focused_comment_id, Some(20));
+        state.focus(Some(11), &entries);
+        assert!(state.next_sibling(&entries));
+        assert_eq!(state.focused_comment_id, Some(13));
+        assert!(!state.next_sibling(&entries));
+        assert!(state.go_to_parent(&entries));
+        assert_eq!(state.focused_comment_id, Some(10));
+        assert!(!state.go_to_parent(&entries));
+        state.focus(Some(12), &entries);
+        assert!(state.next_top_level(&entries));
+        assert_eq!(state.focused_comment_id, Some(20));
+        state.focus(Some(30), &entries);
+        assert!(!state.next_top_level(&entries));
+        state.next(&entries);
+        assert_eq!(state.focused_comment_id, Some(10));
+        state.previous(&entries);
+        assert_eq!(state.focused_comment_id, Some(30));
+
+        // collapsing
+        state.focus(Some(10), &entries);
+        assert!(state.toggle_collapsed(&entries));
+        let collapsed_entries = state.build_entries(&thread_kids, &comments, &HashSet::new());
+        assert_eq!(ids(&collapsed_entries), vec![10, 20, 21, 30]);
+        assert!(collapsed_entries[0].is_collapsed);
+        assert_eq!(collapsed_entries[0].descendants_count, 3);
+        state.next(&collapsed_entries);
+        assert_eq!(state.focused_comment_id, Some(20));
+        state.focus(Some(30), &collapsed_entries);
+        assert!(!state.toggle_collapsed(&collapsed_entries));
+        state.reveal_comments_chain(&[10, 11, 12]);
+        let entries = state.build_entries(&thread_kids, &comments, &HashSet::new());
+        assert_eq!(entries.len(), 7);
+        assert_eq!(state.focused_comment_id, Some(12));
+
+        // hidden comments, along with their sub-comments
+        let hidden_entries = state.build_entries(&thread_kids, &comments, &HashSet::from([11]));
+        assert_eq!(ids(&hidden_entries), vec![10, 13, 20, 21, 30]);
+        state.reconciliate_focused_comment(&hidden_entries);
+        assert_eq!(state.focused_comment_id, Some(10));
+    }
+}
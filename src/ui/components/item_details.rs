@@ -1,17 +1,23 @@
 use async_trait::async_trait;
 use ratatui::{
-    layout::{HorizontalAlignment, Rect},
+    layout::{Constraint, Direction, HorizontalAlignment, Layout, Rect},
     style::Style,
     text::Line,
-    widgets::{Block, BorderType, Borders, Paragraph},
+    widgets::{Block, BorderType, Borders, Paragraph, Sparkline},
 };
 
 use crate::{
     api::HnClient,
-    app::AppContext,
+    app::{
+        AppContext,
+        history::{BookmarkedItem, ItemSnapshot},
+    },
     errors::Result,
     ui::{
         common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::common::{toggle_bookmark, toggle_item_watched},
+        handlers::ApplicationAction,
+        theme::UiThemePalette,
         utils::html_to_plain_text,
     },
 };
@@ -29,6 +35,9 @@ use crate::{
 /// |   <#COMMENTS COUNT>  / POSTED <X> AGO   |
 /// |_________________________________________|
 /// ```
+///
+/// The score, comments count and front page rank of the watched stories
+/// are charted on the right side, once snapshotted at least twice.
 #[derive(Debug, Default)]
 pub struct ItemDetails {
     text: Option<String>,
@@ -37,6 +46,11 @@ pub struct ItemDetails {
 
 pub const ITEM_DETAILS_ID: UiComponentId = "item_details";
 
+/// Minimum inner width of the panel to chart the snapshots of the story.
+const SNAPSHOTS_CHARTS_MIN_PANEL_WIDTH: u16 = 90;
+
+const SNAPSHOTS_CHARTS_LABEL_WIDTH: u16 = 26;
+
 #[async_trait]
 impl UiComponent for ItemDetails {
     fn id(&self) -> UiComponentId {
@@ -71,8 +85,24 @@ impl UiComponent for ItemDetails {
         Ok(())
     }
 
-    async fn handle_inputs(&mut self, _ctx: &mut AppContext) -> Result<bool> {
-        Ok(false)
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        let Some(item) = ctx.get_state().get_currently_viewed_item() else {
+            return Ok(false);
+        };
+        let inputs = ctx.get_inputs();
+        Ok(if inputs.is_active(&ApplicationAction::ToggleBookmark) {
+            let bookmarked_item = BookmarkedItem::Story {
+                id: item.id,
+                title: item.title.clone().unwrap_or_default(),
+            };
+            toggle_bookmark(ctx, bookmarked_item);
+            true
+        } else if inputs.is_active(&ApplicationAction::ToggleWatch) {
+            toggle_item_watched(ctx, item.id);
+            true
+        } else {
+            false
+        })
     }
 
     fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
@@ -94,8 +124,15 @@ impl UiComponent for ItemDetails {
             Line::from(item_title),
             Line::from(viewed_item.url_hostname.clone().unwrap_or_default()),
             Line::from(format!(
-                "{} points by {} {}",
-                viewed_item.score, viewed_item.by_username, viewed_item.posted_since
+                "{} points by {} {}{}",
+                viewed_item.score,
+                viewed_item.by_username,
+                viewed_item.posted_since,
+                if ctx.get_history().is_bookmarked(viewed_item.id) {
+                    " ★"
+                } else {
+                    ""
+                }
             )),
             Line::from(if let Some(count) = self.comments_count {
                 format!("{count} comments")
@@ -106,15 +143,103 @@ impl UiComponent for ItemDetails {
         let text_corpus = Self::build_item_text_line(self, inside, ctx)?;
 
         let paragraph = Paragraph::new([text_base, text_corpus].concat())
-            .block(block)
             .alignment(HorizontalAlignment::Center);
-        f.render_widget(paragraph, inside);
+        let paragraph_area = block.inner(inside);
+        f.render_widget(block, inside);
+
+        let snapshots = ctx.get_history().get_item_snapshots(viewed_item.id);
+        if snapshots.len() >= 2 && paragraph_area.width >= SNAPSHOTS_CHARTS_MIN_PANEL_WIDTH {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+                .split(paragraph_area);
+            f.render_widget(paragraph, chunks[0]);
+            Self::render_snapshots_charts(f, chunks[1], snapshots, theme);
+        } else {
+            f.render_widget(paragraph, paragraph_area);
+        }
 
         Ok(())
     }
 }
 
 impl ItemDetails {
+    /// Render the score, comments count and front page rank of the story over time, one sparkline each.
+    fn render_snapshots_charts(
+        f: &mut RenderFrame,
+        inside: Rect,
+        snapshots: &[ItemSnapshot],
+        theme: &UiThemePalette,
+    ) {
+        let latest = snapshots
+            .last()
+            .expect("ItemDetails: snapshots are not empty");
+        // the higher in the sparkline, the better the rank; unlisted being the lowest
+        let lowest_rank = snapshots
+            .iter()
+            .filter_map(ItemSnapshot::get_rank)
+            .max()
+            .unwrap_or(0);
+        let best_rank = snapshots.iter().filter_map(ItemSnapshot::get_rank).min();
+        let charts = [
+            (
+                format!("score {}", latest.get_score()),
+                snapshots
+                    .iter()
+                    .map(|snapshot| u64::from(snapshot.get_score()))
+                    .collect::<Vec<_>>(),
+            ),
+            (
+                format!("comments {}", latest.get_comments_count()),
+                snapshots
+                    .iter()
+                    .map(|snapshot| u64::from(snapshot.get_comments_count()))
+                    .collect(),
+            ),
+            (
+                match (latest.get_rank(), best_rank) {
+                    (Some(rank), Some(best_rank)) => format!("rank #{rank} (best #{best_rank})"),
+                    (None, Some(best_rank)) => format!("unlisted (best #{best_rank})"),
+                    _ => "never on front page".into(),
+                },
+                snapshots
+                    .iter()
+                    .map(|snapshot| {
+                        snapshot
+                            .get_rank()
+                            .map_or(0, |rank| (lowest_rank + 1 - rank) as u64)
+                    })
+                    .collect(),
+            ),
+        ];
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1); 3])
+            .split(inside);
+        for ((label, data), row) in charts.iter().zip(rows.iter()) {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Length(SNAPSHOTS_CHARTS_LABEL_WIDTH),
+                    Constraint::Min(0),
+                ])
+                .split(*row);
+            f.render_widget(
+                Paragraph::new(label.as_str()).style(Style::default().fg(theme.get_text_color())),
+                columns[0],
+            );
+            // the latest snapshots only, if more than the available width
+            let overflow = data.len().saturating_sub(columns[1].width as usize);
+            f.render_widget(
+                Sparkline::default()
+                    .data(&data[overflow..])
+                    .style(Style::default().fg(theme.get_accent_color())),
+                columns[1],
+            );
+        }
+    }
+
     fn build_item_text_line(&self, inside: Rect, ctx: &AppContext) -> Result<Vec<Line<'_>>> {
         Ok(if let Some(ref corpus) = self.text {
             if ctx
@@ -1,8 +1,9 @@
 use async_trait::async_trait;
+use unicode_width::UnicodeWidthStr;
 
 use ratatui::{
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    layout::{Margin, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Tabs},
 };
@@ -18,15 +19,35 @@ use crate::{
     },
 };
 
-const TABS_TITLES: [&str; 6] = ["Home", "Ask HN", "Show HN", "Jobs", "Settings", "Help"];
+/// Titles of the navigation tabs, which can be hidden from the config file.
+pub const TABS_TITLES: [&str; 10] = [
+    "Home",
+    "Ask HN",
+    "Show HN",
+    "Jobs",
+    "Past",
+    "Hiring",
+    "History",
+    "Bookmarks",
+    "Settings",
+    "Help",
+];
+
+/// Tabs which cannot be hidden, to always reach the settings and the help.
+pub const ALWAYS_VISIBLE_TABS_TITLES: [&str; 3] = ["Home", "Settings", "Help"];
 
 /// The Navigation bar provides a convenient way to switch between screens
 /// by either pressing the hotkey associated with the title, or by
 /// directly switching tabs with the help of the arrow keys.
+///
+/// The tabs hidden in the configuration are skipped.
 #[derive(Debug)]
 pub struct Navigation {
+    /// Titles of the visible tabs.
     titles: Vec<&'static str>,
     selected_index: usize,
+    /// Area of the tabs at the latest rendering, for mouse selection.
+    tabs_area: Rect,
 }
 
 impl Default for Navigation {
@@ -34,11 +55,28 @@ impl Default for Navigation {
         Self {
             titles: TABS_TITLES.to_vec(),
             selected_index: 0,
+            tabs_area: Rect::default(),
         }
     }
 }
 
 impl Navigation {
+    /// Keep the visible tabs in sync with the configuration, which can be reloaded
+    /// or switched to another profile at any time.
+    fn refresh_titles(&mut self, hidden_tabs: &[String], current_route: &AppRoute) {
+        let titles: Vec<_> = TABS_TITLES
+            .into_iter()
+            .filter(|title| !hidden_tabs.iter().any(|hidden| hidden == title))
+            .collect();
+        if titles == self.titles {
+            return;
+        }
+        self.titles = titles;
+        self.selected_index = get_route_tab_title(current_route)
+            .and_then(|current_title| self.titles.iter().position(|title| *title == current_title))
+            .unwrap_or(0);
+    }
+
     fn next(&mut self) {
         self.selected_index = (self.selected_index + 1) % self.titles.len();
     }
@@ -51,14 +89,32 @@ impl Navigation {
         }
     }
 
+    /// Get the index of the tab rendered at the given column, if any.
+    fn get_tab_index_at(&self, column: u16) -> Option<usize> {
+        let mut x = self.tabs_area.x;
+        for (index, title) in self.titles.iter().enumerate() {
+            // padded with a space on each side, then followed by the divider
+            let width = title.width() as u16 + 2;
+            if (x..x + width).contains(&column) {
+                return Some(index);
+            }
+            x += width + 1;
+        }
+        None
+    }
+
     fn navigate_to_current_selection(&self, ctx: &mut AppContext) {
-        let route = match self.selected_index {
-            0 => AppRoute::Home(HnStoriesSections::Home),
-            1 => AppRoute::Home(HnStoriesSections::Ask),
-            2 => AppRoute::Home(HnStoriesSections::Show),
-            3 => AppRoute::Home(HnStoriesSections::Jobs),
-            4 => AppRoute::Settings,
-            5 => AppRoute::Help,
+        let route = match self.titles[self.selected_index] {
+            "Home" => AppRoute::Home(HnStoriesSections::Home),
+            "Ask HN" => AppRoute::Home(HnStoriesSections::Ask),
+            "Show HN" => AppRoute::Home(HnStoriesSections::Show),
+            "Jobs" => AppRoute::Home(HnStoriesSections::Jobs),
+            "Past" => AppRoute::PastFrontPage,
+            "Hiring" => AppRoute::WhoIsHiring,
+            "History" => AppRoute::BrowsingHistory,
+            "Bookmarks" => AppRoute::Bookmarks,
+            "Settings" => AppRoute::Settings,
+            "Help" => AppRoute::Help,
             _ => unreachable!(),
         };
         ctx.get_state_mut().set_main_stories_loading(true);
@@ -66,6 +122,25 @@ impl Navigation {
     }
 }
 
+/// Get the title of the tab of the given route, if any.
+fn get_route_tab_title(route: &AppRoute) -> Option<&'static str> {
+    Some(match route {
+        AppRoute::Home(section) => match section {
+            HnStoriesSections::Home => "Home",
+            HnStoriesSections::Ask => "Ask HN",
+            HnStoriesSections::Show => "Show HN",
+            HnStoriesSections::Jobs => "Jobs",
+        },
+        AppRoute::PastFrontPage => "Past",
+        AppRoute::WhoIsHiring => "Hiring",
+        AppRoute::BrowsingHistory => "History",
+        AppRoute::Bookmarks => "Bookmarks",
+        AppRoute::Settings => "Settings",
+        AppRoute::Help => "Help",
+        _ => return None,
+    })
+}
+
 pub const NAVIGATION_ID: UiComponentId = "navigation";
 
 #[async_trait]
@@ -87,6 +162,10 @@ impl UiComponent for Navigation {
     }
 
     async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        self.refresh_titles(
+            ctx.get_config().get_hidden_tabs(),
+            ctx.get_router().get_current_route(),
+        );
         let inputs = ctx.get_inputs();
         Ok(if inputs.is_active(&ApplicationAction::NavigateLeft) {
             self.previous();
@@ -94,6 +173,14 @@ impl UiComponent for Navigation {
         } else if inputs.is_active(&ApplicationAction::NavigateRight) {
             self.next();
             true
+        } else if let Some(mouse_input) = inputs
+            .get_mouse_input()
+            .filter(|input| input.is_click() && input.is_inside(self.tabs_area))
+            && let Some(index) = self.get_tab_index_at(mouse_input.column)
+        {
+            self.selected_index = index;
+            self.navigate_to_current_selection(ctx);
+            true
         } else if inputs.is_active(&ApplicationAction::SelectItem) {
             if ctx.get_state().get_latest_interacted_with_component() == Some(&NAVIGATION_ID) {
                 self.navigate_to_current_selection(ctx);
@@ -108,32 +195,24 @@ impl UiComponent for Navigation {
 
     fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
         let theme = ctx.get_theme();
+        let current_route = ctx.get_router().get_current_route();
+        self.refresh_titles(ctx.get_config().get_hidden_tabs(), current_route);
 
-        let current_tab_index = match ctx.get_router().get_current_route() {
-            AppRoute::Home(section) => match section {
-                HnStoriesSections::Home => 0,
-                HnStoriesSections::Ask => 1,
-                HnStoriesSections::Show => 2,
-                HnStoriesSections::Jobs => 3,
-            },
-            AppRoute::Settings => 4,
-            AppRoute::Help => 5,
-            _ => usize::MAX,
-        };
-        let selected_title = TABS_TITLES[current_tab_index];
+        // NB: no tab is selected on other screens, like the search results one
+        let selected_title = get_route_tab_title(current_route);
         let tabs_titles: Vec<Line> = self
             .titles
             .iter()
             .map(|title| {
                 Line::from(vec![Span::styled(
                     *title,
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(if *title == selected_title {
+                    Style::default().fg(theme.get_text_color()).add_modifier(
+                        if Some(*title) == selected_title {
                             Modifier::UNDERLINED | Modifier::BOLD
                         } else {
                             Modifier::BOLD
-                        }),
+                        },
+                    ),
                 )])
             })
             .collect();
@@ -147,11 +226,12 @@ impl UiComponent for Navigation {
                     .border_type(BorderType::Rounded)
                     .title("Menu"),
             )
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme.get_text_color()))
             .highlight_style(Style::default().fg(theme.get_accent_color()))
             .divider(Span::raw("|"));
 
         f.render_widget(tabs, inside);
+        self.tabs_area = inside.inner(Margin::new(1, 1));
 
         Ok(())
     }
@@ -159,6 +239,10 @@ impl UiComponent for Navigation {
 
 #[cfg(test)]
 mod tests {
+    use ratatui::layout::Rect;
+
+    use crate::{api::client::HnStoriesSections, ui::router::AppRoute};
+
     use super::Navigation;
 
     #[test]
@@ -174,11 +258,63 @@ mod tests {
         navigation.next();
         navigation.next();
         navigation.next();
+        navigation.next();
+        navigation.next();
+        navigation.next();
+        navigation.next();
         assert_eq!(navigation.selected_index, 0);
 
         navigation.previous();
-        assert_eq!(navigation.selected_index, 5);
+        assert_eq!(navigation.selected_index, 9);
+        navigation.previous();
+        assert_eq!(navigation.selected_index, 8);
+    }
+
+    #[test]
+    fn test_navigation_hidden_tabs() {
+        let mut navigation = Navigation::default();
+        let hidden_tabs = vec!["Jobs".to_string(), "Hiring".to_string()];
+        navigation.refresh_titles(&hidden_tabs, &AppRoute::PastFrontPage);
+        assert_eq!(
+            navigation.titles,
+            [
+                "Home",
+                "Ask HN",
+                "Show HN",
+                "Past",
+                "History",
+                "Bookmarks",
+                "Settings",
+                "Help"
+            ]
+        );
+        assert_eq!(navigation.selected_index, 3);
+
         navigation.previous();
-        assert_eq!(navigation.selected_index, 4);
+        navigation.refresh_titles(&hidden_tabs, &AppRoute::PastFrontPage);
+        assert_eq!(navigation.selected_index, 2);
+
+        navigation.refresh_titles(&[], &AppRoute::Home(HnStoriesSections::Jobs));
+        assert_eq!(navigation.titles.len(), 10);
+        assert_eq!(navigation.selected_index, 3);
+    }
+
+    #[test]
+    fn test_tab_index_at() {
+        let navigation = Navigation {
+            tabs_area: Rect::new(1, 1, 120, 1),
+            ..Default::default()
+        };
+
+        // " Home |"
+        assert_eq!(navigation.get_tab_index_at(0), None);
+        assert_eq!(navigation.get_tab_index_at(1), Some(0));
+        assert_eq!(navigation.get_tab_index_at(6), Some(0));
+        assert_eq!(navigation.get_tab_index_at(7), None);
+        // " Ask HN |"
+        assert_eq!(navigation.get_tab_index_at(8), Some(1));
+        assert_eq!(navigation.get_tab_index_at(15), Some(1));
+        assert_eq!(navigation.get_tab_index_at(16), None);
+        assert_eq!(navigation.get_tab_index_at(119), None);
     }
 }
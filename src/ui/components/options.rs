@@ -3,7 +3,7 @@ use std::convert::TryInto;
 use async_trait::async_trait;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Tabs},
 };
@@ -69,7 +69,6 @@ impl Default for Options {
     fn default() -> Self {
         Self {
             keyboard_debouncer: Debouncer::new(10), // approx. 1000ms
-            // TODO: load from configuration
             selected_sorting_index: 1,
         }
     }
@@ -83,6 +82,17 @@ impl UiComponent for Options {
         OPTIONS_ID
     }
 
+    fn before_mount(&mut self, ctx: &mut AppContext) {
+        // initially the default sorting from the configuration
+        let sorting = *ctx.get_state().get_main_stories_sorting();
+        if let Some(index) = SORTING_OPTIONS_LIST
+            .iter()
+            .position(|sorting_option| sorting_option.clone().try_into().ok() == Some(sorting))
+        {
+            self.selected_sorting_index = index;
+        }
+    }
+
     async fn should_update(
         &mut self,
         elapsed_ticks: UiTickScalar,
@@ -136,7 +146,7 @@ impl UiComponent for Options {
                     Style::default().fg(if i == self.selected_sorting_index {
                         theme.get_main_color()
                     } else {
-                        Color::White
+                        theme.get_text_color()
                     }),
                 ))
             })
@@ -145,7 +155,7 @@ impl UiComponent for Options {
         // TODO: this probably needs a custom widget
         let tabs = Tabs::new(tabs_titles)
             .select(self.selected_sorting_index)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme.get_text_color()))
             .highlight_style(Style::default().fg(theme.get_accent_color()))
             .divider(Span::raw("|"))
             .block(block);
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use ratatui::layout::Rect;
+use ratatui::{layout::Rect, style::Style};
 
 use crate::{
     api::HnClient,
@@ -46,7 +46,8 @@ impl UiComponent for Search {
 
     fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
         let input_widget =
-            TextInputWidget::with_state(ctx.get_state().get_current_algolia_query_state());
+            TextInputWidget::with_state(ctx.get_state().get_current_algolia_query_state())
+                .cursor_style(Style::default().bg(ctx.get_theme().get_highlight_color()));
         f.render_widget(input_widget, inside);
 
         Ok(())
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, BorderType, Borders},
 };
 
@@ -47,23 +47,26 @@ impl UiComponent for AlgoliaInput {
     }
 
     fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
         let input_widget_border_style = if matches!(
             ctx.get_state().get_currently_used_algolia_part(),
             SearchScreenPart::Input
         ) {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme.get_accent_color())
         } else {
             Style::default()
         };
 
         let input_widget =
-            TextInputWidget::with_state(ctx.get_state().get_current_algolia_query_state()).block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Thick)
-                    .border_style(input_widget_border_style)
-                    .title("Search input"),
-            );
+            TextInputWidget::with_state(ctx.get_state().get_current_algolia_query_state())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Thick)
+                        .border_style(input_widget_border_style)
+                        .title("Search input"),
+                )
+                .cursor_style(Style::default().bg(theme.get_highlight_color()));
         f.render_widget(input_widget, inside);
 
         Ok(())
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use ratatui::{
     layout::{HorizontalAlignment, Rect},
-    style::{Color, Style},
+    style::Style,
     text::Line,
     widgets::{Block, BorderType, Borders, Paragraph},
 };
@@ -15,7 +15,7 @@ use crate::{
         common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
         components::{
             common::render_text_message,
-            widgets::custom_list::{CustomList, CustomListState},
+            widgets::custom_list::{CustomList, CustomListState, ListJump},
         },
         displayable_algolia_item::{
             DisplayableAlgoliaComment, DisplayableAlgoliaItem, DisplayableAlgoliaStory,
@@ -192,6 +192,9 @@ impl UiComponent for AlgoliaList {
         } else if inputs.is_active(&ApplicationAction::NavigateDown) {
             self.list_state.next();
             true
+        } else if let Some(jump) = ListJump::from_inputs(inputs) {
+            self.list_state.jump(jump);
+            true
         } else if inputs.is_active(&ApplicationAction::OpenHackerNewsLink) {
             let items = self.list_state.get_items();
             let selected_item = &items[selected.unwrap()];
@@ -215,9 +218,9 @@ impl UiComponent for AlgoliaList {
         let theme = ctx.get_theme();
 
         let block_border_style = Style::default().fg(match self.status {
-            AlgoliaListStatus::Unselected => Color::White,
-            AlgoliaListStatus::Selected => Color::Yellow,
-            AlgoliaListStatus::Focused => Color::Green,
+            AlgoliaListStatus::Unselected => theme.get_text_color(),
+            AlgoliaListStatus::Selected => theme.get_accent_color(),
+            AlgoliaListStatus::Focused => theme.get_success_color(),
         });
 
         // Empty input case
@@ -270,9 +273,9 @@ impl UiComponent for AlgoliaList {
             |rect, buf, item, is_selected| {
                 // selected color
                 let style = Style::default().fg(if is_selected {
-                    Color::Yellow
+                    theme.get_accent_color()
                 } else {
-                    Color::White
+                    theme.get_text_color()
                 });
                 // title
                 let title = item.title();
@@ -294,9 +297,10 @@ impl UiComponent for AlgoliaList {
             |_| 1,
         )
         .block(block)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.get_text_color()))
         .highlight_symbol(">> ")
-        .highlight_style(Style::default().fg(Color::Yellow));
+        .compact(ctx.get_config().get_compact_lists())
+        .highlight_style(Style::default().fg(theme.get_accent_color()));
 
         f.render_widget(custom_list_results, inside);
 
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Tabs},
 };
@@ -126,7 +126,7 @@ impl UiComponent for AlgoliaTags {
             .map(|(i, title)| {
                 Line::from(vec![Span::styled(
                     *title,
-                    Style::default().fg(Color::White).add_modifier(
+                    Style::default().fg(theme.get_text_color()).add_modifier(
                         if Some(i) == self.selected_index {
                             Modifier::UNDERLINED | Modifier::BOLD
                         } else {
@@ -141,7 +141,7 @@ impl UiComponent for AlgoliaTags {
             ctx.get_state().get_currently_used_algolia_part(),
             SearchScreenPart::Filters
         ) {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme.get_accent_color())
         } else {
             Style::default()
         };
@@ -155,8 +155,8 @@ impl UiComponent for AlgoliaTags {
                     .border_style(tabs_border_style)
                     .title("Search Filters"),
             )
-            .style(Style::default().fg(Color::White))
-            .highlight_style(Style::default().fg(Color::LightYellow))
+            .style(Style::default().fg(theme.get_text_color()))
+            .highlight_style(Style::default().fg(theme.get_main_color()))
             .divider(Span::raw("/"));
 
         f.render_widget(tabs, inside);
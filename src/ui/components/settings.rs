@@ -1,59 +1,211 @@
+use std::{
+    ops::{Add, RangeInclusive, Sub},
+    time::Duration,
+};
+
 use async_trait::async_trait;
 use ratatui::{
     layout::{Constraint, Direction, HorizontalAlignment, Layout, Rect},
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 
 use crate::{
-    api::HnClient,
+    api::{HnClient, client::HnStoriesSorting},
     app::AppContext,
-    errors::Result,
+    config::{
+        BROWSING_HISTORY_MAX_ENTRIES_RANGE, BROWSING_HISTORY_MAX_ENTRIES_STEP,
+        BROWSING_HISTORY_RETENTION_DAYS_RANGE, BROWSING_HISTORY_RETENTION_DAYS_STEP,
+        ConfigValueSource, DEFAULT_PROFILE, HTTP_TIMEOUT_SECS_RANGE, HTTP_TIMEOUT_SECS_STEP,
+        MAX_DISPLAYED_STORIES_RANGE, MAX_DISPLAYED_STORIES_STEP, REFRESH_INTERVAL_SECS_RANGE,
+        REFRESH_INTERVAL_SECS_STEP, list_profiles,
+    },
+    errors::{HnCliError, Result},
     ui::{
         common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::widgets::text_input::{TextInputState, TextInputWidget},
+        flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
         handlers::ApplicationAction,
-        theme::UiTheme,
+        keybindings::KeyBindingsPreset,
+        theme::{CUSTOM_THEME_COLORS, UiTheme, UiThemePalette, UiThemePaletteConfiguration},
         utils::breakpoints::Breakpoints,
     },
 };
 
+const STORIES_SORTINGS: [HnStoriesSorting; 3] = [
+    HnStoriesSorting::New,
+    HnStoriesSorting::Top,
+    HnStoriesSorting::Best,
+];
+
+const KEY_BINDINGS_PRESETS: [KeyBindingsPreset; 3] = [
+    KeyBindingsPreset::Default,
+    KeyBindingsPreset::Vim,
+    KeyBindingsPreset::Emacs,
+];
+
+/// Built-in themes a custom palette can be based upon.
+const CUSTOM_THEME_BASES: [UiTheme; 4] = [
+    UiTheme::Blue,
+    UiTheme::Magenta,
+    UiTheme::Yellow,
+    UiTheme::Light,
+];
+
 #[derive(Debug)]
 enum SettingsOption {
+    /// Configuration profile, with its own config and history files.
+    Profile(String),
     /// Theme to use across the application.
     UiTheme(UiTheme),
     /// On the main items list (home screen), should we display the items' metadata (score, number of comments, etc.)?
     DisplayItemsListItemMeta(bool),
     /// On the item details page, should we display the comments panel by default or not?
     DisplayCommentsPanelByDefault(bool),
+    /// On the home screen of wide terminals, should we preview the selected story next to the list?
+    DisplayStoryPreviewPanel(bool),
+    /// Should the lists display a single line per entry, to fit more of them?
+    CompactLists(bool),
     /// Show the global contextual help?
     ShowContextualHelp(bool),
+    /// Capture the mouse events, to click and scroll through the application?
+    EnableMouse(bool),
     /// Enable the global 'q' shortcut (in sub-screens) to immediately quit the application?
     EnableGlobalSubScreenQuitShortcut(bool),
+    /// Sorting of the home screen stories on launch.
+    DefaultStoriesSorting(HnStoriesSorting),
+    /// Maximum number of stories displayed in the stories lists.
+    MaxDisplayedStories(usize),
+    /// Interval between two automatic refreshes of the stories lists.
+    StoriesRefreshInterval(Duration),
+    /// Interval between two automatic refreshes of the comments.
+    CommentsRefreshInterval(Duration),
+    /// Timeout of the Hacker News API requests.
+    HttpTimeout(Duration),
+    /// Maximum number of entries kept in the browsing history.
+    BrowsingHistoryMaxEntries(usize),
+    /// Number of days the browsing history entries are kept for.
+    BrowsingHistoryRetentionDays(u64),
+    /// Built-in key bindings, on top of which the user-defined ones apply.
+    KeyBindingsPreset(KeyBindingsPreset),
+    /// Built-in theme the custom palette is based upon.
+    CustomThemeBase(UiTheme),
+    /// Color of the custom palette, by name, inherited from its base if unset.
+    CustomThemeColor(&'static str, Option<String>),
 }
 
 impl SettingsOption {
-    pub fn get_representation(&self) -> Span<'_> {
+    /// Get the key of the (top-level) configuration option, if any.
+    pub fn get_config_key(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Profile(_) => return None,
+            Self::UiTheme(_) => "theme",
+            Self::DisplayItemsListItemMeta(_) => "display_main_items_list_item_meta",
+            Self::DisplayCommentsPanelByDefault(_) => "display_comments_panel_by_default",
+            Self::DisplayStoryPreviewPanel(_) => "display_story_preview_panel",
+            Self::CompactLists(_) => "compact_lists",
+            Self::ShowContextualHelp(_) => "show_contextual_help",
+            Self::EnableMouse(_) => "enable_mouse",
+            Self::EnableGlobalSubScreenQuitShortcut(_) => "enable_global_sub_screen_quit_shortcut",
+            Self::DefaultStoriesSorting(_) => "default_stories_sorting",
+            Self::MaxDisplayedStories(_) => "max_displayed_stories",
+            Self::StoriesRefreshInterval(_) => "stories_refresh_interval_secs",
+            Self::CommentsRefreshInterval(_) => "comments_refresh_interval_secs",
+            Self::HttpTimeout(_) => "http_timeout_secs",
+            Self::BrowsingHistoryMaxEntries(_) => "browsing_history_max_entries",
+            Self::BrowsingHistoryRetentionDays(_) => "browsing_history_retention_days",
+            Self::KeyBindingsPreset(_) => "keybindings",
+            Self::CustomThemeBase(_) | Self::CustomThemeColor(_, _) => "custom_theme",
+        })
+    }
+
+    /// Get the current value to edit as text, if the option can be typed in.
+    pub fn get_input_value(&self) -> Option<String> {
+        match self {
+            Self::Profile(value) => Some(value.clone()),
+            Self::MaxDisplayedStories(value) | Self::BrowsingHistoryMaxEntries(value) => {
+                Some(value.to_string())
+            }
+            Self::BrowsingHistoryRetentionDays(value) => Some(value.to_string()),
+            Self::StoriesRefreshInterval(value)
+            | Self::CommentsRefreshInterval(value)
+            | Self::HttpTimeout(value) => Some(value.as_secs().to_string()),
+            Self::CustomThemeColor(_, value) => Some(value.clone().unwrap_or_default()),
+            _ => None,
+        }
+    }
+
+    pub fn get_representation(&self, theme: &UiThemePalette) -> Span<'static> {
         match self {
-            Self::UiTheme(theme) => Self::get_theme_representation(theme),
-            Self::DisplayItemsListItemMeta(value) => Self::get_boolean_representation(*value),
-            Self::DisplayCommentsPanelByDefault(value) => Self::get_boolean_representation(*value),
-            Self::ShowContextualHelp(value) => Self::get_boolean_representation(*value),
+            Self::Profile(value) => {
+                Span::styled(value.clone(), Style::default().fg(theme.get_main_color()))
+            }
+            Self::UiTheme(value) | Self::CustomThemeBase(value) => {
+                Self::get_theme_representation(value, theme)
+            }
+            Self::DisplayItemsListItemMeta(value) => {
+                Self::get_boolean_representation(*value, theme)
+            }
+            Self::DisplayCommentsPanelByDefault(value) => {
+                Self::get_boolean_representation(*value, theme)
+            }
+            Self::DisplayStoryPreviewPanel(value) => {
+                Self::get_boolean_representation(*value, theme)
+            }
+            Self::CompactLists(value) => Self::get_boolean_representation(*value, theme),
+            Self::ShowContextualHelp(value) => Self::get_boolean_representation(*value, theme),
+            Self::EnableMouse(value) => Self::get_boolean_representation(*value, theme),
             Self::EnableGlobalSubScreenQuitShortcut(value) => {
-                Self::get_boolean_representation(*value)
+                Self::get_boolean_representation(*value, theme)
+            }
+            Self::DefaultStoriesSorting(value) => {
+                Span::styled(value.label(), Style::default().fg(theme.get_main_color()))
+            }
+            Self::MaxDisplayedStories(value) | Self::BrowsingHistoryMaxEntries(value) => {
+                Span::styled(
+                    value.to_string(),
+                    Style::default().fg(theme.get_main_color()),
+                )
+            }
+            Self::BrowsingHistoryRetentionDays(value) => Span::styled(
+                format!("{value} day{}", if *value > 1 { "s" } else { "" }),
+                Style::default().fg(theme.get_main_color()),
+            ),
+            Self::StoriesRefreshInterval(value)
+            | Self::CommentsRefreshInterval(value)
+            | Self::HttpTimeout(value) => Self::get_duration_representation(value, theme),
+            Self::KeyBindingsPreset(value) => {
+                Span::styled(value.label(), Style::default().fg(theme.get_main_color()))
+            }
+            Self::CustomThemeColor(_, Some(value)) => {
+                Span::styled(value.clone(), Style::default().fg(theme.get_main_color()))
+            }
+            Self::CustomThemeColor(_, None) => {
+                Span::styled("Inherited", Style::default().fg(theme.get_muted_color()))
             }
         }
     }
 
-    fn get_theme_representation(value: &UiTheme) -> Span<'_> {
-        Span::styled(value.label(), Style::default().fg(value.get_main_color()))
+    fn get_duration_representation(value: &Duration, theme: &UiThemePalette) -> Span<'static> {
+        let (minutes, seconds) = (value.as_secs() / 60, value.as_secs() % 60);
+        let representation = match (minutes, seconds) {
+            (0, seconds) => format!("{seconds} s"),
+            (minutes, 0) => format!("{minutes} min"),
+            (minutes, seconds) => format!("{minutes} min {seconds} s"),
+        };
+        Span::styled(representation, Style::default().fg(theme.get_main_color()))
     }
 
-    fn get_boolean_representation(value: bool) -> Span<'static> {
+    fn get_theme_representation(value: &UiTheme, theme: &UiThemePalette) -> Span<'static> {
+        Span::styled(value.label(), Style::default().fg(theme.get_main_color()))
+    }
+
+    fn get_boolean_representation(value: bool, theme: &UiThemePalette) -> Span<'static> {
         if value {
-            Span::styled("Enabled", Style::default().fg(Color::Green))
+            Span::styled("Enabled", Style::default().fg(theme.get_success_color()))
         } else {
-            Span::styled("Disabled", Style::default().fg(Color::Red))
+            Span::styled("Disabled", Style::default().fg(theme.get_error_color()))
         }
     }
 }
@@ -62,38 +214,53 @@ impl SettingsOption {
 struct SettingsControl {
     label: String,
     option: SettingsOption,
+    /// Where the current value comes from, for the configuration options.
+    source: Option<ConfigValueSource>,
 }
 
 impl SettingsControl {
-    pub fn render(&self, f: &mut RenderFrame, inside: Rect, is_active: bool, theme: &UiTheme) {
+    pub fn render(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        is_active: bool,
+        value_input: Option<&TextInputState>,
+        theme: &UiThemePalette,
+    ) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints(vec![Constraint::Percentage(75), Constraint::Percentage(25)])
+            .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
             .split(inside);
 
-        let label_text = vec![
-            Line::from(""),
-            Line::from(""),
-            Line::from(""),
-            Line::from(Span::styled(
-                &self.label,
-                Style::default().fg(if is_active {
-                    theme.get_accent_color()
-                } else {
-                    Color::White
-                }),
-            )),
-        ];
-        let label_paragraph = Paragraph::new(label_text).alignment(HorizontalAlignment::Left);
+        let label_paragraph = Paragraph::new(Line::from(Span::styled(
+            &self.label,
+            Style::default().fg(if is_active {
+                theme.get_accent_color()
+            } else {
+                theme.get_text_color()
+            }),
+        )))
+        .alignment(HorizontalAlignment::Left);
         f.render_widget(label_paragraph, chunks[0]);
 
-        let value_text = vec![
-            Line::from(""),
-            Line::from(""),
-            Line::from(""),
-            Line::from(self.option.get_representation()),
-        ];
-        let value_paragraph = Paragraph::new(value_text).alignment(HorizontalAlignment::Right);
+        // editing case
+        if let Some(value_input) = value_input {
+            let input_widget = TextInputWidget::with_state(value_input)
+                .style(Style::default().fg(theme.get_accent_color()))
+                .cursor_style(Style::default().bg(theme.get_highlight_color()));
+            f.render_widget(input_widget, chunks[1]);
+            return;
+        }
+
+        let mut value_spans = vec![self.option.get_representation(theme)];
+        if let Some(source) = self.source {
+            value_spans.push(Span::styled(
+                format!(" ({})", source.label()),
+                Style::default().fg(theme.get_muted_color()),
+            ));
+        }
+        let value_paragraph =
+            Paragraph::new(Line::from(value_spans)).alignment(HorizontalAlignment::Right);
         f.render_widget(value_paragraph, chunks[1]);
     }
 }
@@ -103,6 +270,8 @@ pub struct Settings {
     controls: Vec<SettingsControl>,
     selected_control_index: usize,
     breakpoints: Breakpoints,
+    /// Revision of the configuration the controls were refreshed from.
+    config_revision: u64,
 }
 
 impl Default for Settings {
@@ -110,6 +279,7 @@ impl Default for Settings {
         Self {
             controls: vec![],
             selected_control_index: 0,
+            config_revision: 0,
             breakpoints: Breakpoints::new("settings_component", &[0, 100]).breakpoint(40, &[7, 93]),
         }
     }
@@ -117,6 +287,9 @@ impl Default for Settings {
 
 pub const SETTINGS_ID: UiComponentId = "settings";
 
+/// Height of a settings row, including the spacing below it.
+const CONTROL_HEIGHT: u16 = 2;
+
 #[async_trait]
 impl UiComponent for Settings {
     fn before_mount(&mut self, ctx: &mut AppContext) {
@@ -130,29 +303,49 @@ impl UiComponent for Settings {
     async fn should_update(
         &mut self,
         _elapsed_ticks: UiTickScalar,
-        _ctx: &AppContext,
+        ctx: &AppContext,
     ) -> Result<bool> {
-        Ok(false)
+        // the configuration was reloaded from the file, or a typed-in value was submitted
+        Ok(ctx.get_config().get_revision() != self.config_revision
+            || ctx.get_state().get_settings_value_input_submitted())
     }
 
-    async fn update(&mut self, _client: &mut HnClient, _ctx: &mut AppContext) -> Result<()> {
+    async fn update(&mut self, _client: &mut HnClient, ctx: &mut AppContext) -> Result<()> {
+        if ctx.get_state().get_settings_value_input_submitted() {
+            self.apply_value_input(ctx);
+        }
+        self.refresh_controls(ctx);
         Ok(())
     }
 
     async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
         let inputs = ctx.get_inputs();
-        Ok(if inputs.is_active(&ApplicationAction::NavigateUp) {
+        let result = if inputs.is_active(&ApplicationAction::NavigateUp) {
             self.previous_control();
-            true
+            return Ok(true);
         } else if inputs.is_active(&ApplicationAction::NavigateDown) {
             self.next_control();
-            true
-        } else if inputs.is_active(&ApplicationAction::SettingsToggleControl) {
-            self.toggle_current_control(ctx);
-            true
+            return Ok(true);
+        } else if inputs.is_active(&ApplicationAction::SettingsToggleControl)
+            || inputs.is_active(&ApplicationAction::SettingsIncreaseValue)
+        {
+            self.step_current_control(ctx, true)
+        } else if inputs.is_active(&ApplicationAction::SettingsDecreaseValue) {
+            self.step_current_control(ctx, false)
+        } else if inputs.is_active(&ApplicationAction::SettingsEditValue) {
+            self.edit_current_control(ctx);
+            return Ok(true);
+        } else if inputs.is_active(&ApplicationAction::SettingsResetValue) {
+            self.reset_current_control(ctx)
         } else {
-            false
-        })
+            return Ok(false);
+        };
+
+        if let Err(why) = result {
+            Self::flash_warning(ctx, why);
+        }
+        self.refresh_controls(ctx);
+        Ok(true)
     }
 
     fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
@@ -170,21 +363,32 @@ impl UiComponent for Settings {
             f.render_widget(header_paragraph, chunks[0]);
         }
 
-        // controls block
+        // controls block, scrolled to keep the selected control visible
         assert!(!self.controls.is_empty());
-        let controls_width_percentage = 100 / self.controls.len() as u16;
-        let controls_constraints: Vec<Constraint> = (0..self.controls.len())
-            .map(|_| Constraint::Percentage(controls_width_percentage))
-            .collect();
-        let controls_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(controls_constraints)
-            .split(chunks[1]);
-        for (i, control) in self.controls.iter().enumerate() {
+        let controls_area = chunks[1];
+        let visible_controls_count = (controls_area.height / CONTROL_HEIGHT).max(1) as usize;
+        let scroll_offset =
+            (self.selected_control_index + 1).saturating_sub(visible_controls_count);
+        let value_input = ctx.get_state().get_settings_value_input();
+        for (i, control) in self
+            .controls
+            .iter()
+            .enumerate()
+            .skip(scroll_offset)
+            .take(visible_controls_count)
+        {
+            let control_area = Rect::new(
+                controls_area.x,
+                controls_area.y + (i - scroll_offset) as u16 * CONTROL_HEIGHT,
+                controls_area.width,
+                1.min(controls_area.height),
+            );
+            let is_active = i == self.selected_control_index;
             control.render(
                 f,
-                controls_chunks[i],
-                i == self.selected_control_index,
+                control_area,
+                is_active,
+                value_input.filter(|_| is_active),
                 ctx.get_theme(),
             );
         }
@@ -206,49 +410,285 @@ impl Settings {
         }
     }
 
-    fn toggle_current_control(&mut self, ctx: &mut AppContext) {
+    /// Go to the next (or previous) value of the current control: toggle, pick or step.
+    fn step_current_control(&mut self, ctx: &mut AppContext, forward: bool) -> Result<()> {
         let config = ctx.get_config_mut();
-        match self.selected_control_index {
-            0 => config.set_theme_to_next_value(),
-            1 => config.toggle_display_main_items_list_item_meta(),
-            2 => config.toggle_display_comments_panel_by_default(),
-            3 => config.toggle_show_contextual_help(),
-            4 => config.toggle_enable_global_sub_screen_quit_shortcut(),
-            _ => (),
+        match &self.controls[self.selected_control_index].option {
+            SettingsOption::Profile(value) => {
+                let profile = cycle(&list_profiles(), value.clone(), forward);
+                return ctx.switch_profile(&profile);
+            }
+            SettingsOption::UiTheme(value) => {
+                let has_custom_theme = config.get_custom_theme().is_some();
+                config.set_theme(if forward {
+                    value.next_value(has_custom_theme)
+                } else {
+                    value.previous_value(has_custom_theme)
+                });
+            }
+            SettingsOption::DisplayItemsListItemMeta(_) => {
+                config.toggle_display_main_items_list_item_meta()
+            }
+            SettingsOption::DisplayCommentsPanelByDefault(_) => {
+                config.toggle_display_comments_panel_by_default()
+            }
+            SettingsOption::DisplayStoryPreviewPanel(_) => {
+                config.toggle_display_story_preview_panel()
+            }
+            SettingsOption::CompactLists(_) => config.toggle_compact_lists(),
+            SettingsOption::ShowContextualHelp(_) => config.toggle_show_contextual_help(),
+            SettingsOption::EnableMouse(_) => config.toggle_enable_mouse(),
+            SettingsOption::EnableGlobalSubScreenQuitShortcut(_) => {
+                config.toggle_enable_global_sub_screen_quit_shortcut()
+            }
+            SettingsOption::DefaultStoriesSorting(value) => {
+                config.set_default_stories_sorting(cycle(&STORIES_SORTINGS, *value, forward))
+            }
+            SettingsOption::MaxDisplayedStories(value) => {
+                config.set_max_displayed_stories(step_value(
+                    *value,
+                    MAX_DISPLAYED_STORIES_STEP,
+                    MAX_DISPLAYED_STORIES_RANGE,
+                    forward,
+                ))?
+            }
+            SettingsOption::StoriesRefreshInterval(value) => config
+                .set_stories_refresh_interval_secs(step_value(
+                    value.as_secs(),
+                    REFRESH_INTERVAL_SECS_STEP,
+                    REFRESH_INTERVAL_SECS_RANGE,
+                    forward,
+                ))?,
+            SettingsOption::CommentsRefreshInterval(value) => config
+                .set_comments_refresh_interval_secs(step_value(
+                    value.as_secs(),
+                    REFRESH_INTERVAL_SECS_STEP,
+                    REFRESH_INTERVAL_SECS_RANGE,
+                    forward,
+                ))?,
+            SettingsOption::HttpTimeout(value) => config.set_http_timeout_secs(step_value(
+                value.as_secs(),
+                HTTP_TIMEOUT_SECS_STEP,
+                HTTP_TIMEOUT_SECS_RANGE,
+                forward,
+            ))?,
+            SettingsOption::BrowsingHistoryMaxEntries(value) => config
+                .set_browsing_history_max_entries(step_value(
+                    *value,
+                    BROWSING_HISTORY_MAX_ENTRIES_STEP,
+                    BROWSING_HISTORY_MAX_ENTRIES_RANGE,
+                    forward,
+                ))?,
+            SettingsOption::BrowsingHistoryRetentionDays(value) => config
+                .set_browsing_history_retention_days(step_value(
+                    *value,
+                    BROWSING_HISTORY_RETENTION_DAYS_STEP,
+                    BROWSING_HISTORY_RETENTION_DAYS_RANGE,
+                    forward,
+                ))?,
+            SettingsOption::KeyBindingsPreset(value) => {
+                config.set_key_bindings_preset(cycle(&KEY_BINDINGS_PRESETS, *value, forward))
+            }
+            SettingsOption::CustomThemeBase(value) => {
+                config.set_custom_theme_base(cycle(&CUSTOM_THEME_BASES, *value, forward))?
+            }
+            // free text only
+            SettingsOption::CustomThemeColor(_, _) => (),
+        }
+        Ok(())
+    }
+
+    /// Start typing in the value of the current control, if possible.
+    fn edit_current_control(&self, ctx: &mut AppContext) {
+        if let Some(value) = self.controls[self.selected_control_index]
+            .option
+            .get_input_value()
+        {
+            ctx.get_state_mut()
+                .set_settings_value_input(Some(TextInputState::from_string(&value)));
+        }
+    }
+
+    /// Apply the submitted typed-in value to the current control, keeping the input if invalid.
+    fn apply_value_input(&self, ctx: &mut AppContext) {
+        let Some(raw) = ctx
+            .get_state()
+            .get_settings_value_input()
+            .map(|input| input.get_value().trim().to_string())
+        else {
+            return;
+        };
+        let config = ctx.get_config_mut();
+        let result = match &self.controls[self.selected_control_index].option {
+            SettingsOption::Profile(_) => ctx.switch_profile(&raw),
+            SettingsOption::MaxDisplayedStories(_) => {
+                parse_number(&raw).and_then(|value| config.set_max_displayed_stories(value))
+            }
+            SettingsOption::StoriesRefreshInterval(_) => {
+                parse_number(&raw).and_then(|value| config.set_stories_refresh_interval_secs(value))
+            }
+            SettingsOption::CommentsRefreshInterval(_) => parse_number(&raw)
+                .and_then(|value| config.set_comments_refresh_interval_secs(value)),
+            SettingsOption::HttpTimeout(_) => {
+                parse_number(&raw).and_then(|value| config.set_http_timeout_secs(value))
+            }
+            SettingsOption::BrowsingHistoryMaxEntries(_) => {
+                parse_number(&raw).and_then(|value| config.set_browsing_history_max_entries(value))
+            }
+            SettingsOption::BrowsingHistoryRetentionDays(_) => parse_number(&raw)
+                .and_then(|value| config.set_browsing_history_retention_days(value)),
+            SettingsOption::CustomThemeColor(name, _) => {
+                // an empty value inherits the color from the base theme
+                config
+                    .set_custom_theme_color(name, Some(raw.as_str()).filter(|raw| !raw.is_empty()))
+            }
+            _ => Ok(()),
+        };
+        Self::handle_value_input_result(ctx, result);
+    }
+
+    /// Stop editing if the typed-in value was applied, and keep the input otherwise.
+    fn handle_value_input_result(ctx: &mut AppContext, result: Result<()>) {
+        match result {
+            Ok(()) => ctx.get_state_mut().set_settings_value_input(None),
+            Err(why) => {
+                ctx.get_state_mut()
+                    .set_settings_value_input_submitted(false);
+                Self::flash_warning(ctx, why);
+            }
         }
-        self.refresh_controls(ctx);
+    }
+
+    /// Reset the value of the current control to its default.
+    fn reset_current_control(&self, ctx: &mut AppContext) -> Result<()> {
+        let config = ctx.get_config_mut();
+        match &self.controls[self.selected_control_index].option {
+            SettingsOption::Profile(_) => ctx.switch_profile(DEFAULT_PROFILE)?,
+            // the user-defined key bindings are kept
+            SettingsOption::KeyBindingsPreset(_) => {
+                config.set_key_bindings_preset(KeyBindingsPreset::default())
+            }
+            SettingsOption::CustomThemeBase(_) => {
+                config.set_custom_theme_base(UiThemePaletteConfiguration::default().base)?
+            }
+            SettingsOption::CustomThemeColor(name, _) => {
+                config.set_custom_theme_color(name, None)?
+            }
+            option => {
+                if let Some(key) = option.get_config_key() {
+                    config.reset_option(key);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn flash_warning(ctx: &mut AppContext, why: HnCliError) {
+        ctx.get_state_mut().set_flash_message(FlashMessage::new(
+            why.to_string(),
+            FlashMessageType::Warning,
+            FLASH_MESSAGE_DEFAULT_DURATION_MS,
+        ));
     }
 
     fn refresh_controls(&mut self, ctx: &AppContext) {
         let config = ctx.get_config();
+        self.config_revision = config.get_revision();
         self.controls = vec![
-            SettingsControl {
-                label: "Application-wide theme".into(),
-                option: SettingsOption::UiTheme(*config.get_theme()),
-            },
-            SettingsControl {
-                label: "Display the stories' metadata on main screen:".into(),
-                option: SettingsOption::DisplayItemsListItemMeta(
-                    config.get_display_main_items_list_item_meta(),
-                ),
-            },
-            SettingsControl {
-                label: "Display the comments panel by default:".into(),
-                option: SettingsOption::DisplayCommentsPanelByDefault(
-                    config.get_display_comments_panel_by_default(),
-                ),
-            },
-            SettingsControl {
-                label: "Show the global contextual help:".into(),
-                option: SettingsOption::ShowContextualHelp(config.get_show_contextual_help()),
-            },
-            SettingsControl {
-                label: "Enable the global 'q' quit shortcut in sub-screens, besides CTRL+C:".into(),
-                option: SettingsOption::EnableGlobalSubScreenQuitShortcut(
-                    config.get_enable_global_sub_screen_quit_shortcut(),
-                ),
-            },
-        ];
+            SettingsOption::Profile(config.get_profile().into()),
+            SettingsOption::UiTheme(config.get_ui_theme()),
+            SettingsOption::DisplayItemsListItemMeta(
+                config.get_display_main_items_list_item_meta(),
+            ),
+            SettingsOption::DisplayCommentsPanelByDefault(
+                config.get_display_comments_panel_by_default(),
+            ),
+            SettingsOption::DisplayStoryPreviewPanel(config.get_display_story_preview_panel()),
+            SettingsOption::CompactLists(config.get_compact_lists()),
+            SettingsOption::ShowContextualHelp(config.get_show_contextual_help()),
+            SettingsOption::EnableMouse(config.get_enable_mouse()),
+            SettingsOption::EnableGlobalSubScreenQuitShortcut(
+                config.get_enable_global_sub_screen_quit_shortcut(),
+            ),
+            SettingsOption::DefaultStoriesSorting(config.get_default_stories_sorting()),
+            SettingsOption::MaxDisplayedStories(config.get_max_displayed_stories()),
+            SettingsOption::StoriesRefreshInterval(config.get_stories_refresh_interval()),
+            SettingsOption::CommentsRefreshInterval(config.get_comments_refresh_interval()),
+            SettingsOption::HttpTimeout(config.get_http_timeout()),
+            SettingsOption::BrowsingHistoryMaxEntries(config.get_browsing_history_max_entries()),
+            SettingsOption::BrowsingHistoryRetentionDays(
+                config.get_browsing_history_retention_days(),
+            ),
+            SettingsOption::KeyBindingsPreset(config.get_key_bindings_preset()),
+            SettingsOption::CustomThemeBase(
+                config
+                    .get_custom_theme()
+                    .map(|custom_theme| custom_theme.base)
+                    .unwrap_or_default(),
+            ),
+        ]
+        .into_iter()
+        .chain(CUSTOM_THEME_COLORS.iter().map(|name| {
+            SettingsOption::CustomThemeColor(
+                name,
+                config
+                    .get_custom_theme()
+                    .and_then(|custom_theme| custom_theme.get_color(name))
+                    .map(String::from),
+            )
+        }))
+        .map(|option| SettingsControl {
+            label: Self::get_label(&option),
+            source: option
+                .get_config_key()
+                .map(|key| config.get_value_source(key)),
+            option,
+        })
+        .collect();
+    }
+
+    fn get_label(option: &SettingsOption) -> String {
+        match option {
+            SettingsOption::Profile(_) => "Configuration profile (name to create one):".into(),
+            SettingsOption::UiTheme(_) => "Application-wide theme:".into(),
+            SettingsOption::DisplayItemsListItemMeta(_) => {
+                "Display the stories' metadata on main screen:".into()
+            }
+            SettingsOption::DisplayCommentsPanelByDefault(_) => {
+                "Display the comments panel by default:".into()
+            }
+            SettingsOption::DisplayStoryPreviewPanel(_) => {
+                "Preview the selected story on wide terminals:".into()
+            }
+            SettingsOption::CompactLists(_) => "Compact lists, one line per entry:".into(),
+            SettingsOption::ShowContextualHelp(_) => "Show the global contextual help:".into(),
+            SettingsOption::EnableMouse(_) => "Enable the mouse (click and scroll):".into(),
+            SettingsOption::EnableGlobalSubScreenQuitShortcut(_) => {
+                "Enable the global 'q' quit shortcut in sub-screens, besides CTRL+C:".into()
+            }
+            SettingsOption::DefaultStoriesSorting(_) => {
+                "Default sorting of the home stories:".into()
+            }
+            SettingsOption::MaxDisplayedStories(_) => "Maximum number of displayed stories:".into(),
+            SettingsOption::StoriesRefreshInterval(_) => {
+                "Stories refresh interval (in seconds):".into()
+            }
+            SettingsOption::CommentsRefreshInterval(_) => {
+                "Comments refresh interval (in seconds):".into()
+            }
+            SettingsOption::HttpTimeout(_) => "Hacker News requests timeout (in seconds):".into(),
+            SettingsOption::BrowsingHistoryMaxEntries(_) => {
+                "Maximum number of browsing history entries:".into()
+            }
+            SettingsOption::BrowsingHistoryRetentionDays(_) => {
+                "Browsing history retention (in days):".into()
+            }
+            SettingsOption::KeyBindingsPreset(_) => "Key bindings preset:".into(),
+            SettingsOption::CustomThemeBase(_) => "Custom theme: base theme:".into(),
+            SettingsOption::CustomThemeColor(name, _) => {
+                format!("Custom theme: {name} color (name or #rrggbb):")
+            }
+        }
     }
 
     fn get_common_block() -> Block<'static> {
@@ -257,3 +697,60 @@ impl Settings {
             .borders(Borders::ALL)
     }
 }
+
+/// Get the next (or previous) value among the given ones, cycling around.
+fn cycle<T: Clone + PartialEq>(values: &[T], current: T, forward: bool) -> T {
+    let index = values
+        .iter()
+        .position(|value| *value == current)
+        .unwrap_or(0);
+    if forward {
+        values[(index + 1) % values.len()].clone()
+    } else {
+        values[(index + values.len() - 1) % values.len()].clone()
+    }
+}
+
+/// Increase (or decrease) a numeric value by a step, clamped to its range.
+fn step_value<T>(value: T, step: T, range: RangeInclusive<T>, forward: bool) -> T
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    let (start, end) = range.into_inner();
+    if forward {
+        (value + step).min(end)
+    } else if value >= start + step {
+        value - step
+    } else {
+        start
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(raw: &str) -> Result<T> {
+    raw.parse()
+        .map_err(|_| HnCliError::InvalidOptionError(format!("expected a number (got \"{raw}\")")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::client::HnStoriesSorting;
+
+    use super::{STORIES_SORTINGS, cycle, step_value};
+
+    #[test]
+    fn test_settings_values_stepping() {
+        assert_eq!(
+            cycle(&STORIES_SORTINGS, HnStoriesSorting::Best, true),
+            HnStoriesSorting::New
+        );
+        assert_eq!(
+            cycle(&STORIES_SORTINGS, HnStoriesSorting::New, false),
+            HnStoriesSorting::Best
+        );
+
+        assert_eq!(step_value(50, 10, 10..=500, true), 60);
+        assert_eq!(step_value(495, 10, 10..=500, true), 500);
+        assert_eq!(step_value(15, 10, 10..=500, false), 10);
+        assert_eq!(step_value(30u64, 30, 30..=3600, false), 30);
+    }
+}
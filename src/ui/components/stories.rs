@@ -6,8 +6,8 @@ use async_trait::async_trait;
 
 use ratatui::{
     layout::{HorizontalAlignment, Rect},
-    style::{Color, Style},
-    text::Line,
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 use unicode_width::UnicodeWidthStr;
@@ -18,19 +18,23 @@ use crate::{
         client::{HnStoriesSections, HnStoriesSorting},
         types::HnItemIdScalar,
     },
-    app::AppContext,
+    app::{AppContext, history::BookmarkedItem},
     errors::Result,
     ui::{
-        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar, get_ticks_in_duration},
+        components::{
+            common::{toggle_bookmark, toggle_item_watched},
+            widgets::text_input::TextInputState,
+        },
         displayable_item::DisplayableHackerNewsItem,
         flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
-        handlers::ApplicationAction,
+        handlers::{ApplicationAction, MouseInputKind},
         router::AppRoute,
-        utils::{loader::Loader, open_browser_tab},
+        utils::{fuzzy_match, fuzzy_match_quality, loader::Loader, open_browser_tab},
     },
 };
 
-use super::widgets::custom_list::{CustomList, CustomListState};
+use super::widgets::custom_list::{CustomList, CustomListState, ListJump};
 
 #[derive(Debug)]
 pub struct StoriesPanel {
@@ -38,14 +42,20 @@ pub struct StoriesPanel {
     loading: bool,
     loader: Loader,
     sorting_type_for_last_update: Option<HnStoriesSorting>,
-    list_cutoff: usize,
+    /// Fetched stories, including the hidden ones.
+    stories: Vec<DisplayableHackerNewsItem>,
+    /// Currently displayed stories.
     list_state: CustomListState<HnItemIdScalar, DisplayableHackerNewsItem>,
+    /// Number of fetched stories hidden by the killfile or by the user.
+    hidden_stories_count: usize,
+    /// Route of the latest fetched stories, the filter being cleared when it changes.
+    route_for_last_update: Option<AppRoute>,
+    /// Filter applied to the displayed stories.
+    filter: String,
+    /// Story selected before filtering, selected back once the filter is cleared.
+    selection_before_filter: Option<HnItemIdScalar>,
 }
 
-// TODO: load from configuration
-const HOME_MAX_DISPLAYED_STORIES: usize = 50;
-const MEAN_TICKS_BETWEEN_UPDATES: UiTickScalar = 1800; // approx. every 3 minute
-
 impl Default for StoriesPanel {
     fn default() -> Self {
         Self {
@@ -53,14 +63,126 @@ impl Default for StoriesPanel {
             loading: true,
             loader: Loader::default(),
             sorting_type_for_last_update: None,
-            list_cutoff: HOME_MAX_DISPLAYED_STORIES,
+            stories: vec![],
             list_state: CustomListState::with_items(vec![]),
+            hidden_stories_count: 0,
+            route_for_last_update: None,
+            filter: String::new(),
+            selection_before_filter: None,
         }
     }
 }
 
 pub const STORIES_PANEL_ID: UiComponentId = "panel_stories";
 
+impl StoriesPanel {
+    /// Rebuild the displayed stories list, hiding the read ones if requested,
+    /// the killed ones unless revealed and the ones not matching the filter.
+    fn refresh_displayed_stories(&mut self, ctx: &mut AppContext) {
+        let state = ctx.get_state();
+        let filter = state.get_main_stories_filter();
+        let filter_changed = self.filter != filter;
+        self.filter = filter.to_string();
+        let (hide_read, reveal_hidden) = (
+            state.get_main_stories_hide_read(),
+            state.get_reveal_hidden_items(),
+        );
+        let history = ctx.get_history();
+        let killfile = ctx.get_config().get_killfile();
+        self.hidden_stories_count = 0;
+        let mut displayed_stories = self
+            .stories
+            .iter()
+            .filter(|story| !hide_read || !history.is_item_read(story.id))
+            .filter(|story| {
+                if history.is_item_hidden(story.id) || killfile.kills_story(story) {
+                    self.hidden_stories_count += 1;
+                    reveal_hidden
+                } else {
+                    true
+                }
+            })
+            .filter_map(|story| {
+                if self.filter.is_empty() {
+                    return Some((None, story.clone()));
+                }
+                Self::get_filter_match_quality(story, &self.filter)
+                    .map(|quality| (Some(quality), story.clone()))
+            })
+            .collect::<Vec<_>>();
+        // the stable sort keeps the original ranking among the matches of the same quality
+        displayed_stories.sort_by_key(|(quality, _)| *quality);
+        self.list_state.replace_items(
+            displayed_stories
+                .into_iter()
+                .map(|(_, story)| story)
+                .collect(),
+        );
+        if filter_changed {
+            // the best match is the first one, and the previous selection is restored once cleared
+            let selected_index = match self.selection_before_filter {
+                Some(story_id) if self.filter.is_empty() => self
+                    .list_state
+                    .get_items()
+                    .iter()
+                    .position(|story| story.id == story_id),
+                _ => None,
+            };
+            self.list_state.select(selected_index);
+            if self.filter.is_empty() {
+                self.selection_before_filter = None;
+            }
+        }
+        if self.list_state.selected().is_none() && !self.list_state.is_empty() {
+            self.list_state.select(Some(0));
+        }
+        self.publish_selected_story(ctx);
+    }
+
+    /// Share the selected story, for it to be previewed.
+    fn publish_selected_story(&self, ctx: &mut AppContext) {
+        let selected_story = self
+            .list_state
+            .selected()
+            .map(|index| self.list_state.get_items()[index].clone());
+        ctx.get_state_mut()
+            .set_main_stories_selected_story(selected_story);
+    }
+
+    /// Quality of the best fuzzy match of the given filter among the story title, domain and author,
+    /// if any (lower is better).
+    fn get_filter_match_quality(
+        story: &DisplayableHackerNewsItem,
+        filter: &str,
+    ) -> Option<(bool, usize)> {
+        [
+            story.title.as_deref(),
+            story.url_hostname.as_deref(),
+            Some(story.by_username.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|text| fuzzy_match(filter, text))
+        .map(|indices| fuzzy_match_quality(&indices))
+        .min()
+    }
+
+    /// Mark the given story as read, in the persisted history.
+    fn mark_as_read(ctx: &mut AppContext, story_id: HnItemIdScalar) {
+        let history = ctx.get_history_mut();
+        history.mark_item_as_read(story_id);
+        history.persist();
+    }
+
+    /// Open the page of the given story.
+    fn view_story(ctx: &mut AppContext, story: DisplayableHackerNewsItem) {
+        Self::mark_as_read(ctx, story.id);
+        ctx.get_state_mut()
+            .set_currently_viewed_item(Some(story.clone()));
+        ctx.router_push_navigation_stack(AppRoute::ItemDetails(story));
+    }
+}
+
 #[async_trait]
 impl UiComponent for StoriesPanel {
     fn id(&self) -> UiComponentId {
@@ -78,7 +200,10 @@ impl UiComponent for StoriesPanel {
     ) -> Result<bool> {
         self.ticks_since_last_update += elapsed_ticks;
 
-        self.loading = self.ticks_since_last_update >= MEAN_TICKS_BETWEEN_UPDATES
+        // an explicit refresh request (section or past day change) also triggers an update
+        self.loading = self.ticks_since_last_update
+            >= get_ticks_in_duration(ctx.get_config().get_stories_refresh_interval())
+            || ctx.get_state().get_main_stories_loading()
             || match &self.sorting_type_for_last_update {
                 Some(last_sorting_type) => {
                     last_sorting_type != ctx.get_state().get_main_stories_sorting()
@@ -88,10 +213,16 @@ impl UiComponent for StoriesPanel {
 
         self.loader.update();
 
-        Ok(self.loading)
+        // the displayed stories follow the filter as it is typed in
+        Ok(self.loading || self.filter != ctx.get_state().get_main_stories_filter())
     }
 
     async fn update(&mut self, client: &mut HnClient, ctx: &mut AppContext) -> Result<()> {
+        if !self.loading {
+            self.refresh_displayed_stories(ctx);
+            return Ok(());
+        }
+
         self.ticks_since_last_update = 0;
         self.loading = true;
 
@@ -100,32 +231,37 @@ impl UiComponent for StoriesPanel {
         let sorting_type = *ctx.get_state().get_main_stories_sorting();
 
         // Data fetching
-        let api = client.classic().await;
         let router = ctx.get_router();
         let displayable_stories = {
-            let fetched_stories =
-                if let Some(current_section) = router.get_current_route().get_home_section() {
-                    if current_section == &HnStoriesSections::Home {
-                        api.get_home_items(&sorting_type).await
-                    } else {
-                        api.get_home_section_items(current_section).await
+            let fetched_stories = match router.get_current_route() {
+                AppRoute::PastFrontPage => {
+                    let day = *ctx.get_state().get_past_front_page_day();
+                    client.get_front_page_items_at(day).await
+                }
+                AppRoute::SearchResults(query) => client.search_stories_items(query).await,
+                route => {
+                    let api = client.classic().await;
+                    match route.get_home_section() {
+                        Some(current_section) if current_section != &HnStoriesSections::Home => {
+                            api.get_home_section_items(current_section).await
+                        }
+                        _ => api.get_home_items(&sorting_type).await,
                     }
-                } else {
-                    api.get_home_items(&sorting_type).await
-                };
+                }
+            };
             match fetched_stories {
                 Ok(stories) => stories
                     .iter()
-                    .take(self.list_cutoff)
+                    .take(ctx.get_config().get_max_displayed_stories())
                     .cloned()
                     .map(|raw_item| {
                         DisplayableHackerNewsItem::try_from(raw_item)
                             .expect("StoriesPanel.update: can map DisplayableHackerNewsItem")
                     })
                     .collect(),
-                _ => {
+                Err(why) => {
                     ctx.get_state_mut().set_flash_message(FlashMessage::new(
-                        "Could not fetch HackerNews stories.",
+                        format!("Could not fetch HackerNews stories. {why}"),
                         FlashMessageType::Error,
                         FLASH_MESSAGE_DEFAULT_DURATION_MS,
                     ));
@@ -134,10 +270,22 @@ impl UiComponent for StoriesPanel {
             }
         };
 
-        self.list_state.replace_items(displayable_stories);
-        if self.list_state.selected().is_none() {
-            self.list_state.select(Some(0));
+        // the filter only applies to the stories list it was typed in for
+        let current_route = ctx.get_router().get_current_route().clone();
+        if self
+            .route_for_last_update
+            .as_ref()
+            .is_some_and(|route| route != &current_route)
+        {
+            let state = ctx.get_state_mut();
+            state.set_main_stories_filter(String::new());
+            state.set_main_stories_filter_input(None);
+            self.selection_before_filter = None;
         }
+        self.route_for_last_update = Some(current_route);
+
+        self.stories = displayable_stories;
+        self.refresh_displayed_stories(ctx);
 
         self.sorting_type_for_last_update = Some(sorting_type);
 
@@ -155,37 +303,96 @@ impl UiComponent for StoriesPanel {
         }
 
         let inputs = ctx.get_inputs();
-        let selected = self.list_state.selected();
+        let selected = *self.list_state.selected();
         Ok(if inputs.is_active(&ApplicationAction::NavigateUp) {
             self.list_state.previous();
+            self.publish_selected_story(ctx);
             true
         } else if inputs.is_active(&ApplicationAction::NavigateDown) {
             self.list_state.next();
+            self.publish_selected_story(ctx);
+            true
+        } else if let Some(jump) = ListJump::from_inputs(inputs) {
+            self.list_state.jump(jump);
+            self.publish_selected_story(ctx);
+            true
+        } else if inputs.is_active(&ApplicationAction::StoriesToggleHideRead) {
+            let hide_read = ctx.get_state().get_main_stories_hide_read();
+            ctx.get_state_mut().set_main_stories_hide_read(!hide_read);
+            self.refresh_displayed_stories(ctx);
+            true
+        } else if inputs.is_active(&ApplicationAction::StoriesEditFilter) {
+            if ctx.get_state().get_main_stories_filter().is_empty() {
+                self.selection_before_filter =
+                    selected.map(|index| self.list_state.get_items()[index].id);
+            }
+            let state = ctx.get_state_mut();
+            let input = TextInputState::from_string(state.get_main_stories_filter());
+            state.set_main_stories_filter_input(Some(input));
             true
-        } else if inputs.is_active(&ApplicationAction::OpenHackerNewsLink) {
-            let items = self.list_state.get_items();
-            let selected_item = &items[selected.unwrap()];
-            let item_hn_link = selected_item.get_hacker_news_link();
-            open_browser_tab(&item_hn_link);
+        } else if inputs.is_active(&ApplicationAction::ToggleRevealHidden) {
+            let reveal_hidden = ctx.get_state().get_reveal_hidden_items();
+            ctx.get_state_mut().set_reveal_hidden_items(!reveal_hidden);
+            self.refresh_displayed_stories(ctx);
             true
-        } else if inputs.is_active(&ApplicationAction::OpenExternalOrHackerNewsLink) {
-            let items = self.list_state.get_items();
-            let selected_item = &items[selected.unwrap()];
-            let item_link = selected_item
-                .url
-                .clone()
-                .unwrap_or_else(|| selected_item.get_hacker_news_link());
-            open_browser_tab(&item_link);
+        } else if let Some(mouse_input) = inputs.get_mouse_input().filter(|input| input.is_click())
+            && let Some(index) = self
+                .list_state
+                .get_index_at(mouse_input.column, mouse_input.row)
+        {
+            let is_double_click = mouse_input.kind == MouseInputKind::DoubleClick;
+            self.list_state.select(Some(index));
+            self.publish_selected_story(ctx);
+            if is_double_click {
+                let story = self.list_state.get_items()[index].clone();
+                Self::view_story(ctx, story);
+            }
             true
         } else if let Some(selected_index) = selected {
-            if inputs.is_active(&ApplicationAction::SelectItem)
+            let selected_item = self.list_state.get_items()[selected_index].clone();
+            if inputs.is_active(&ApplicationAction::OpenHackerNewsLink) {
+                open_browser_tab(&selected_item.get_hacker_news_link());
+                Self::mark_as_read(ctx, selected_item.id);
+                true
+            } else if inputs.is_active(&ApplicationAction::OpenExternalOrHackerNewsLink) {
+                let item_link = selected_item
+                    .url
+                    .clone()
+                    .unwrap_or_else(|| selected_item.get_hacker_news_link());
+                open_browser_tab(&item_link);
+                Self::mark_as_read(ctx, selected_item.id);
+                true
+            } else if inputs.is_active(&ApplicationAction::ToggleBookmark) {
+                toggle_bookmark(
+                    ctx,
+                    BookmarkedItem::Story {
+                        id: selected_item.id,
+                        title: selected_item.title.clone().unwrap_or_default(),
+                    },
+                );
+                true
+            } else if inputs.is_active(&ApplicationAction::ToggleWatch) {
+                toggle_item_watched(ctx, selected_item.id);
+                true
+            } else if inputs.is_active(&ApplicationAction::StoriesToggleHidden) {
+                let history = ctx.get_history_mut();
+                let hidden = history.toggle_item_hidden(selected_item.id);
+                history.persist();
+                ctx.get_state_mut().set_flash_message(FlashMessage::new(
+                    if hidden {
+                        "Story hidden."
+                    } else {
+                        "Story shown back."
+                    },
+                    FlashMessageType::Info,
+                    FLASH_MESSAGE_DEFAULT_DURATION_MS,
+                ));
+                self.refresh_displayed_stories(ctx);
+                true
+            } else if inputs.is_active(&ApplicationAction::SelectItem)
                 && ctx.get_state().get_latest_interacted_with_component() == Some(&STORIES_PANEL_ID)
             {
-                let items = self.list_state.get_items();
-                let selected_item = &items[*selected_index];
-                ctx.get_state_mut()
-                    .set_currently_viewed_item(Some(selected_item.clone()));
-                ctx.router_push_navigation_stack(AppRoute::ItemDetails(selected_item.clone()));
+                Self::view_story(ctx, selected_item);
                 true
             } else {
                 false
@@ -214,11 +421,46 @@ impl UiComponent for StoriesPanel {
         }
 
         // General case
-        let block_title = match ctx.get_state().get_main_stories_section() {
-            HnStoriesSections::Home => "Top stories",
-            HnStoriesSections::Ask => "Ask Hacker News",
-            HnStoriesSections::Show => "Show Hacker News",
-            HnStoriesSections::Jobs => "Jobs",
+        let block_title =
+            if let AppRoute::SearchResults(query) = ctx.get_router().get_current_route() {
+                format!("Search results for \"{query}\"")
+            } else if ctx.get_router().get_current_route().is_past_front_page() {
+                format!(
+                    "Front page of {}",
+                    ctx.get_state().get_past_front_page_day().format("%Y-%m-%d")
+                )
+            } else {
+                match ctx.get_state().get_main_stories_section() {
+                    HnStoriesSections::Home => "Top stories",
+                    HnStoriesSections::Ask => "Ask Hacker News",
+                    HnStoriesSections::Show => "Show Hacker News",
+                    HnStoriesSections::Jobs => "Jobs",
+                }
+                .to_string()
+            };
+        let block_title = if ctx.get_state().get_main_stories_hide_read() {
+            format!("{block_title} (unread only)")
+        } else {
+            block_title
+        };
+        let block_title = match (
+            self.hidden_stories_count,
+            ctx.get_state().get_reveal_hidden_items(),
+        ) {
+            (0, _) => block_title,
+            (hidden_count, false) => format!("{block_title} ({hidden_count} hidden)"),
+            (hidden_count, true) => format!("{block_title} ({hidden_count} hidden, revealed)"),
+        };
+        let is_typing_filter = ctx.get_state().get_main_stories_filter_input().is_some();
+        let block_title = if is_typing_filter || !self.filter.is_empty() {
+            format!(
+                "{block_title} (filter: {}{}, {} matching)",
+                self.filter,
+                if is_typing_filter { "_" } else { "" },
+                self.list_state.get_items().len()
+            )
+        } else {
+            block_title
         };
         let block = Block::default()
             .style(Style::default().fg(theme.get_block_color()))
@@ -228,6 +470,9 @@ impl UiComponent for StoriesPanel {
 
         // Custom List
         let display_story_meta = ctx.get_config().get_display_main_items_list_item_meta();
+        let history = ctx.get_history();
+        let killfile = ctx.get_config().get_killfile();
+        let filter = self.filter.as_str();
         let custom_list_stories = CustomList::new(
             &mut self.list_state,
             |rect, buf, item, is_selected| {
@@ -235,11 +480,39 @@ impl UiComponent for StoriesPanel {
                 let style = Style::default().fg(if is_selected {
                     theme.get_accent_color()
                 } else {
-                    Color::White
+                    theme.get_text_color()
                 });
-                // title
-                let title = item.title.clone().unwrap_or_default();
-                let (x, _) = buf.set_stringn(rect.x, rect.y, title, rect.width as usize, style);
+                // read stories are dimmed
+                let style = if history.is_item_read(item.id) {
+                    style.add_modifier(Modifier::DIM)
+                } else {
+                    style
+                };
+                // revealed hidden stories are crossed out
+                let style = if history.is_item_hidden(item.id) || killfile.kills_story(item) {
+                    style.add_modifier(Modifier::CROSSED_OUT)
+                } else {
+                    style
+                };
+                // title, starred if bookmarked, with the characters matching the filter highlighted
+                let title = item.title.as_deref().unwrap_or_default();
+                let mut title_spans = vec![];
+                if history.is_bookmarked(item.id) {
+                    title_spans.push(Span::styled("★ ", style));
+                }
+                let matched_indices = fuzzy_match(filter, title).unwrap_or_default();
+                let matched_style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+                title_spans.extend(title.chars().enumerate().map(|(index, char)| {
+                    Span::styled(
+                        char.to_string(),
+                        if matched_indices.contains(&index) {
+                            matched_style
+                        } else {
+                            style
+                        },
+                    )
+                }));
+                let (x, _) = buf.set_line(rect.x, rect.y, &Line::from(title_spans), rect.width);
                 // (optional) points & comments count
                 if !display_story_meta || x >= rect.width {
                     return;
@@ -262,8 +535,9 @@ impl UiComponent for StoriesPanel {
             |_| 1,
         )
         .block(block)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.get_text_color()))
         .highlight_symbol(">> ")
+        .compact(ctx.get_config().get_compact_lists())
         .highlight_style(Style::default().fg(theme.get_accent_color()));
 
         f.render_widget(custom_list_stories, inside);
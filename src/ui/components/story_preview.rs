@@ -0,0 +1,204 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use log::warn;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::{HnClient, types::HnItemIdScalar},
+    app::AppContext,
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        displayable_item::DisplayableHackerNewsItem,
+        utils::html_to_plain_text,
+    },
+};
+
+/// Story preview component, displayed next to the stories list on wide terminals.
+///
+/// Shows a summary of the selected story and its top comments, which are
+/// only fetched once the selection settles.
+///
+/// ```md
+/// ___________________________________________
+/// |                <TITLE>                  |
+/// |            <URL HOSTNAME?>              |
+/// |      <SCORE> POINTS / BY <USERNAME>     |
+/// |              <TEXT?>                    |
+/// |                                         |
+/// |     <TOP COMMENTS, BY <USERNAME> ...>   |
+/// |_________________________________________|
+/// ```
+#[derive(Debug, Default)]
+pub struct StoryPreview {
+    /// Story whose top comments are fetched.
+    story_id: Option<HnItemIdScalar>,
+    /// Selected story, possibly not fetched yet.
+    selected_story_id: Option<HnItemIdScalar>,
+    /// Elapsed ticks (since the latest update) when the selection changed.
+    selection_changed_at: UiTickScalar,
+    top_comments: Vec<DisplayableHackerNewsItem>,
+}
+
+pub const STORY_PREVIEW_ID: UiComponentId = "story_preview";
+
+/// Ticks to wait for once the selection changed, before fetching the top comments. 1 tick ~= 100ms.
+const TOP_COMMENTS_FETCH_DELAY_TICKS: UiTickScalar = 3;
+
+/// Number of previewed top comments.
+const PREVIEWED_TOP_COMMENTS_COUNT: usize = 5;
+
+/// Maximum number of lines of a previewed comment.
+const PREVIEWED_COMMENT_MAX_LINES: usize = 6;
+
+#[async_trait]
+impl UiComponent for StoryPreview {
+    fn id(&self) -> UiComponentId {
+        STORY_PREVIEW_ID
+    }
+
+    async fn should_update(
+        &mut self,
+        elapsed_ticks: UiTickScalar,
+        ctx: &AppContext,
+    ) -> Result<bool> {
+        let selected_story_id = ctx
+            .get_state()
+            .get_main_stories_selected_story()
+            .map(|story| story.id);
+        // the comments are not fetched while moving through the stories
+        if selected_story_id != self.selected_story_id {
+            self.selected_story_id = selected_story_id;
+            self.selection_changed_at = elapsed_ticks;
+        }
+
+        Ok(self.selected_story_id != self.story_id
+            && elapsed_ticks.saturating_sub(self.selection_changed_at)
+                >= TOP_COMMENTS_FETCH_DELAY_TICKS)
+    }
+
+    async fn update(&mut self, client: &mut HnClient, ctx: &mut AppContext) -> Result<()> {
+        self.story_id = self.selected_story_id;
+        self.top_comments.clear();
+
+        let Some(kids) = ctx
+            .get_state()
+            .get_main_stories_selected_story()
+            .and_then(|story| story.kids.as_ref())
+        else {
+            return Ok(());
+        };
+        let top_comments_ids: Vec<_> = kids
+            .iter()
+            .take(PREVIEWED_TOP_COMMENTS_COUNT)
+            .copied()
+            .collect();
+        match client.classic().await.get_items(&top_comments_ids).await {
+            Ok(comments) => {
+                self.top_comments = comments
+                    .into_iter()
+                    .filter_map(|comment| DisplayableHackerNewsItem::try_from(comment).ok())
+                    .collect();
+            }
+            Err(why) => warn!("StoryPreview: cannot fetch the top comments: {why}"),
+        }
+
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, _ctx: &mut AppContext) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title("Preview");
+        let paragraph_area = block.inner(inside);
+        f.render_widget(block, inside);
+
+        let Some(story) = ctx.get_state().get_main_stories_selected_story() else {
+            return Ok(());
+        };
+        let width = paragraph_area.width as usize;
+
+        // Summary
+        let mut lines = vec![
+            Line::styled(
+                story.title.clone().unwrap_or_default(),
+                Style::default()
+                    .fg(theme.get_accent_color())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::from(story.url_hostname.clone().unwrap_or_default()),
+            Line::from(format!(
+                "{} points by {} {}, {} comments",
+                story.score,
+                story.by_username,
+                story.posted_since,
+                story.kids.as_ref().map_or(0, |kids| kids.len()),
+            )),
+        ];
+        if let Some(text) = &story.text {
+            lines.push(Line::from(""));
+            lines.extend(
+                html_to_plain_text(text, width)?
+                    .lines()
+                    .map(|line| Line::from(line.to_string())),
+            );
+        }
+
+        // Top comments, once fetched for this story
+        lines.push(Line::from(""));
+        let top_comments: &[DisplayableHackerNewsItem] = if self.story_id == Some(story.id) {
+            &self.top_comments
+        } else {
+            lines.push(Line::styled(
+                "Loading the top comments...",
+                Style::default().fg(theme.get_muted_color()),
+            ));
+            &[]
+        };
+        let killfile = ctx.get_config().get_killfile();
+        let reveal_hidden = ctx.get_state().get_reveal_hidden_items();
+        for comment in top_comments
+            .iter()
+            .filter(|comment| reveal_hidden || !killfile.kills_comment(comment))
+        {
+            lines.push(Line::styled(
+                format!("{} {}", comment.by_username, comment.posted_since),
+                Style::default().fg(theme.get_main_color()),
+            ));
+            let text = html_to_plain_text(comment.text.as_deref().unwrap_or_default(), width)?;
+            let text_lines: Vec<_> = text.lines().collect();
+            lines.extend(
+                text_lines
+                    .iter()
+                    .take(PREVIEWED_COMMENT_MAX_LINES)
+                    .map(|line| Line::from(line.to_string())),
+            );
+            if text_lines.len() > PREVIEWED_COMMENT_MAX_LINES {
+                lines.push(Line::styled(
+                    "[...]",
+                    Style::default().fg(theme.get_muted_color()),
+                ));
+            }
+            lines.push(Line::from(""));
+        }
+
+        let paragraph = Paragraph::new(lines).style(Style::default().fg(theme.get_text_color()));
+        f.render_widget(paragraph, paragraph_area);
+
+        Ok(())
+    }
+}
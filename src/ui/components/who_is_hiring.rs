@@ -0,0 +1,5 @@
+//! Components of the screen browsing the latest monthly "Who is hiring?" thread.
+
+pub mod filters;
+pub mod posting_details;
+pub mod postings;
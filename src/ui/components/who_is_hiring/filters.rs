@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::HnClient,
+    app::AppContext,
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::widgets::text_input::{TextInputState, TextInputWidget},
+        displayable_item::job_posting::JobPostingsFilterField,
+        handlers::ApplicationAction,
+    },
+};
+
+/// The Who Is Hiring Filters component displays and toggles the filters
+/// applied to the "Who is hiring?" postings.
+///
+/// NB: typing in the text filters is handled by the screen itself.
+#[derive(Debug, Default)]
+pub struct WhoIsHiringFilters {}
+
+pub const WHO_IS_HIRING_FILTERS_ID: UiComponentId = "who_is_hiring_filters";
+
+#[async_trait]
+impl UiComponent for WhoIsHiringFilters {
+    fn id(&self) -> UiComponentId {
+        WHO_IS_HIRING_FILTERS_ID
+    }
+
+    async fn should_update(
+        &mut self,
+        _elapsed_ticks: UiTickScalar,
+        _ctx: &AppContext,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    async fn update(&mut self, _client: &mut HnClient, _ctx: &mut AppContext) -> Result<()> {
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        let inputs = ctx.get_inputs();
+        let edited_field = if inputs.is_active(&ApplicationAction::WhoIsHiringEditLocation) {
+            JobPostingsFilterField::Location
+        } else if inputs.is_active(&ApplicationAction::WhoIsHiringEditKeyword) {
+            JobPostingsFilterField::Keyword
+        } else if inputs.is_active(&ApplicationAction::WhoIsHiringToggleRemoteOnly) {
+            let filters = ctx.get_state_mut().get_who_is_hiring_filters_mut();
+            filters.remote_only = !filters.remote_only;
+            return Ok(true);
+        } else if inputs.is_active(&ApplicationAction::WhoIsHiringToggleHideSeen) {
+            let filters = ctx.get_state_mut().get_who_is_hiring_filters_mut();
+            filters.hide_seen = !filters.hide_seen;
+            return Ok(true);
+        } else {
+            return Ok(false);
+        };
+
+        let state = ctx.get_state_mut();
+        let current_value = state.get_who_is_hiring_filters().get_text(edited_field);
+        let input = TextInputState::from_string(current_value);
+        state.set_who_is_hiring_filter_input(Some((edited_field, input)));
+        Ok(true)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+        let state = ctx.get_state();
+        let filters = state.get_who_is_hiring_filters();
+
+        let key =
+            |action: ApplicationAction| ctx.get_inputs().get_key_bindings_representation(&action);
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .border_type(BorderType::Thick)
+            .borders(Borders::ALL)
+            .title(format!(
+                "Filters ({}: remote, {}: unseen, {}: location, {}: keyword, {}: mark seen)",
+                key(ApplicationAction::WhoIsHiringToggleRemoteOnly),
+                key(ApplicationAction::WhoIsHiringToggleHideSeen),
+                key(ApplicationAction::WhoIsHiringEditLocation),
+                key(ApplicationAction::WhoIsHiringEditKeyword),
+                key(ApplicationAction::WhoIsHiringToggleSeen),
+            ));
+
+        // Editing case
+        if let Some((field, input)) = state.get_who_is_hiring_filter_input() {
+            let label = format!("{}: ", field.get_label());
+            let block_inner = block.inner(inside);
+            f.render_widget(block, inside);
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(label.len() as u16), Constraint::Min(1)])
+                .split(block_inner);
+            f.render_widget(Paragraph::new(label), chunks[0]);
+            let input_widget = TextInputWidget::with_state(input)
+                .style(Style::default().fg(theme.get_accent_color()))
+                .cursor_style(Style::default().bg(theme.get_highlight_color()));
+            f.render_widget(input_widget, chunks[1]);
+            return Ok(());
+        }
+
+        // General case
+        let toggle_span = |label: &'static str, enabled: bool| {
+            Span::styled(
+                format!("[{}] {}", if enabled { "x" } else { " " }, label),
+                Style::default().fg(if enabled {
+                    theme.get_main_color()
+                } else {
+                    theme.get_text_color()
+                }),
+            )
+        };
+        let text_filter_span = |field: JobPostingsFilterField| {
+            let value = filters.get_text(field);
+            Span::styled(
+                format!(
+                    "{}: {}",
+                    field.get_label(),
+                    if value.is_empty() { "any" } else { value }
+                ),
+                Style::default().fg(if value.is_empty() {
+                    theme.get_muted_color()
+                } else {
+                    theme.get_main_color()
+                }),
+            )
+        };
+        let separator = || Span::raw(" | ");
+        let text = vec![Line::from(vec![
+            toggle_span("Remote only", filters.remote_only),
+            separator(),
+            toggle_span("Unseen only", filters.hide_seen),
+            separator(),
+            text_filter_span(JobPostingsFilterField::Location),
+            separator(),
+            text_filter_span(JobPostingsFilterField::Keyword),
+        ])];
+        let paragraph = Paragraph::new(text).block(block);
+        f.render_widget(paragraph, inside);
+
+        Ok(())
+    }
+}
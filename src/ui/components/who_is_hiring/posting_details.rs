@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+};
+
+use crate::{
+    api::HnClient,
+    app::AppContext,
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        utils::html_to_plain_text,
+    },
+};
+
+/// The Who Is Hiring Posting Details component displays the full text
+/// of the currently selected job posting.
+///
+/// Does not do any fetching, everything is provided by the postings list.
+#[derive(Debug, Default)]
+pub struct WhoIsHiringPostingDetails {}
+
+pub const WHO_IS_HIRING_POSTING_DETAILS_ID: UiComponentId = "who_is_hiring_posting_details";
+
+#[async_trait]
+impl UiComponent for WhoIsHiringPostingDetails {
+    fn id(&self) -> UiComponentId {
+        WHO_IS_HIRING_POSTING_DETAILS_ID
+    }
+
+    async fn should_update(
+        &mut self,
+        _elapsed_ticks: UiTickScalar,
+        _ctx: &AppContext,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    async fn update(&mut self, _client: &mut HnClient, _ctx: &mut AppContext) -> Result<()> {
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, _ctx: &mut AppContext) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        let posting = match ctx.get_state().get_who_is_hiring_selected_posting() {
+            Some(posting) => posting,
+            None => {
+                let paragraph = Paragraph::new(vec![Line::from("No posting selected.")])
+                    .block(block.title("Posting"));
+                f.render_widget(paragraph, inside);
+                return Ok(());
+            }
+        };
+
+        let block = block.title(format!(
+            "By {} {}{}",
+            posting.by_username,
+            posting.posted_since,
+            if ctx.get_history().is_job_posting_seen(posting.id) {
+                " (seen)"
+            } else {
+                ""
+            }
+        ));
+        let text_width = block.inner(inside).width.max(1) as usize;
+        let text: Vec<Line> = html_to_plain_text(&posting.text, text_width)?
+            .lines()
+            .map(|line| Line::from(line.to_string()))
+            .collect();
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .style(Style::default().fg(theme.get_text_color()))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, inside);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use ratatui::{
+    layout::{HorizontalAlignment, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    api::{HnClient, types::HnItemIdScalar},
+    app::AppContext,
+    errors::Result,
+    ui::{
+        common::{RenderFrame, UiComponent, UiComponentId, UiTickScalar},
+        components::widgets::custom_list::{CustomList, CustomListState},
+        displayable_item::{
+            DisplayableHackerNewsItem,
+            job_posting::{DisplayableJobPosting, JobPostingsFilters},
+        },
+        flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
+        handlers::ApplicationAction,
+        utils::{loader::Loader, open_browser_tab},
+    },
+};
+
+/// The Who Is Hiring Postings component lists the (filtered) job postings
+/// of the latest "Who is hiring?" thread.
+#[derive(Debug)]
+pub struct WhoIsHiringPostings {
+    ticks_since_last_update: UiTickScalar,
+    /// Should the thread be (re-)fetched at the next update?
+    loading: bool,
+    loader: Loader,
+    /// Title of the fetched thread, if any.
+    thread_title: Option<String>,
+    /// All the postings of the fetched thread.
+    postings: Vec<DisplayableJobPosting>,
+    /// Filters used to build the currently displayed postings list.
+    filters_for_last_refresh: Option<JobPostingsFilters>,
+    /// Currently displayed postings, *i.e.* the ones matching the filters.
+    list_state: CustomListState<HnItemIdScalar, DisplayableJobPosting>,
+}
+
+/// The thread is only posted once a month, but postings keep coming for a few days.
+const MEAN_TICKS_BETWEEN_UPDATES: UiTickScalar = 36000; // approx. every hour
+
+impl Default for WhoIsHiringPostings {
+    fn default() -> Self {
+        Self {
+            ticks_since_last_update: 0,
+            loading: true,
+            loader: Loader::default(),
+            thread_title: None,
+            postings: vec![],
+            filters_for_last_refresh: None,
+            list_state: CustomListState::with_items(vec![]),
+        }
+    }
+}
+
+pub const WHO_IS_HIRING_POSTINGS_ID: UiComponentId = "who_is_hiring_postings";
+
+impl WhoIsHiringPostings {
+    /// Rebuild the displayed postings list from the current filters.
+    fn refresh_displayed_postings(&mut self, ctx: &mut AppContext) {
+        let filters = ctx.get_state().get_who_is_hiring_filters().clone();
+        let history = ctx.get_history();
+        let displayed_postings = self
+            .postings
+            .iter()
+            .filter(|posting| posting.matches(&filters, history.is_job_posting_seen(posting.id)))
+            .cloned()
+            .collect();
+        self.list_state.replace_items(displayed_postings);
+        if self.list_state.selected().is_none() && !self.list_state.is_empty() {
+            self.list_state.select(Some(0));
+        }
+        self.filters_for_last_refresh = Some(filters);
+        self.sync_selected_posting(ctx);
+    }
+
+    /// Share the selected posting with the details component.
+    fn sync_selected_posting(&self, ctx: &mut AppContext) {
+        let selected_posting = self
+            .list_state
+            .selected()
+            .and_then(|index| self.list_state.get_items().get(index))
+            .cloned();
+        ctx.get_state_mut()
+            .set_who_is_hiring_selected_posting(selected_posting);
+    }
+}
+
+#[async_trait]
+impl UiComponent for WhoIsHiringPostings {
+    fn id(&self) -> UiComponentId {
+        WHO_IS_HIRING_POSTINGS_ID
+    }
+
+    fn before_unmount(&mut self) {
+        self.loader.stop();
+    }
+
+    async fn should_update(
+        &mut self,
+        elapsed_ticks: UiTickScalar,
+        ctx: &AppContext,
+    ) -> Result<bool> {
+        self.ticks_since_last_update += elapsed_ticks;
+        self.loader.update();
+
+        self.loading = self.loading || self.ticks_since_last_update >= MEAN_TICKS_BETWEEN_UPDATES;
+        let filters_changed = self.filters_for_last_refresh.as_ref()
+            != Some(ctx.get_state().get_who_is_hiring_filters());
+
+        Ok(self.loading || filters_changed)
+    }
+
+    async fn update(&mut self, client: &mut HnClient, ctx: &mut AppContext) -> Result<()> {
+        // filters change only: no fetching needed
+        if !self.loading {
+            self.refresh_displayed_postings(ctx);
+            return Ok(());
+        }
+
+        self.ticks_since_last_update = 0;
+
+        match client.get_latest_who_is_hiring_thread().await {
+            Ok((thread, raw_postings)) => {
+                self.thread_title = DisplayableHackerNewsItem::try_from(thread)
+                    .ok()
+                    .and_then(|thread| thread.title);
+                self.postings = raw_postings
+                    .into_iter()
+                    .filter_map(|raw_posting| DisplayableHackerNewsItem::try_from(raw_posting).ok())
+                    .filter_map(|comment| DisplayableJobPosting::from_comment(&comment))
+                    .collect();
+            }
+            Err(why) => {
+                ctx.get_state_mut().set_flash_message(FlashMessage::new(
+                    format!("Could not fetch the latest \"Who is hiring?\" thread: {why}"),
+                    FlashMessageType::Error,
+                    FLASH_MESSAGE_DEFAULT_DURATION_MS,
+                ));
+            }
+        }
+        self.refresh_displayed_postings(ctx);
+
+        self.loading = false;
+
+        Ok(())
+    }
+
+    async fn handle_inputs(&mut self, ctx: &mut AppContext) -> Result<bool> {
+        if self.loading || self.list_state.is_empty() {
+            return Ok(false);
+        }
+
+        let inputs = ctx.get_inputs();
+        let selected_posting = self
+            .list_state
+            .selected()
+            .and_then(|index| self.list_state.get_items().get(index))
+            .cloned();
+        if inputs.is_active(&ApplicationAction::NavigateUp) {
+            self.list_state.previous();
+            self.sync_selected_posting(ctx);
+        } else if inputs.is_active(&ApplicationAction::NavigateDown) {
+            self.list_state.next();
+            self.sync_selected_posting(ctx);
+        } else if inputs.is_active(&ApplicationAction::OpenHackerNewsLink)
+            || inputs.is_active(&ApplicationAction::OpenExternalOrHackerNewsLink)
+        {
+            if let Some(posting) = selected_posting {
+                open_browser_tab(&posting.get_hacker_news_link());
+            }
+        } else if inputs.is_active(&ApplicationAction::WhoIsHiringToggleSeen) {
+            if let Some(posting) = selected_posting {
+                let history = ctx.get_history_mut();
+                history.toggle_job_posting_seen(posting.id);
+                history.persist();
+                if ctx.get_state().get_who_is_hiring_filters().hide_seen {
+                    self.refresh_displayed_postings(ctx);
+                }
+            }
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn render(&mut self, f: &mut RenderFrame, inside: Rect, ctx: &AppContext) -> Result<()> {
+        let theme = ctx.get_theme();
+
+        // Loading case
+        if self.loading {
+            let block = Block::default()
+                .style(Style::default().fg(theme.get_block_color()))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded);
+
+            let text = vec![Line::from(""), Line::from(self.loader.text())];
+            let paragraph = Paragraph::new(text)
+                .block(block)
+                .alignment(HorizontalAlignment::Center);
+            f.render_widget(paragraph, inside);
+            return Ok(());
+        }
+
+        // General case
+        let block_title = format!(
+            "{} ({}/{})",
+            self.thread_title.as_deref().unwrap_or("Who is hiring?"),
+            self.list_state.get_items().len(),
+            self.postings.len(),
+        );
+        let block = Block::default()
+            .style(Style::default().fg(theme.get_block_color()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(block_title);
+
+        let history = ctx.get_history();
+        let custom_list_postings = CustomList::new(
+            &mut self.list_state,
+            |rect, buf, posting, is_selected| {
+                let style = Style::default().fg(if is_selected {
+                    theme.get_accent_color()
+                } else {
+                    theme.get_text_color()
+                });
+                // seen postings are dimmed
+                let style = if history.is_job_posting_seen(posting.id) {
+                    style.add_modifier(Modifier::DIM)
+                } else {
+                    style
+                };
+                buf.set_stringn(
+                    rect.x,
+                    rect.y,
+                    &posting.header_line,
+                    rect.width as usize,
+                    style,
+                );
+            },
+            |_| 1,
+        )
+        .block(block)
+        .style(Style::default().fg(theme.get_text_color()))
+        .highlight_symbol(">> ")
+        .compact(ctx.get_config().get_compact_lists())
+        .highlight_style(Style::default().fg(theme.get_accent_color()));
+
+        f.render_widget(custom_list_postings, inside);
+
+        Ok(())
+    }
+}
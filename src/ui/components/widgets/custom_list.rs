@@ -30,12 +30,63 @@ use unicode_width::UnicodeWidthStr;
 use num_traits::Num;
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Position, Rect},
     style::Style,
     widgets::{Block, ListDirection, Widget},
 };
 
-use crate::ui::utils::ItemWithId;
+use crate::ui::{
+    handlers::{ApplicationAction, InputsController},
+    utils::ItemWithId,
+};
+
+/// Symbol in front of the selected item of compact lists.
+const COMPACT_HIGHLIGHT_SYMBOL: &str = "> ";
+
+/// Page-wise or boundary move of a list selection, not wrapping around.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListJump {
+    PageUp,
+    PageDown,
+    HalfPageUp,
+    HalfPageDown,
+    First,
+    Last,
+}
+
+impl ListJump {
+    /// Get the jump requested by the current inputs, if any.
+    pub fn from_inputs(inputs: &InputsController) -> Option<Self> {
+        use ApplicationAction::*;
+        [
+            (NavigatePageUp, Self::PageUp),
+            (NavigatePageDown, Self::PageDown),
+            (NavigateHalfPageUp, Self::HalfPageUp),
+            (NavigateHalfPageDown, Self::HalfPageDown),
+            (NavigateFirst, Self::First),
+            (NavigateLast, Self::Last),
+        ]
+        .into_iter()
+        .find(|(action, _)| inputs.is_active(action))
+        .map(|(_, jump)| jump)
+    }
+
+    /// Get the index to select among `count` items, given the currently selected one
+    /// and the number of items per page.
+    pub fn apply(&self, selected: usize, count: usize, page_size: usize) -> usize {
+        let page_size = page_size.max(1);
+        let half_page_size = (page_size / 2).max(1);
+        let last = count.saturating_sub(1);
+        match self {
+            Self::PageUp => selected.saturating_sub(page_size),
+            Self::PageDown => (selected + page_size).min(last),
+            Self::HalfPageUp => selected.saturating_sub(half_page_size),
+            Self::HalfPageDown => (selected + half_page_size).min(last),
+            Self::First => 0,
+            Self::Last => last,
+        }
+    }
+}
 
 /// Custom `CustomList` state analogous to `ListState` but providing wrap-around navigation,
 /// and storing the "raw" list items (from which each line is rendered).
@@ -48,6 +99,10 @@ where
     offset: usize,
     selected: Option<usize>,
     items: Vec<T>,
+    /// Number of items displayed at the latest rendering, for page-wise navigation.
+    page_size: usize,
+    /// Areas of the items displayed at the latest rendering, starting at `offset`, for mouse selection.
+    rendered_areas: Vec<Rect>,
     // NB: this field is only there to prevent the "N parameter not used" compilation error
     _n: N,
 }
@@ -63,6 +118,8 @@ where
             offset: 0,
             selected: None,
             items,
+            page_size: 1,
+            rendered_areas: vec![],
             _n: Default::default(),
         }
     }
@@ -91,7 +148,12 @@ where
 
     /// Select the next item, starting at 0 if none is selected or
     /// wrapping around to 0 if at the end of the list.
+    ///
+    /// Does nothing if the list is empty.
     pub fn next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
         self.select(Some(match self.selected {
             None => 0,
             Some(i) => (i + 1) % self.items.len(),
@@ -100,7 +162,12 @@ where
 
     /// Select the previous item, starting at 0 if none is selected or
     /// wrapping around to `items.len() - 1` if at the start of the list.
+    ///
+    /// Does nothing if the list is empty.
     pub fn previous(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
         self.select(Some(match self.selected {
             None => 0,
             Some(i) => {
@@ -113,6 +180,28 @@ where
         }))
     }
 
+    /// Move the selection page-wise, or to the first or last item, starting at 0 if none is selected.
+    ///
+    /// Does nothing if the list is empty.
+    pub fn jump(&mut self, jump: ListJump) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.select(Some(jump.apply(
+            self.selected.unwrap_or(0),
+            self.items.len(),
+            self.page_size,
+        )));
+    }
+
+    /// Get the index of the item displayed at the given terminal position, if any.
+    pub fn get_index_at(&self, column: u16, row: u16) -> Option<usize> {
+        self.rendered_areas
+            .iter()
+            .position(|area| area.contains(Position::new(column, row)))
+            .map(|position| self.offset + position)
+    }
+
     pub fn selected(&self) -> &Option<usize> {
         &self.selected
     }
@@ -171,6 +260,8 @@ where
     render: F,
     /// Get the height of a given item.
     get_item_height: H,
+    /// Compact lists display a single line per item, with a shorter highlight symbol.
+    compact: bool,
 }
 
 impl<'a, F, H, N, T> CustomList<'a, F, H, N, T>
@@ -190,6 +281,7 @@ where
             state,
             render,
             get_item_height,
+            compact: false,
         }
     }
 
@@ -208,6 +300,11 @@ where
         self
     }
 
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
     pub fn highlight_style(mut self, style: Style) -> Self {
         self.highlight_style = style;
         self
@@ -223,7 +320,11 @@ where
         let mut height = 0;
         let mut item_heights = HashMap::new();
         for item in self.state.items.iter().skip(offset) {
-            let item_height = (self.get_item_height)(item);
+            let item_height = if self.compact {
+                1
+            } else {
+                (self.get_item_height)(item)
+            };
             if height + item_height > max_height {
                 break;
             }
@@ -236,7 +337,11 @@ where
             if let Some(h) = item_heights.get(&item.get_id()) {
                 *h
             } else {
-                let h = (self.get_item_height)(item);
+                let h = if self.compact {
+                    1
+                } else {
+                    (self.get_item_height)(item)
+                };
                 item_heights.insert(item.get_id(), h);
                 h
             }
@@ -276,6 +381,7 @@ where
     T: Clone + ItemWithId<N>,
 {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
+        self.state.rendered_areas.clear();
         buf.set_style(area, self.style);
         let list_area = match self.block.take() {
             Some(b) => {
@@ -297,18 +403,27 @@ where
 
         let (start, end, mut item_heights) = self.get_items_bounds(list_height);
         self.state.offset = start;
+        self.state.page_size = end - start;
 
         let mut access_item_height = |item: &T| -> usize {
             if let Some(h) = item_heights.get(&item.get_id()) {
                 *h
             } else {
-                let h = (self.get_item_height)(item);
+                let h = if self.compact {
+                    1
+                } else {
+                    (self.get_item_height)(item)
+                };
                 item_heights.insert(item.get_id(), h);
                 h
             }
         };
 
-        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let highlight_symbol = match self.highlight_symbol {
+            Some(_) if self.compact => COMPACT_HIGHLIGHT_SYMBOL,
+            Some(highlight_symbol) => highlight_symbol,
+            None => "",
+        };
         let blank_symbol = " ".repeat(highlight_symbol.width());
 
         let mut current_height = 0;
@@ -338,6 +453,7 @@ where
                 height: item_height as u16,
             };
             buf.set_style(area, self.style);
+            self.state.rendered_areas.push(area);
 
             let is_selected = self.state.selected.map(|s| s == i).unwrap_or(false);
             // if the item is selected, we need to display the hightlight symbol:
@@ -371,7 +487,7 @@ where
 mod tests {
     use crate::ui::utils::ItemWithId;
 
-    use super::CustomListState;
+    use super::{CustomListState, ListJump};
 
     #[derive(Clone)]
     struct CustomListStateTestScalar {
@@ -415,4 +531,27 @@ mod tests {
         stateful_list.previous();
         assert_eq!(*stateful_list.selected(), Some(0));
     }
+
+    #[test]
+    pub fn test_stateful_custom_list_jumps() {
+        let mut stateful_list =
+            CustomListState::with_items((0..25).map(CustomListStateTestScalar::new).collect());
+        stateful_list.page_size = 10;
+
+        stateful_list.jump(ListJump::PageDown);
+        assert_eq!(*stateful_list.selected(), Some(10));
+        stateful_list.jump(ListJump::HalfPageDown);
+        assert_eq!(*stateful_list.selected(), Some(15));
+        // no wrapping around
+        stateful_list.jump(ListJump::PageDown);
+        assert_eq!(*stateful_list.selected(), Some(24));
+        stateful_list.jump(ListJump::HalfPageUp);
+        assert_eq!(*stateful_list.selected(), Some(19));
+        stateful_list.jump(ListJump::First);
+        assert_eq!(*stateful_list.selected(), Some(0));
+        stateful_list.jump(ListJump::PageUp);
+        assert_eq!(*stateful_list.selected(), Some(0));
+        stateful_list.jump(ListJump::Last);
+        assert_eq!(*stateful_list.selected(), Some(24));
+    }
 }
@@ -209,6 +209,8 @@ pub struct TextInputWidget<'a> {
     state: &'a TextInputState,
     /// (Optional) Custom rendering style.
     style: Style,
+    /// (Optional) Custom cursor rendering style.
+    cursor_style: Style,
     /// (Optional) Wrapping `tui-rs` Block widget.
     block: Option<Block<'a>>,
 }
@@ -218,6 +220,7 @@ impl<'a> TextInputWidget<'a> {
         Self {
             state,
             style: Style::default(),
+            cursor_style: Style::default().bg(Color::LightYellow),
             block: None,
         }
     }
@@ -231,6 +234,11 @@ impl<'a> TextInputWidget<'a> {
         self.style = style;
         self
     }
+
+    pub fn cursor_style(mut self, cursor_style: Style) -> Self {
+        self.cursor_style = cursor_style;
+        self
+    }
 }
 
 impl<'a> Widget for TextInputWidget<'a> {
@@ -254,12 +262,7 @@ impl<'a> Widget for TextInputWidget<'a> {
 
         buf.set_string(text_area.x, text_area.y, &self.state.value, self.style);
         if let Some(cursor_index) = cursor_position {
-            buf.set_string(
-                area.x + cursor_index,
-                area.y,
-                " ",
-                Style::default().bg(Color::LightYellow),
-            );
+            buf.set_string(area.x + cursor_index, area.y, " ", self.cursor_style);
         }
     }
 }
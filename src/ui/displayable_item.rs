@@ -10,6 +10,7 @@ use crate::{
     errors::{HnCliError, Result},
 };
 
+pub mod job_posting;
 pub mod user;
 
 use super::utils::{ItemWithId, datetime_from_hn_time};
@@ -109,6 +110,31 @@ impl ItemWithId<HnItemIdScalar> for DisplayableHackerNewsItem {
     }
 }
 
+#[cfg(test)]
+impl DisplayableHackerNewsItem {
+    /// Build a top-level comment for the tests, without replies.
+    ///
+    /// Any field can be overridden with the struct update syntax, *e.g.* to build a story:
+    /// `DisplayableHackerNewsItem { is_comment: false, ..DisplayableHackerNewsItem::test_item(1) }`.
+    pub fn test_item(id: HnItemIdScalar) -> Self {
+        Self {
+            id,
+            posted_at: Utc::now(),
+            posted_since: "1 minute ago".into(),
+            by_username: "pg".into(),
+            title: None,
+            text: Some("Comment.".into()),
+            score: 0,
+            url: None,
+            url_hostname: None,
+            kids: None,
+            parent: None,
+            is_comment: true,
+            is_job: false,
+        }
+    }
+}
+
 // TODO: avoid url.clone here
 impl TryFrom<HnItem> for DisplayableHackerNewsItem {
     type Error = HnCliError;
@@ -0,0 +1,290 @@
+use crate::{
+    api::types::HnItemIdScalar,
+    ui::utils::{ItemWithId, html_to_plain_text},
+};
+
+use super::DisplayableHackerNewsItem;
+
+/// Wrapping width of the plain text of the postings, on which the keyword filter applies.
+const PLAIN_TEXT_WIDTH: usize = 1000;
+
+/// Structured data parsed from the conventional header line of a "Who is hiring?" posting,
+/// *i.e.* `Company | Role | Location | REMOTE | ...`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JobPostingHeader {
+    /// Company name, always the first field.
+    pub company: String,
+    /// Role(s), conventionally the second field.
+    pub role: Option<String>,
+    /// Location(s), conventionally the third field.
+    pub location: Option<String>,
+    /// Is remote work mentioned as possible?
+    pub is_remote: bool,
+}
+
+impl JobPostingHeader {
+    /// Parse the given plain text header line.
+    ///
+    /// Returns `None` if the line does not follow the `|`-separated convention.
+    pub fn parse(header: &str) -> Option<Self> {
+        let fields: Vec<&str> = header
+            .split('|')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .collect();
+        if fields.len() < 2 {
+            return None;
+        }
+
+        let is_remote = fields[1..].iter().any(|field| Self::mentions_remote(field));
+        let positional = |index: usize| {
+            fields
+                .get(index)
+                .filter(|field| !field.eq_ignore_ascii_case("remote"))
+                .map(|field| field.to_string())
+        };
+        Some(Self {
+            company: fields[0].to_string(),
+            role: positional(1),
+            location: positional(2),
+            is_remote,
+        })
+    }
+
+    fn mentions_remote(field: &str) -> bool {
+        let field = field.to_lowercase();
+        field.contains("remote")
+            && !["no remote", "not remote", "non-remote", "onsite only"]
+                .iter()
+                .any(|negation| field.contains(negation))
+    }
+}
+
+/// A display-ready top-level comment of a "Who is hiring?" thread.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayableJobPosting {
+    /// Unique ID of the comment.
+    pub id: HnItemIdScalar,
+    /// Username of the poster.
+    pub by_username: String,
+    /// Posted since, formatted for display.
+    pub posted_since: String,
+    /// Raw header line, as plain text.
+    pub header_line: String,
+    /// Parsed header, if the posting follows the convention.
+    pub header: Option<JobPostingHeader>,
+    /// *HTML* body of the posting, including the header line.
+    pub text: String,
+    /// Plain text body of the posting, on a single line.
+    plain_text: String,
+}
+
+impl DisplayableJobPosting {
+    pub fn get_hacker_news_link(&self) -> String {
+        format!("https://news.ycombinator.com/item?id={}", self.id)
+    }
+
+    /// Build a job posting from a top-level comment, if it has any text.
+    pub fn from_comment(comment: &DisplayableHackerNewsItem) -> Option<Self> {
+        let text = comment.text.as_ref()?;
+        let header_html = text.split("<p>").next().unwrap_or_default();
+        let header_line = html_fragment_to_plain_text(header_html);
+        // the lines are joined back, for the keywords to match across the wrapped lines
+        let plain_text = html_to_plain_text(text, PLAIN_TEXT_WIDTH)
+            .unwrap_or_else(|_| html_fragment_to_plain_text(text))
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some(Self {
+            id: comment.id,
+            by_username: comment.by_username.clone(),
+            posted_since: comment.posted_since.clone(),
+            header: JobPostingHeader::parse(&header_line),
+            header_line,
+            text: text.clone(),
+            plain_text,
+        })
+    }
+
+    /// Does this posting satisfy the given filters?
+    ///
+    /// `is_seen` tells if the posting has already been marked as seen.
+    pub fn matches(&self, filters: &JobPostingsFilters, is_seen: bool) -> bool {
+        if filters.hide_seen && is_seen {
+            return false;
+        }
+        if filters.remote_only && !self.header.as_ref().is_some_and(|header| header.is_remote) {
+            return false;
+        }
+        if !filters.location.is_empty() {
+            // fallback on the whole header line for postings not following the convention
+            let location = self
+                .header
+                .as_ref()
+                .and_then(|header| header.location.as_deref())
+                .unwrap_or(&self.header_line);
+            if !contains_ignore_case(location, &filters.location) {
+                return false;
+            }
+        }
+        filters.keyword.is_empty() || contains_ignore_case(&self.plain_text, &filters.keyword)
+    }
+}
+
+impl ItemWithId<HnItemIdScalar> for DisplayableJobPosting {
+    fn get_id(&self) -> HnItemIdScalar {
+        self.id
+    }
+}
+
+/// Filters applicable to the "Who is hiring?" postings.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JobPostingsFilters {
+    /// Only keep the postings mentioning remote work.
+    pub remote_only: bool,
+    /// Only keep the postings not yet marked as seen.
+    pub hide_seen: bool,
+    /// Case-insensitive location filter, ignored if empty.
+    pub location: String,
+    /// Case-insensitive keyword filter on the whole posting, ignored if empty.
+    pub keyword: String,
+}
+
+/// The text filters of the "Who is hiring?" postings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobPostingsFilterField {
+    Location,
+    Keyword,
+}
+
+impl JobPostingsFilterField {
+    pub fn get_label(&self) -> &str {
+        match self {
+            Self::Location => "Location",
+            Self::Keyword => "Keyword",
+        }
+    }
+}
+
+impl JobPostingsFilters {
+    /// Get the value of the given text filter.
+    pub fn get_text(&self, field: JobPostingsFilterField) -> &str {
+        match field {
+            JobPostingsFilterField::Location => &self.location,
+            JobPostingsFilterField::Keyword => &self.keyword,
+        }
+    }
+
+    /// Set the value of the given text filter.
+    pub fn set_text(&mut self, field: JobPostingsFilterField, value: String) {
+        match field {
+            JobPostingsFilterField::Location => self.location = value,
+            JobPostingsFilterField::Keyword => self.keyword = value,
+        }
+    }
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// Strip the tags and decode the few HTML entities used by Hacker News, on a single line.
+fn html_fragment_to_plain_text(html: &str) -> String {
+    let mut stripped = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => (),
+        }
+    }
+    stripped
+        .replace("&#x2F;", "/")
+        .replace("&#x27;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DisplayableJobPosting, JobPostingHeader, JobPostingsFilters};
+    use crate::ui::displayable_item::DisplayableHackerNewsItem;
+
+    #[test]
+    fn test_job_posting_header_parsing() {
+        assert_eq!(
+            JobPostingHeader::parse("Acme Corp | Senior Rust Engineer | Berlin, Germany | REMOTE"),
+            Some(JobPostingHeader {
+                company: "Acme Corp".into(),
+                role: Some("Senior Rust Engineer".into()),
+                location: Some("Berlin, Germany".into()),
+                is_remote: true,
+            })
+        );
+        assert_eq!(
+            JobPostingHeader::parse("Foo | Backend | REMOTE"),
+            Some(JobPostingHeader {
+                company: "Foo".into(),
+                role: Some("Backend".into()),
+                location: None,
+                is_remote: true,
+            })
+        );
+        assert!(
+            !JobPostingHeader::parse("Bar | SRE | NYC | Onsite, no remote")
+                .unwrap()
+                .is_remote
+        );
+        assert_eq!(JobPostingHeader::parse("We are hiring, email me!"), None);
+    }
+
+    #[test]
+    fn test_job_posting_from_comment_and_filters() {
+        let posting = DisplayableJobPosting::from_comment(&DisplayableHackerNewsItem {
+            text: Some(
+                "Acme &amp; Co | Rust Engineer | Paris | REMOTE (EU)<p>We use <i>Rust</i> and Postgres."
+                    .into(),
+            ),
+            parent: Some(1),
+            ..DisplayableHackerNewsItem::test_item(42)
+        })
+        .unwrap();
+        assert_eq!(
+            posting.header_line,
+            "Acme & Co | Rust Engineer | Paris | REMOTE (EU)"
+        );
+        assert_eq!(posting.header.as_ref().unwrap().company, "Acme & Co");
+
+        let mut filters = JobPostingsFilters::default();
+        assert!(posting.matches(&filters, false));
+        filters.remote_only = true;
+        filters.location = "paris".into();
+        filters.keyword = "postgres".into();
+        assert!(posting.matches(&filters, false));
+        filters.hide_seen = true;
+        assert!(!posting.matches(&filters, true));
+        filters.hide_seen = false;
+        filters.location = "London".into();
+        assert!(!posting.matches(&filters, false));
+        filters.location.clear();
+        filters.keyword = "golang".into();
+        assert!(!posting.matches(&filters, false));
+
+        // the keywords apply to the text, not to the markup
+        filters.remote_only = false;
+        for keyword in ["use rust and", "& co"] {
+            filters.keyword = keyword.into();
+            assert!(posting.matches(&filters, false), "{keyword}");
+        }
+        for keyword in ["<i>", "amp", "p>"] {
+            filters.keyword = keyword.into();
+            assert!(!posting.matches(&filters, false), "{keyword}");
+        }
+    }
+}
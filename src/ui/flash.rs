@@ -1,9 +1,6 @@
 use ratatui::{
     layout::{HorizontalAlignment, Rect},
-    style::{
-        Color::{self, Blue, Red, Yellow},
-        Style,
-    },
+    style::{Color, Style},
     text::Line,
     widgets::Paragraph,
 };
@@ -11,7 +8,7 @@ use std::cmp;
 
 use crate::ui::{UI_TICK_RATE_MS, common::UiTickScalar};
 
-use super::common::RenderFrame;
+use super::{common::RenderFrame, theme::UiThemePalette};
 
 pub type FlashMessageDurationType = UiTickScalar;
 
@@ -19,21 +16,21 @@ pub const FLASH_MESSAGE_DEFAULT_DURATION_MS: FlashMessageDurationType = 4000;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FlashMessageType {
-    /// Info, to be displayed in blue.
+    /// Info, to be displayed with the theme info color.
     Info,
-    /// Warning, to be displayed in yellow.
+    /// Warning, to be displayed with the theme warning color.
     Warning,
-    /// Error, to be displayed in red.
+    /// Error, to be displayed with the theme error color.
     Error,
 }
 
 impl FlashMessageType {
-    fn to_color(self) -> Color {
+    fn to_color(self, theme: &UiThemePalette) -> Color {
         use FlashMessageType::*;
         match self {
-            Info => Blue,
-            Warning => Yellow,
-            Error => Red,
+            Info => theme.get_info_color(),
+            Warning => theme.get_warning_color(),
+            Error => theme.get_error_color(),
         }
     }
 }
@@ -41,8 +38,7 @@ impl FlashMessageType {
 /// Global flash message renderer.
 #[derive(Debug)]
 pub struct FlashMessage {
-    /// Cached instead of the message type for the rendering function.
-    color: Color,
+    message_type: FlashMessageType,
     message: String,
     starting_duration_ms: FlashMessageDurationType,
     spent_duration_ms: FlashMessageDurationType,
@@ -51,7 +47,7 @@ pub struct FlashMessage {
 impl Default for FlashMessage {
     fn default() -> Self {
         Self {
-            color: FlashMessageType::Info.to_color(),
+            message_type: FlashMessageType::Info,
             message: "".into(),
             starting_duration_ms: FLASH_MESSAGE_DEFAULT_DURATION_MS,
             spent_duration_ms: 0,
@@ -66,7 +62,7 @@ impl FlashMessage {
         duration_ms: FlashMessageDurationType,
     ) -> Self {
         Self {
-            color: message_type.to_color(),
+            message_type,
             message: message.into(),
             starting_duration_ms: duration_ms,
             spent_duration_ms: 0,
@@ -86,11 +82,11 @@ impl FlashMessage {
         self.spent_duration_ms < self.starting_duration_ms
     }
 
-    pub fn render(&self, f: &mut RenderFrame, inside: Rect) {
+    pub fn render(&self, f: &mut RenderFrame, inside: Rect, theme: &UiThemePalette) {
         let text = vec![Line::from(self.message.clone())];
         let paragraph = Paragraph::new(text)
             .alignment(HorizontalAlignment::Center)
-            .style(Style::default().fg(self.color));
+            .style(Style::default().fg(self.message_type.to_color(theme)));
         f.render_widget(paragraph, inside);
     }
 
@@ -107,7 +103,7 @@ mod tests {
     #[test]
     fn test_flash_default() {
         let flash_message = FlashMessage::default();
-        assert_eq!(flash_message.color, FlashMessageType::Info.to_color());
+        assert_eq!(flash_message.message_type, FlashMessageType::Info);
         assert_eq!(
             flash_message.starting_duration_ms,
             FLASH_MESSAGE_DEFAULT_DURATION_MS
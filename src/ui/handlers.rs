@@ -1,12 +1,17 @@
+use std::time::{Duration, Instant};
+
 use crossterm::event::{self, KeyModifiers};
-use event::{KeyCode, KeyEvent};
+use event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
 
 use crate::app::state::AppState;
 
+use super::keybindings::KeyBindings;
+
 /// Abstraction over a key event.
 ///
 /// Used to abstract over tui's backend, and to facilitate user configuration.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Key {
     None,
     /// Escape key.
@@ -25,6 +30,14 @@ pub enum Key {
     Left,
     /// Right arrow.
     Right,
+    /// Page Up key.
+    PageUp,
+    /// Page Down key.
+    PageDown,
+    /// Home key.
+    Home,
+    /// End key.
+    End,
     /// Keyboard character.
     Char(char),
     /// Unhandled.
@@ -46,6 +59,11 @@ impl Key {
             Down => "⬇️ (down)".into(),
             Left => "⬅️ (left)".into(),
             Right => "➡️ (right)".into(),
+            PageUp => "⇞ (page up)".into(),
+            PageDown => "⇟ (page down)".into(),
+            Home => "⇱ (home)".into(),
+            End => "⇲ (end)".into(),
+            Char(' ') => "␣ (space)".into(),
             Char(char) => format!("'{char}'"),
             Other => unreachable!(),
             None => unreachable!(),
@@ -66,77 +84,176 @@ impl From<KeyEvent> for Key {
             KeyCode::Down => Down,
             KeyCode::Left => Left,
             KeyCode::Right => Right,
+            KeyCode::PageUp => PageUp,
+            KeyCode::PageDown => PageDown,
+            KeyCode::Home => Home,
+            KeyCode::End => End,
             KeyCode::Char(c) => Char(c),
             _ => Other,
         }
     }
 }
 
+/// Kind of a (supported) mouse event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseInputKind {
+    /// Left button pressed.
+    Click,
+    /// Left button pressed a second time in a row, at the same position.
+    DoubleClick,
+    ScrollUp,
+    ScrollDown,
+}
+
+/// Abstraction over a mouse event, at a given position of the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MouseInput {
+    pub kind: MouseInputKind,
+    pub column: u16,
+    pub row: u16,
+}
+
+impl MouseInput {
+    pub fn is_click(&self) -> bool {
+        matches!(
+            self.kind,
+            MouseInputKind::Click | MouseInputKind::DoubleClick
+        )
+    }
+
+    pub fn is_inside(&self, area: Rect) -> bool {
+        area.contains(Position::new(self.column, self.row))
+    }
+}
+
+/// Maximum delay between the two clicks of a double-click.
+const DOUBLE_CLICK_MAX_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Abstraction over a key event modifier.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum KeyModifier {
     None,
     Shift,
     Control,
 }
 
-#[derive(Debug)]
-pub enum ApplicationAction {
+/// Avoid boilerplate when adding application actions, each one being named
+/// in the `[keybindings]` table of the user configuration.
+macro_rules! define_application_actions {
+    ($ ( $( #[$group: meta] )* $action: ident => $config_name: literal, )* ) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum ApplicationAction {
+            $( $( #[$group] )* $action, )*
+        }
+
+        impl ApplicationAction {
+            /// All the application actions, in declaration order.
+            pub const ALL: &'static [ApplicationAction] = &[$( Self::$action, )*];
+
+            /// Name of the action in the `[keybindings]` configuration table.
+            pub fn get_config_name(&self) -> &'static str {
+                match self {
+                    $( Self::$action => $config_name, )*
+                }
+            }
+
+            pub fn from_config_name(name: &str) -> Option<Self> {
+                match name {
+                    $( $config_name => Some(Self::$action), )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+define_application_actions! {
     // general
-    OpenExternalOrHackerNewsLink,
-    OpenHackerNewsLink,
-    SelectItem,
-    ToggleHelp,
-    Back,
-    Quit,
-    QuitShortcut,
+    OpenExternalOrHackerNewsLink => "open_external_or_hacker_news_link",
+    OpenHackerNewsLink => "open_hacker_news_link",
+    SelectItem => "select_item",
+    ToggleHelp => "toggle_help",
+    Back => "back",
+    Quit => "quit",
+    QuitShortcut => "quit_shortcut",
     // navigation
-    NavigateUp,
-    NavigateDown,
-    NavigateLeft,
-    NavigateRight,
+    NavigateUp => "navigate_up",
+    NavigateDown => "navigate_down",
+    NavigateLeft => "navigate_left",
+    NavigateRight => "navigate_right",
+    NavigatePageUp => "navigate_page_up",
+    NavigatePageDown => "navigate_page_down",
+    NavigateHalfPageUp => "navigate_half_page_up",
+    NavigateHalfPageDown => "navigate_half_page_down",
+    NavigateFirst => "navigate_first",
+    NavigateLast => "navigate_last",
     // input
-    InputSetCursor,
-    InputInsertCharacter,
-    InputGoToPreviousCharacter,
-    InputGoToNextCharacter,
-    InputGoToStart,
-    InputGoToEnd,
-    InputDeletePreviousCharacter,
-    InputDeleteBeforeCursor,
-    InputDeleteAfterCursor,
+    InputSetCursor => "input_set_cursor",
+    InputInsertCharacter => "input_insert_character",
+    InputGoToPreviousCharacter => "input_go_to_previous_character",
+    InputGoToNextCharacter => "input_go_to_next_character",
+    InputGoToStart => "input_go_to_start",
+    InputGoToEnd => "input_go_to_end",
+    InputDeletePreviousCharacter => "input_delete_previous_character",
+    InputDeleteBeforeCursor => "input_delete_before_cursor",
+    InputDeleteAfterCursor => "input_delete_after_cursor",
     // home screen
-    HomeToggleSortingOption,
+    HomeToggleSortingOption => "home_toggle_sorting_option",
+    // stories lists
+    StoriesToggleHideRead => "stories_toggle_hide_read",
+    StoriesToggleHidden => "stories_toggle_hidden",
+    ToggleRevealHidden => "toggle_reveal_hidden",
+    StoriesEditFilter => "stories_edit_filter",
+    // past front page screen
+    PastFrontPagePreviousDay => "past_front_page_previous_day",
+    PastFrontPageNextDay => "past_front_page_next_day",
+    PastFrontPageEditDay => "past_front_page_edit_day",
+    // who is hiring screen
+    WhoIsHiringToggleRemoteOnly => "who_is_hiring_toggle_remote_only",
+    WhoIsHiringToggleHideSeen => "who_is_hiring_toggle_hide_seen",
+    WhoIsHiringEditLocation => "who_is_hiring_edit_location",
+    WhoIsHiringEditKeyword => "who_is_hiring_edit_keyword",
+    WhoIsHiringToggleSeen => "who_is_hiring_toggle_seen",
+    // browsing history screen
+    BrowsingHistoryEditFilter => "browsing_history_edit_filter",
+    // bookmarks
+    ToggleBookmark => "toggle_bookmark",
+    FocusedCommentToggleBookmark => "focused_comment_toggle_bookmark",
+    // watched stories
+    ToggleWatch => "toggle_watch",
+    // bookmarks screen
+    BookmarksEditTags => "bookmarks_edit_tags",
+    BookmarksEditNotes => "bookmarks_edit_notes",
+    BookmarksRemove => "bookmarks_remove",
+    BookmarksCycleTagFilter => "bookmarks_cycle_tag_filter",
+    BookmarksToggleSorting => "bookmarks_toggle_sorting",
     // item screen
-    ItemToggleComments,
-    ItemExpandFocusedComment,
-    FocusedCommentViewUserProfile,
+    ItemToggleComments => "item_toggle_comments",
+    ItemExpandFocusedComment => "item_expand_focused_comment",
+    FocusedCommentViewUserProfile => "focused_comment_view_user_profile",
+    ItemNextNewComment => "item_next_new_comment",
+    ItemPreviousNewComment => "item_previous_new_comment",
+    ItemToggleCommentsTree => "item_toggle_comments_tree",
+    CommentsTreeGoToParent => "comments_tree_go_to_parent",
+    CommentsTreeNextSibling => "comments_tree_next_sibling",
+    CommentsTreeNextTopLevel => "comments_tree_next_top_level",
     // user profile screen
-    OpenHackerNewsProfile,
+    OpenHackerNewsProfile => "open_hacker_news_profile",
     // search screen
-    ToggleFocusResults,
+    ToggleFocusResults => "toggle_focus_results",
     // settings screen
-    SettingsToggleControl,
+    SettingsToggleControl => "settings_toggle_control",
+    SettingsIncreaseValue => "settings_increase_value",
+    SettingsDecreaseValue => "settings_decrease_value",
+    SettingsEditValue => "settings_edit_value",
+    SettingsResetValue => "settings_reset_value",
 }
 
 impl ApplicationAction {
     pub fn matches_event(&self, inputs: &InputsController) -> bool {
         use ApplicationAction::*;
         match self {
-            // general
-            OpenExternalOrHackerNewsLink => inputs.key == Key::Char('o'),
-            OpenHackerNewsLink => inputs.key == Key::Char('l'),
-            SelectItem => inputs.key == Key::Enter,
-            ToggleHelp => inputs.key == Key::Char('h'),
-            Back => inputs.key == Key::Escape,
-            Quit => inputs.modifier == KeyModifier::Control && inputs.key == Key::Char('c'),
-            QuitShortcut => inputs.modifier == KeyModifier::None && inputs.key == Key::Char('q'),
-            // navigation
-            NavigateUp => inputs.key == Key::Up,
-            NavigateDown => inputs.key == Key::Down,
-            NavigateLeft => inputs.key == Key::Left,
-            NavigateRight => inputs.key == Key::Right,
-            // input
+            // not configurable since depending on the typed-in character
             InputSetCursor => {
                 inputs.modifier == KeyModifier::None
                     && (inputs.key == Key::Left || inputs.key == Key::Right)
@@ -144,34 +261,31 @@ impl ApplicationAction {
             InputInsertCharacter => {
                 inputs.modifier == KeyModifier::None && Self::is_key_char(&inputs.key)
             }
-            InputGoToPreviousCharacter => inputs.key == Key::Left,
-            InputGoToNextCharacter => inputs.key == Key::Right,
-            InputGoToStart => {
-                inputs.modifier == KeyModifier::Control && inputs.key == Key::Char('a')
-            }
-            InputGoToEnd => inputs.modifier == KeyModifier::Control && inputs.key == Key::Char('e'),
-            InputDeletePreviousCharacter => inputs.key == Key::Backspace,
-            InputDeleteBeforeCursor => {
-                inputs.modifier == KeyModifier::Control && inputs.key == Key::Char('u')
+            // the mouse wheel navigates like the arrow keys
+            NavigateUp | NavigateDown if inputs.mouse_input.is_some() => {
+                let scroll_kind = if *self == NavigateUp {
+                    MouseInputKind::ScrollUp
+                } else {
+                    MouseInputKind::ScrollDown
+                };
+                inputs
+                    .mouse_input
+                    .is_some_and(|mouse_input| mouse_input.kind == scroll_kind)
             }
-            InputDeleteAfterCursor => {
-                inputs.modifier == KeyModifier::Control && inputs.key == Key::Char('k')
-            }
-            // home screen
-            HomeToggleSortingOption => inputs.key == Key::Char('s'),
-            // item screen
-            ItemToggleComments => inputs.key == Key::Tab,
-            ItemExpandFocusedComment => inputs.key == Key::Enter,
-            FocusedCommentViewUserProfile => inputs.key == Key::Char('p'),
-            // user profile screen
-            OpenHackerNewsProfile => inputs.key == Key::Char('o'),
-            // search screen
-            ToggleFocusResults => inputs.key == Key::Enter,
-            // settings screen
-            SettingsToggleControl => inputs.key == Key::Tab,
+            action => inputs
+                .key_bindings
+                .matches(action, &inputs.modifier, &inputs.key),
         }
     }
 
+    /// Can the key bindings of the action be changed in the configuration?
+    pub fn is_configurable(&self) -> bool {
+        !matches!(
+            self,
+            ApplicationAction::InputSetCursor | ApplicationAction::InputInsertCharacter
+        )
+    }
+
     fn is_key_char(key: &Key) -> bool {
         matches!(key, Key::Char(_))
     }
@@ -186,18 +300,40 @@ pub struct InputsController {
     modifier: KeyModifier,
     active_input_key: Key,
     active_input_mode: bool,
+    /// Effective key bindings, from the user configuration.
+    key_bindings: KeyBindings,
+    /// The latest event, if it was a supported mouse one.
+    mouse_input: Option<MouseInput>,
+    /// Time and position of the latest single click, to detect double-clicks.
+    latest_click: Option<(Instant, u16, u16)>,
 }
 
 impl InputsController {
-    pub fn new() -> Self {
+    pub fn new(key_bindings: KeyBindings) -> Self {
         Self {
             key: Key::None,
             modifier: KeyModifier::None,
             active_input_key: Key::None,
             active_input_mode: true,
+            key_bindings,
+            mouse_input: None,
+            latest_click: None,
         }
     }
 
+    pub fn get_key_bindings(&self) -> &KeyBindings {
+        &self.key_bindings
+    }
+
+    pub fn set_key_bindings(&mut self, key_bindings: KeyBindings) {
+        self.key_bindings = key_bindings;
+    }
+
+    /// Get the displayable key binding(s) of the given action, for help purposes.
+    pub fn get_key_bindings_representation(&self, action: &ApplicationAction) -> String {
+        self.key_bindings.get_representation(action)
+    }
+
     pub fn pump_event(&mut self, event: KeyEvent, _state: &AppState) {
         if event.modifiers.contains(KeyModifiers::CONTROL) {
             self.modifier = KeyModifier::Control;
@@ -215,6 +351,41 @@ impl InputsController {
         } else {
             Key::None
         };
+        self.mouse_input = None;
+    }
+
+    /// Pump a mouse event, returning false if not supported (*e.g.* mouse moves), in which case it is ignored.
+    pub fn pump_mouse_event(&mut self, event: MouseEvent) -> bool {
+        let (column, row) = (event.column, event.row);
+        let kind = match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let now = Instant::now();
+                let is_double_click = self.latest_click.is_some_and(|(clicked_at, x, y)| {
+                    (x, y) == (column, row)
+                        && now.duration_since(clicked_at) <= DOUBLE_CLICK_MAX_INTERVAL
+                });
+                // a third click starts over
+                self.latest_click = (!is_double_click).then_some((now, column, row));
+                if is_double_click {
+                    MouseInputKind::DoubleClick
+                } else {
+                    MouseInputKind::Click
+                }
+            }
+            MouseEventKind::ScrollUp => MouseInputKind::ScrollUp,
+            MouseEventKind::ScrollDown => MouseInputKind::ScrollDown,
+            _ => return false,
+        };
+        self.key = Key::None;
+        self.modifier = KeyModifier::None;
+        self.active_input_key = Key::None;
+        self.mouse_input = Some(MouseInput { kind, column, row });
+        true
+    }
+
+    /// Get the latest event, if it was a mouse one.
+    pub fn get_mouse_input(&self) -> Option<&MouseInput> {
+        self.mouse_input.as_ref()
     }
 
     pub fn is_active(&self, action: &ApplicationAction) -> bool {
@@ -230,12 +401,54 @@ impl InputsController {
     }
 
     pub fn get_active_input_key(&self) -> Option<(Key, char)> {
-        for c in b'A'..=b'z' {
-            let key = Key::Char(c as char);
-            if key == self.active_input_key {
-                return Some((key, c as char));
-            }
+        // CTRL-based shortcuts are reserved for text editing actions
+        if self.has_ctrl_modifier() {
+            return None;
         }
-        None
+        match self.active_input_key {
+            Key::Char(c) => Some((Key::Char(c), c)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+    use crate::ui::keybindings::KeyBindings;
+
+    use super::{ApplicationAction, InputsController, MouseInputKind};
+
+    fn mouse_event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_mouse_inputs() {
+        let mut inputs = InputsController::new(KeyBindings::default());
+        let click = MouseEventKind::Down(MouseButton::Left);
+        let kind = |inputs: &InputsController| inputs.get_mouse_input().map(|input| input.kind);
+
+        assert!(inputs.pump_mouse_event(mouse_event(click, 10, 5)));
+        assert_eq!(kind(&inputs), Some(MouseInputKind::Click));
+        assert!(inputs.pump_mouse_event(mouse_event(click, 10, 5)));
+        assert_eq!(kind(&inputs), Some(MouseInputKind::DoubleClick));
+        assert!(inputs.pump_mouse_event(mouse_event(click, 10, 5)));
+        assert_eq!(kind(&inputs), Some(MouseInputKind::Click));
+        // elsewhere
+        assert!(inputs.pump_mouse_event(mouse_event(click, 11, 5)));
+        assert_eq!(kind(&inputs), Some(MouseInputKind::Click));
+        assert!(!inputs.is_active(&ApplicationAction::NavigateDown));
+
+        assert!(inputs.pump_mouse_event(mouse_event(MouseEventKind::ScrollDown, 0, 0)));
+        assert!(inputs.is_active(&ApplicationAction::NavigateDown));
+        assert!(!inputs.is_active(&ApplicationAction::NavigateUp));
+        assert!(!inputs.pump_mouse_event(mouse_event(MouseEventKind::Moved, 0, 0)));
     }
 }
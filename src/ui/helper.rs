@@ -9,7 +9,7 @@ use crate::app::state::AppState;
 use super::{
     common::RenderFrame,
     displayable_item::DisplayableHackerNewsItem,
-    handlers::{InputsController, Key},
+    handlers::{ApplicationAction, InputsController},
     router::AppRoute,
 };
 
@@ -19,20 +19,23 @@ enum HelpWidget {
     Empty,
     /// Static text.
     Text(String),
-    /// Key reminder. Structure: (icon, text, key).
-    KeyReminder(char, String, Key),
+    /// Key reminder, displaying the effective key binding(s). Structure: (icon, text, action).
+    KeyReminder(char, String, ApplicationAction),
 }
 
 impl HelpWidget {
-    pub fn render(&self, f: &mut RenderFrame, inside: Rect) {
+    pub fn render(&self, f: &mut RenderFrame, inside: Rect, app_inputs: &InputsController) {
         use HelpWidget::*;
 
         let widget_text = match self {
             Empty => "".into(),
             Text(text) => text.clone(),
-            KeyReminder(icon, text, key) => {
-                format!("{} - {} to {}", icon, key.get_representation(), text)
-            }
+            KeyReminder(icon, text, action) => format!(
+                "{} - {} to {}",
+                icon,
+                app_inputs.get_key_bindings_representation(action),
+                text
+            ),
         };
         let text = vec![Line::from(widget_text)];
         let paragraph = Paragraph::new(text).alignment(HorizontalAlignment::Center);
@@ -57,35 +60,224 @@ impl ContextualHelper {
         app_inputs: &InputsController,
     ) {
         match for_route {
-            AppRoute::Home(_) => self.render_home_page_help(f, inside, app_inputs),
+            AppRoute::Home(_) | AppRoute::SearchResults(_) => {
+                self.render_home_page_help(f, inside, app_state, app_inputs)
+            }
+            AppRoute::PastFrontPage => {
+                self.render_past_front_page_help(f, inside, app_state, app_inputs)
+            }
+            AppRoute::WhoIsHiring => {
+                self.render_who_is_hiring_page_help(f, inside, app_state, app_inputs)
+            }
+            AppRoute::BrowsingHistory => {
+                self.render_browsing_history_page_help(f, inside, app_state, app_inputs)
+            }
+            AppRoute::Bookmarks => {
+                self.render_bookmarks_page_help(f, inside, app_state, app_inputs)
+            }
             AppRoute::ItemDetails(item) => {
                 self.render_item_page_help(f, inside, app_state, app_inputs, item)
             }
-            AppRoute::ItemNestedComments(_) => self.render_comments_page_help(f, inside),
-            AppRoute::UserProfile(_) => self.render_user_page_help(f, inside),
-            AppRoute::SearchHelp => self.render_search_page_help(f, inside),
+            AppRoute::ItemNestedComments(_) => {
+                self.render_comments_page_help(f, inside, app_inputs)
+            }
+            AppRoute::UserProfile(_) => self.render_user_page_help(f, inside, app_inputs),
+            AppRoute::SearchHelp => self.render_search_page_help(f, inside, app_inputs),
             // AppRoute::Search => self.render_search_page_help(f, inside),
-            AppRoute::Settings => self.render_settings_page_help(f, inside),
-            AppRoute::Help => self.render_help_page_help(f, inside),
+            AppRoute::Settings => self.render_settings_page_help(f, inside, app_inputs),
+            AppRoute::Help => self.render_help_page_help(f, inside, app_inputs),
         }
     }
 
+    /// Widgets displayed while typing in the stories filter.
+    fn get_stories_filter_input_widgets() -> Vec<HelpWidget> {
+        vec![
+            HelpWidget::KeyReminder(
+                '✅',
+                "keep the matching stories".into(),
+                ApplicationAction::SelectItem,
+            ),
+            HelpWidget::KeyReminder('⬅', "clear the filter".into(), ApplicationAction::Back),
+        ]
+    }
+
     fn render_home_page_help(
         &self,
         f: &mut RenderFrame,
         inside: Rect,
+        app_state: &AppState,
         app_inputs: &InputsController,
     ) {
+        if app_state.get_main_stories_filter_input().is_some() {
+            let widgets = Self::get_stories_filter_input_widgets();
+            Self::render_widgets(f, inside, &widgets, app_inputs);
+            return;
+        }
+
         let widgets = vec![
-            HelpWidget::KeyReminder('💡', "toggle help".into(), Key::Char('h')),
-            HelpWidget::Text("🌐 'l' or 'o' to open HN/item link".into()),
+            HelpWidget::KeyReminder('💡', "toggle help".into(), ApplicationAction::ToggleHelp),
+            HelpWidget::Text(format!(
+                "🌐 {} or {} to open HN/item link",
+                app_inputs.get_key_bindings_representation(&ApplicationAction::OpenHackerNewsLink),
+                app_inputs.get_key_bindings_representation(
+                    &ApplicationAction::OpenExternalOrHackerNewsLink
+                ),
+            )),
+            HelpWidget::KeyReminder(
+                '👀',
+                "hide read".into(),
+                ApplicationAction::StoriesToggleHideRead,
+            ),
+            HelpWidget::KeyReminder(
+                '🙈',
+                "hide story".into(),
+                ApplicationAction::StoriesToggleHidden,
+            ),
+            HelpWidget::KeyReminder('🔎', "filter".into(), ApplicationAction::StoriesEditFilter),
             if app_inputs.has_ctrl_modifier() {
-                HelpWidget::Text("❌ - CTRL + 'c' to quit".into())
+                HelpWidget::KeyReminder('❌', "quit".into(), ApplicationAction::Quit)
             } else {
-                HelpWidget::KeyReminder('❌', "quit".into(), Key::Char('q'))
+                HelpWidget::KeyReminder('❌', "quit".into(), ApplicationAction::QuitShortcut)
             },
         ];
-        Self::render_widgets(f, inside, &widgets);
+        Self::render_widgets(f, inside, &widgets, app_inputs);
+    }
+
+    fn render_past_front_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_state: &AppState,
+        app_inputs: &InputsController,
+    ) {
+        let widgets = if app_state.get_main_stories_filter_input().is_some() {
+            Self::get_stories_filter_input_widgets()
+        } else if app_state.get_past_front_page_day_input().is_some() {
+            vec![
+                HelpWidget::KeyReminder(
+                    '✅',
+                    "apply the day".into(),
+                    ApplicationAction::SelectItem,
+                ),
+                HelpWidget::KeyReminder('⬅', "cancel".into(), ApplicationAction::Back),
+            ]
+        } else {
+            vec![
+                HelpWidget::Text(format!(
+                    "📅 {} or {} to browse days",
+                    app_inputs.get_key_bindings_representation(
+                        &ApplicationAction::PastFrontPagePreviousDay
+                    ),
+                    app_inputs
+                        .get_key_bindings_representation(&ApplicationAction::PastFrontPageNextDay),
+                )),
+                HelpWidget::KeyReminder(
+                    '🔎',
+                    "pick a day".into(),
+                    ApplicationAction::PastFrontPageEditDay,
+                ),
+                HelpWidget::KeyReminder('💡', "toggle help".into(), ApplicationAction::ToggleHelp),
+            ]
+        };
+        Self::render_widgets(f, inside, &widgets, app_inputs);
+    }
+
+    fn render_who_is_hiring_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_state: &AppState,
+        app_inputs: &InputsController,
+    ) {
+        let widgets = if app_state.get_who_is_hiring_filter_input().is_some() {
+            vec![
+                HelpWidget::KeyReminder(
+                    '✅',
+                    "apply the filter".into(),
+                    ApplicationAction::SelectItem,
+                ),
+                HelpWidget::KeyReminder('⬅', "cancel".into(), ApplicationAction::Back),
+            ]
+        } else {
+            vec![
+                HelpWidget::KeyReminder(
+                    '👀',
+                    "mark as seen".into(),
+                    ApplicationAction::WhoIsHiringToggleSeen,
+                ),
+                HelpWidget::Text(format!(
+                    "🔎 {} or {} to filter by location or keyword",
+                    app_inputs.get_key_bindings_representation(
+                        &ApplicationAction::WhoIsHiringEditLocation
+                    ),
+                    app_inputs.get_key_bindings_representation(
+                        &ApplicationAction::WhoIsHiringEditKeyword
+                    ),
+                )),
+                HelpWidget::KeyReminder(
+                    '🌐',
+                    "open the posting".into(),
+                    ApplicationAction::OpenExternalOrHackerNewsLink,
+                ),
+            ]
+        };
+        Self::render_widgets(f, inside, &widgets, app_inputs);
+    }
+
+    fn render_browsing_history_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_state: &AppState,
+        app_inputs: &InputsController,
+    ) {
+        let widgets = if app_state.get_browsing_history_filter_input().is_some() {
+            vec![
+                HelpWidget::KeyReminder(
+                    '✅',
+                    "apply the filter".into(),
+                    ApplicationAction::SelectItem,
+                ),
+                HelpWidget::KeyReminder('⬅', "cancel".into(), ApplicationAction::Back),
+            ]
+        } else {
+            vec![
+                HelpWidget::KeyReminder(
+                    '🔎',
+                    "filter by title or user".into(),
+                    ApplicationAction::BrowsingHistoryEditFilter,
+                ),
+                HelpWidget::KeyReminder('↩', "open back".into(), ApplicationAction::SelectItem),
+                HelpWidget::KeyReminder('⬅', "go back".into(), ApplicationAction::Back),
+            ]
+        };
+        Self::render_widgets(f, inside, &widgets, app_inputs);
+    }
+
+    fn render_bookmarks_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_state: &AppState,
+        app_inputs: &InputsController,
+    ) {
+        let widgets = if app_state.get_bookmark_input().is_some() {
+            vec![
+                HelpWidget::KeyReminder('✅', "save".into(), ApplicationAction::SelectItem),
+                HelpWidget::KeyReminder('⬅', "cancel".into(), ApplicationAction::Back),
+            ]
+        } else {
+            vec![
+                HelpWidget::KeyReminder('↩', "open back".into(), ApplicationAction::SelectItem),
+                HelpWidget::KeyReminder(
+                    '🏷',
+                    "filter by tag".into(),
+                    ApplicationAction::BookmarksCycleTagFilter,
+                ),
+                HelpWidget::KeyReminder('🗑', "remove".into(), ApplicationAction::BookmarksRemove),
+            ]
+        };
+        Self::render_widgets(f, inside, &widgets, app_inputs);
     }
 
     fn render_item_page_help(
@@ -96,8 +288,11 @@ impl ContextualHelper {
         app_inputs: &InputsController,
         item: &DisplayableHackerNewsItem,
     ) {
-        let widget_open_hn_link =
-            HelpWidget::Text("🌐 - SHIFT + 'o' to open the item Hacker News page".into());
+        let widget_open_hn_link = HelpWidget::KeyReminder(
+            '🌐',
+            "open the item Hacker News page".into(),
+            ApplicationAction::OpenHackerNewsLink,
+        );
 
         let has_widget_toggle_comments = !app_state
             .get_currently_viewed_item()
@@ -112,7 +307,11 @@ impl ContextualHelper {
             widgets.push(if app_inputs.has_shift_modifier() {
                 widget_open_hn_link
             } else {
-                HelpWidget::KeyReminder('🌐', format!("open {hostname}"), Key::Char('o'))
+                HelpWidget::KeyReminder(
+                    '🌐',
+                    format!("open {hostname}"),
+                    ApplicationAction::OpenExternalOrHackerNewsLink,
+                )
             });
         } else {
             widgets.push(widget_open_hn_link);
@@ -128,73 +327,164 @@ impl ContextualHelper {
                     "show comments"
                 })
                 .into(),
-                Key::Tab,
+                ApplicationAction::ItemToggleComments,
             ));
         }
 
         // focus comment widget, if applicable
-        if display_comments_panel {
+        if display_comments_panel && app_state.get_item_page_comments_tree_view() {
+            widgets.push(HelpWidget::KeyReminder(
+                '🌲',
+                "collapse/expand".into(),
+                ApplicationAction::ItemExpandFocusedComment,
+            ));
+            widgets.push(HelpWidget::KeyReminder(
+                '⤴',
+                "parent".into(),
+                ApplicationAction::CommentsTreeGoToParent,
+            ));
+            widgets.push(HelpWidget::KeyReminder(
+                '💬',
+                "classic view".into(),
+                ApplicationAction::ItemToggleCommentsTree,
+            ));
+        } else if display_comments_panel {
             widgets.push(HelpWidget::KeyReminder(
                 '🎯',
                 "focus comment".into(),
-                Key::Enter,
+                ApplicationAction::ItemExpandFocusedComment,
             ));
             widgets.push(HelpWidget::KeyReminder(
                 '👤',
                 "user profile".into(),
-                Key::Char('p'),
+                ApplicationAction::FocusedCommentViewUserProfile,
+            ));
+        } else {
+            widgets.push(HelpWidget::KeyReminder(
+                '🔖',
+                "bookmark".into(),
+                ApplicationAction::ToggleBookmark,
             ));
         }
 
         // go back widget (if there is room)
         if widgets.len() < 3 {
-            widgets.push(HelpWidget::KeyReminder('⬅', "go back".into(), Key::Escape));
+            widgets.push(HelpWidget::KeyReminder(
+                '⬅',
+                "go back".into(),
+                ApplicationAction::Back,
+            ));
         }
 
-        Self::render_widgets(f, inside, &widgets);
+        Self::render_widgets(f, inside, &widgets, app_inputs);
     }
 
-    fn render_comments_page_help(&self, f: &mut RenderFrame, inside: Rect) {
-        let widget_focus_sub_comments =
-            HelpWidget::KeyReminder('💬', "view sub-comment(s)".into(), Key::Enter);
-        let widget_go_back = HelpWidget::KeyReminder('⬅', "go back".into(), Key::Escape);
+    fn render_comments_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_inputs: &InputsController,
+    ) {
+        let widget_focus_sub_comments = HelpWidget::KeyReminder(
+            '💬',
+            "view sub-comment(s)".into(),
+            ApplicationAction::ItemExpandFocusedComment,
+        );
+        let widget_next_new_comment = HelpWidget::KeyReminder(
+            '🆕',
+            "next new comment".into(),
+            ApplicationAction::ItemNextNewComment,
+        );
+        let widget_bookmark = HelpWidget::KeyReminder(
+            '🔖',
+            "bookmark".into(),
+            ApplicationAction::FocusedCommentToggleBookmark,
+        );
+        let widget_go_back =
+            HelpWidget::KeyReminder('⬅', "go back".into(), ApplicationAction::Back);
 
-        let widgets = vec![widget_focus_sub_comments, widget_go_back];
-        Self::render_widgets(f, inside, &widgets);
+        let widgets = vec![
+            widget_focus_sub_comments,
+            widget_next_new_comment,
+            widget_bookmark,
+            widget_go_back,
+        ];
+        Self::render_widgets(f, inside, &widgets, app_inputs);
     }
 
-    fn render_user_page_help(&self, f: &mut RenderFrame, inside: Rect) {
-        let widget_open_profile_page =
-            HelpWidget::KeyReminder('🌐', "open the profile page".into(), Key::Char('o'));
-        let widget_go_back = HelpWidget::KeyReminder('⬅', "go back".into(), Key::Escape);
+    fn render_user_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_inputs: &InputsController,
+    ) {
+        let widget_open_profile_page = HelpWidget::KeyReminder(
+            '🌐',
+            "open the profile page".into(),
+            ApplicationAction::OpenHackerNewsProfile,
+        );
+        let widget_go_back =
+            HelpWidget::KeyReminder('⬅', "go back".into(), ApplicationAction::Back);
 
         let widgets = vec![widget_open_profile_page, widget_go_back];
-        Self::render_widgets(f, inside, &widgets);
+        Self::render_widgets(f, inside, &widgets, app_inputs);
     }
 
-    fn render_search_page_help(&self, f: &mut RenderFrame, inside: Rect) {
+    fn render_search_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_inputs: &InputsController,
+    ) {
         let widgets = vec![HelpWidget::Empty];
-        Self::render_widgets(f, inside, &widgets);
+        Self::render_widgets(f, inside, &widgets, app_inputs);
     }
 
-    fn render_settings_page_help(&self, f: &mut RenderFrame, inside: Rect) {
+    fn render_settings_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_inputs: &InputsController,
+    ) {
         let widgets = vec![
-            HelpWidget::Text("⬆️  / i or ⬇️  / k to navigate".into()),
-            HelpWidget::KeyReminder('✅', "toggle setting".into(), Key::Tab),
-            HelpWidget::KeyReminder('⬅', "go back".into(), Key::Escape),
+            HelpWidget::Text(format!(
+                "⬆️  {} or ⬇️  {} to navigate",
+                app_inputs.get_key_bindings_representation(&ApplicationAction::NavigateUp),
+                app_inputs.get_key_bindings_representation(&ApplicationAction::NavigateDown),
+            )),
+            HelpWidget::Text(format!(
+                "✅ {} or {} to change",
+                app_inputs
+                    .get_key_bindings_representation(&ApplicationAction::SettingsDecreaseValue),
+                app_inputs
+                    .get_key_bindings_representation(&ApplicationAction::SettingsIncreaseValue),
+            )),
+            HelpWidget::KeyReminder('✏', "edit".into(), ApplicationAction::SettingsEditValue),
+            HelpWidget::KeyReminder('↺', "reset".into(), ApplicationAction::SettingsResetValue),
+            HelpWidget::KeyReminder('⬅', "go back".into(), ApplicationAction::Back),
         ];
-        Self::render_widgets(f, inside, &widgets);
+        Self::render_widgets(f, inside, &widgets, app_inputs);
     }
 
-    fn render_help_page_help(&self, f: &mut RenderFrame, inside: Rect) {
+    fn render_help_page_help(
+        &self,
+        f: &mut RenderFrame,
+        inside: Rect,
+        app_inputs: &InputsController,
+    ) {
         let widgets = vec![
-            HelpWidget::KeyReminder('💡', "toggle help".into(), Key::Char('h')),
-            HelpWidget::KeyReminder('⬅', "go back".into(), Key::Escape),
+            HelpWidget::KeyReminder('💡', "toggle help".into(), ApplicationAction::ToggleHelp),
+            HelpWidget::KeyReminder('⬅', "go back".into(), ApplicationAction::Back),
         ];
-        Self::render_widgets(f, inside, &widgets);
+        Self::render_widgets(f, inside, &widgets, app_inputs);
     }
 
-    fn render_widgets(f: &mut RenderFrame, inside: Rect, widgets: &[HelpWidget]) {
+    fn render_widgets(
+        f: &mut RenderFrame,
+        inside: Rect,
+        widgets: &[HelpWidget],
+        app_inputs: &InputsController,
+    ) {
         // automatic layout
         assert!(!widgets.is_empty());
         let width_percentage = 100 / widgets.len() as u16;
@@ -208,7 +498,7 @@ impl ContextualHelper {
 
         // widgets rendering
         for (i, widget) in widgets.iter().enumerate() {
-            widget.render(f, chunks[i]);
+            widget.render(f, chunks[i], app_inputs);
         }
     }
 }
@@ -0,0 +1,687 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{HnCliError, Result};
+
+use super::handlers::{ApplicationAction, Key, KeyModifier};
+
+/// A single key binding, *e.g.* "ctrl+c" or "enter".
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    modifier: KeyModifier,
+    key: Key,
+}
+
+impl KeyBinding {
+    /// Does the binding match the given key event?
+    ///
+    /// The Shift modifier is already reflected in the case of characters, and is thus ignored for them.
+    pub fn matches(&self, modifier: &KeyModifier, key: &Key) -> bool {
+        let modifier = match (key, modifier) {
+            (Key::Char(_), KeyModifier::Shift) => &KeyModifier::None,
+            _ => modifier,
+        };
+        &self.key == key && &self.modifier == modifier
+    }
+
+    /// Is the binding a plain character, *i.e.* something that can be typed in a text input?
+    fn is_plain_character(&self) -> bool {
+        self.modifier == KeyModifier::None && matches!(self.key, Key::Char(_))
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = HnCliError;
+
+    /// Parse a key specification, for instance "ctrl+u", "shift+tab", "esc" or "L".
+    fn from_str(spec: &str) -> Result<Self> {
+        let invalid = || HnCliError::KeyBindingsError(format!("invalid key \"{spec}\""));
+
+        let (modifiers, key) = match spec.rsplit_once('+') {
+            // "+" itself, or a trailing "+" as in "ctrl++"
+            Some(("", "")) => ("", "+"),
+            Some((modifiers, "")) => (modifiers.strip_suffix('+').ok_or_else(invalid)?, "+"),
+            Some((modifiers, key)) => (modifiers, key),
+            None => ("", spec),
+        };
+        let mut modifier = KeyModifier::None;
+        for raw_modifier in modifiers.split('+').filter(|raw| !raw.is_empty()) {
+            modifier = match raw_modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifier::Control,
+                "shift" if modifier == KeyModifier::None => KeyModifier::Shift,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let mut key = match key.to_lowercase().as_str() {
+            "esc" | "escape" => Key::Escape,
+            "enter" | "return" => Key::Enter,
+            "backspace" => Key::Backspace,
+            "tab" => Key::Tab,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "pageup" | "pgup" => Key::PageUp,
+            "pagedown" | "pgdn" => Key::PageDown,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "space" => Key::Char(' '),
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Key::Char(c),
+                    _ => return Err(invalid()),
+                }
+            }
+        };
+        // the terminal sends uppercase characters for Shift + character
+        if let (KeyModifier::Shift, Key::Char(c)) = (&modifier, &key) {
+            key = Key::Char(c.to_ascii_uppercase());
+            modifier = KeyModifier::None;
+        }
+        // the terminal sends lowercase characters for Control + character
+        if let (KeyModifier::Control, Key::Char(c)) = (&modifier, &key) {
+            key = Key::Char(c.to_ascii_lowercase());
+        }
+
+        Ok(Self { modifier, key })
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.modifier {
+            KeyModifier::None => write!(f, "{}", self.key.get_representation()),
+            KeyModifier::Shift => write!(f, "SHIFT + {}", self.key.get_representation()),
+            KeyModifier::Control => write!(f, "CTRL + {}", self.key.get_representation()),
+        }
+    }
+}
+
+/// The built-in key bindings sets, on top of which the user configuration applies.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyBindingsPreset {
+    #[default]
+    Default,
+    /// hjkl navigation.
+    Vim,
+    /// CTRL-based navigation and editing.
+    Emacs,
+}
+
+impl KeyBindingsPreset {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Vim => "Vim",
+            Self::Emacs => "Emacs",
+        }
+    }
+}
+
+/// Default key bindings, of the form: (action, key specifications).
+const DEFAULT_KEY_BINDINGS: &[(ApplicationAction, &[&str])] = {
+    use ApplicationAction::*;
+    &[
+        // general
+        (OpenExternalOrHackerNewsLink, &["o"]),
+        (OpenHackerNewsLink, &["l"]),
+        (SelectItem, &["enter"]),
+        (ToggleHelp, &["h"]),
+        (Back, &["esc"]),
+        (Quit, &["ctrl+c"]),
+        (QuitShortcut, &["q"]),
+        // navigation
+        (NavigateUp, &["up"]),
+        (NavigateDown, &["down"]),
+        (NavigateLeft, &["left"]),
+        (NavigateRight, &["right"]),
+        (NavigatePageUp, &["pageup"]),
+        (NavigatePageDown, &["pagedown"]),
+        (NavigateHalfPageUp, &["ctrl+u"]),
+        (NavigateHalfPageDown, &["ctrl+d"]),
+        (NavigateFirst, &["home"]),
+        (NavigateLast, &["end"]),
+        // input
+        (InputGoToPreviousCharacter, &["left"]),
+        (InputGoToNextCharacter, &["right"]),
+        (InputGoToStart, &["ctrl+a"]),
+        (InputGoToEnd, &["ctrl+e"]),
+        (InputDeletePreviousCharacter, &["backspace"]),
+        (InputDeleteBeforeCursor, &["ctrl+u"]),
+        (InputDeleteAfterCursor, &["ctrl+k"]),
+        // home screen
+        (HomeToggleSortingOption, &["s"]),
+        // stories lists
+        (StoriesToggleHideRead, &["u"]),
+        (StoriesToggleHidden, &["x"]),
+        (ToggleRevealHidden, &["X"]),
+        (StoriesEditFilter, &["/"]),
+        // past front page screen
+        (PastFrontPagePreviousDay, &["["]),
+        (PastFrontPageNextDay, &["]"]),
+        (PastFrontPageEditDay, &["d"]),
+        // who is hiring screen
+        (WhoIsHiringToggleRemoteOnly, &["r"]),
+        (WhoIsHiringToggleHideSeen, &["u"]),
+        (WhoIsHiringEditLocation, &["w"]),
+        (WhoIsHiringEditKeyword, &["f"]),
+        (WhoIsHiringToggleSeen, &["m"]),
+        // browsing history screen
+        (BrowsingHistoryEditFilter, &["f"]),
+        // bookmarks
+        (ToggleBookmark, &["b"]),
+        (FocusedCommentToggleBookmark, &["B"]),
+        // watched stories
+        (ToggleWatch, &["w"]),
+        // bookmarks screen
+        (BookmarksEditTags, &["t"]),
+        (BookmarksEditNotes, &["e"]),
+        (BookmarksRemove, &["d"]),
+        (BookmarksCycleTagFilter, &["f"]),
+        (BookmarksToggleSorting, &["s"]),
+        // item screen
+        (ItemToggleComments, &["tab"]),
+        (ItemExpandFocusedComment, &["enter"]),
+        (FocusedCommentViewUserProfile, &["p"]),
+        (ItemNextNewComment, &["n"]),
+        (ItemPreviousNewComment, &["N"]),
+        (ItemToggleCommentsTree, &["t"]),
+        (CommentsTreeGoToParent, &["left"]),
+        (CommentsTreeNextSibling, &["right"]),
+        (CommentsTreeNextTopLevel, &["]"]),
+        // user profile screen
+        (OpenHackerNewsProfile, &["o"]),
+        // search screen
+        (ToggleFocusResults, &["enter"]),
+        // settings screen
+        (SettingsToggleControl, &["tab"]),
+        (SettingsIncreaseValue, &["+", "="]),
+        (SettingsDecreaseValue, &["-"]),
+        (SettingsEditValue, &["e"]),
+        (SettingsResetValue, &["r"]),
+    ]
+};
+
+/// Vim preset, applied on top of the default key bindings.
+const VIM_KEY_BINDINGS: &[(ApplicationAction, &[&str])] = {
+    use ApplicationAction::*;
+    &[
+        (NavigateUp, &["k", "up"]),
+        (NavigateDown, &["j", "down"]),
+        (NavigateLeft, &["h", "left"]),
+        (NavigateRight, &["l", "right"]),
+        (ToggleHelp, &["?"]),
+        (OpenHackerNewsLink, &["L"]),
+        (WhoIsHiringEditKeyword, &["/"]),
+        (BrowsingHistoryEditFilter, &["/"]),
+    ]
+};
+
+/// Emacs preset, applied on top of the default key bindings.
+const EMACS_KEY_BINDINGS: &[(ApplicationAction, &[&str])] = {
+    use ApplicationAction::*;
+    &[
+        (Back, &["esc", "ctrl+g"]),
+        (NavigateUp, &["ctrl+p", "up"]),
+        (NavigateDown, &["ctrl+n", "down"]),
+        (NavigateLeft, &["ctrl+b", "left"]),
+        (NavigateRight, &["ctrl+f", "right"]),
+        (InputGoToPreviousCharacter, &["ctrl+b", "left"]),
+        (InputGoToNextCharacter, &["ctrl+f", "right"]),
+        (InputDeletePreviousCharacter, &["backspace", "ctrl+h"]),
+    ]
+};
+
+/// Where an action can be triggered, used to detect conflicting key bindings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ActionContext {
+    /// Available on (almost) every screen.
+    General,
+    /// While typing in a text input.
+    TextInput,
+    Home,
+    PastFrontPage,
+    WhoIsHiring,
+    BrowsingHistory,
+    Bookmarks,
+    Item,
+    UserProfile,
+    Search,
+    Settings,
+}
+
+const SCREEN_ACTION_CONTEXTS: [ActionContext; 9] = [
+    ActionContext::Home,
+    ActionContext::PastFrontPage,
+    ActionContext::WhoIsHiring,
+    ActionContext::BrowsingHistory,
+    ActionContext::Bookmarks,
+    ActionContext::Item,
+    ActionContext::UserProfile,
+    ActionContext::Search,
+    ActionContext::Settings,
+];
+
+impl ActionContext {
+    fn of(action: &ApplicationAction) -> &'static [ActionContext] {
+        use ActionContext::*;
+        use ApplicationAction::*;
+        match action {
+            SelectItem | Back | Quit => &[General, TextInput],
+            OpenExternalOrHackerNewsLink
+            | OpenHackerNewsLink
+            | ToggleHelp
+            | QuitShortcut
+            | NavigateUp
+            | NavigateDown
+            | NavigateLeft
+            | NavigateRight
+            | NavigatePageUp
+            | NavigatePageDown
+            | NavigateHalfPageUp
+            | NavigateHalfPageDown
+            | NavigateFirst
+            | NavigateLast => &[General],
+            InputSetCursor
+            | InputInsertCharacter
+            | InputGoToPreviousCharacter
+            | InputGoToNextCharacter
+            | InputGoToStart
+            | InputGoToEnd
+            | InputDeletePreviousCharacter
+            | InputDeleteBeforeCursor
+            | InputDeleteAfterCursor => &[TextInput],
+            HomeToggleSortingOption => &[Home],
+            StoriesToggleHideRead | StoriesToggleHidden | StoriesEditFilter => {
+                &[Home, PastFrontPage]
+            }
+            ToggleRevealHidden => &[Home, PastFrontPage, Item],
+            PastFrontPagePreviousDay | PastFrontPageNextDay | PastFrontPageEditDay => {
+                &[PastFrontPage]
+            }
+            WhoIsHiringToggleRemoteOnly
+            | WhoIsHiringToggleHideSeen
+            | WhoIsHiringEditLocation
+            | WhoIsHiringEditKeyword
+            | WhoIsHiringToggleSeen => &[WhoIsHiring],
+            BrowsingHistoryEditFilter => &[BrowsingHistory],
+            ToggleBookmark | ToggleWatch => &[Home, PastFrontPage, Item],
+            FocusedCommentToggleBookmark => &[Item],
+            BookmarksEditTags
+            | BookmarksEditNotes
+            | BookmarksRemove
+            | BookmarksCycleTagFilter
+            | BookmarksToggleSorting => &[Bookmarks],
+            ItemToggleComments
+            | ItemExpandFocusedComment
+            | FocusedCommentViewUserProfile
+            | ItemNextNewComment
+            | ItemPreviousNewComment
+            | ItemToggleCommentsTree
+            | CommentsTreeGoToParent
+            | CommentsTreeNextSibling
+            | CommentsTreeNextTopLevel => &[Item],
+            OpenHackerNewsProfile => &[UserProfile],
+            ToggleFocusResults => &[Search],
+            SettingsToggleControl
+            | SettingsIncreaseValue
+            | SettingsDecreaseValue
+            | SettingsEditValue
+            | SettingsResetValue => &[Settings],
+        }
+    }
+}
+
+/// Screen-specific actions intentionally sharing their key with a general action.
+fn is_intentional_override(action: &ApplicationAction, other: &ApplicationAction) -> bool {
+    use ApplicationAction::*;
+    matches!(
+        (action, other),
+        (ItemExpandFocusedComment, SelectItem)
+            | (ToggleFocusResults, SelectItem)
+            | (OpenHackerNewsProfile, OpenExternalOrHackerNewsLink)
+            | (CommentsTreeGoToParent, NavigateLeft)
+            | (CommentsTreeNextSibling, NavigateRight)
+    )
+}
+
+/// The effective key bindings of every (configurable) application action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBindings {
+    bindings: HashMap<ApplicationAction, Vec<KeyBinding>>,
+}
+
+impl KeyBindings {
+    pub fn from_preset(preset: KeyBindingsPreset) -> Self {
+        let mut key_bindings = Self {
+            bindings: HashMap::with_capacity(ApplicationAction::ALL.len()),
+        };
+        let preset_overrides = match preset {
+            KeyBindingsPreset::Default => &[][..],
+            KeyBindingsPreset::Vim => VIM_KEY_BINDINGS,
+            KeyBindingsPreset::Emacs => EMACS_KEY_BINDINGS,
+        };
+        for (action, specs) in DEFAULT_KEY_BINDINGS.iter().chain(preset_overrides) {
+            let bindings = specs
+                .iter()
+                .map(|spec| {
+                    spec.parse()
+                        .expect("KeyBindings::from_preset: built-in key specifications are valid")
+                })
+                .collect();
+            key_bindings.bindings.insert(*action, bindings);
+        }
+        key_bindings
+    }
+
+    /// Does any binding of the given action match the given key event?
+    pub fn matches(&self, action: &ApplicationAction, modifier: &KeyModifier, key: &Key) -> bool {
+        self.get(action)
+            .iter()
+            .any(|binding| binding.matches(modifier, key))
+    }
+
+    pub fn get(&self, action: &ApplicationAction) -> &[KeyBinding] {
+        self.bindings.get(action).map_or(&[], |bindings| bindings)
+    }
+
+    /// Get the displayable key binding(s) of the given action, *e.g.* "'k' or ⬆️ (up)".
+    pub fn get_representation(&self, action: &ApplicationAction) -> String {
+        let representations: Vec<String> = self
+            .get(action)
+            .iter()
+            .map(|binding| binding.to_string())
+            .collect();
+        if representations.is_empty() {
+            "(unbound)".into()
+        } else {
+            representations.join(" or ")
+        }
+    }
+
+    /// Apply the user-defined key bindings, replacing the existing ones for each given action.
+    fn with_overrides(mut self, overrides: &BTreeMap<String, KeyBindingSpecs>) -> Result<Self> {
+        let mut errors = vec![];
+        for (action_name, specs) in overrides {
+            let action = match ApplicationAction::from_config_name(action_name) {
+                Some(action) if action.is_configurable() => action,
+                Some(_) => {
+                    errors.push(format!("action \"{action_name}\" is not configurable"));
+                    continue;
+                }
+                None => {
+                    errors.push(format!("unknown action \"{action_name}\""));
+                    continue;
+                }
+            };
+            let mut bindings = Vec::with_capacity(specs.as_slice().len());
+            for spec in specs.as_slice() {
+                match spec.parse() {
+                    Ok(binding) => bindings.push(binding),
+                    Err(why) => errors.push(format!("{action_name}: {why}")),
+                }
+            }
+            self.bindings.insert(action, bindings);
+        }
+
+        if errors.is_empty() {
+            Ok(self)
+        } else {
+            Err(HnCliError::KeyBindingsError(errors.join(", ")))
+        }
+    }
+
+    /// Check that no two actions available at the same time share a key binding.
+    fn validate(&self) -> Result<()> {
+        let mut conflicts = vec![];
+
+        // text inputs capture every plain character
+        for (action, bindings) in &self.bindings {
+            if ActionContext::of(action) == [ActionContext::TextInput]
+                && let Some(binding) = bindings.iter().find(|binding| binding.is_plain_character())
+            {
+                conflicts.push(format!(
+                    "{binding} cannot be used by {} since it can be typed in",
+                    action.get_config_name()
+                ));
+            }
+        }
+
+        let contexts_to_check = [ActionContext::General, ActionContext::TextInput]
+            .into_iter()
+            .chain(SCREEN_ACTION_CONTEXTS);
+        for context in contexts_to_check {
+            // general actions remain available on every screen
+            let is_active_in_context = |action: &ApplicationAction| {
+                let action_contexts = ActionContext::of(action);
+                action_contexts.contains(&context)
+                    || (SCREEN_ACTION_CONTEXTS.contains(&context)
+                        && action_contexts.contains(&ActionContext::General))
+            };
+            let active_actions: Vec<&ApplicationAction> = ApplicationAction::ALL
+                .iter()
+                .filter(|action| is_active_in_context(action))
+                .collect();
+            for (i, action) in active_actions.iter().enumerate() {
+                for other in &active_actions[i + 1..] {
+                    if is_intentional_override(action, other)
+                        || is_intentional_override(other, action)
+                    {
+                        continue;
+                    }
+                    if let Some(binding) = self
+                        .get(action)
+                        .iter()
+                        .find(|binding| self.get(other).contains(binding))
+                    {
+                        let conflict = format!(
+                            "{binding} is used by both {} and {}",
+                            action.get_config_name(),
+                            other.get_config_name()
+                        );
+                        if !conflicts.contains(&conflict) {
+                            conflicts.push(conflict);
+                        }
+                    }
+                }
+            }
+        }
+
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(HnCliError::KeyBindingsError(conflicts.join(", ")))
+        }
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::from_preset(KeyBindingsPreset::default())
+    }
+}
+
+/// One or more key specifications, as written in the configuration.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyBindingSpecs {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyBindingSpecs {
+    fn as_slice(&self) -> &[String] {
+        match self {
+            Self::One(spec) => std::slice::from_ref(spec),
+            Self::Many(specs) => specs,
+        }
+    }
+}
+
+/// The `[keybindings]` table of the configuration file.
+///
+/// Example:
+///
+/// ```toml
+/// [keybindings]
+/// preset = "Vim"
+/// toggle_help = "?"
+/// quit = ["ctrl+c", "ctrl+x"]
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBindingsConfiguration {
+    #[serde(default)]
+    pub preset: KeyBindingsPreset,
+    /// User-defined key bindings, by action name.
+    #[serde(flatten)]
+    pub overrides: BTreeMap<String, KeyBindingSpecs>,
+}
+
+impl KeyBindingsConfiguration {
+    /// Compute the effective key bindings, checking for errors and conflicts.
+    pub fn to_key_bindings(&self) -> Result<KeyBindings> {
+        let key_bindings = KeyBindings::from_preset(self.preset).with_overrides(&self.overrides)?;
+        key_bindings.validate()?;
+        Ok(key_bindings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::ui::handlers::{ApplicationAction, Key, KeyModifier};
+
+    use super::{
+        KeyBinding, KeyBindingSpecs, KeyBindings, KeyBindingsConfiguration, KeyBindingsPreset,
+    };
+
+    #[test]
+    fn test_key_binding_parsing() {
+        let parsed = |spec: &str| spec.parse::<KeyBinding>().unwrap();
+        assert_eq!(
+            parsed("ctrl+c"),
+            KeyBinding {
+                modifier: KeyModifier::Control,
+                key: Key::Char('c')
+            }
+        );
+        assert_eq!(
+            parsed("Enter"),
+            KeyBinding {
+                modifier: KeyModifier::None,
+                key: Key::Enter
+            }
+        );
+        assert_eq!(parsed("shift+l"), parsed("L"));
+        assert_eq!(parsed("ctrl++").key, Key::Char('+'));
+        assert_eq!(
+            parsed("+"),
+            KeyBinding {
+                modifier: KeyModifier::None,
+                key: Key::Char('+')
+            }
+        );
+        assert_eq!(parsed("space").key, Key::Char(' '));
+        assert_eq!(
+            parsed("ctrl+PageUp"),
+            KeyBinding {
+                modifier: KeyModifier::Control,
+                key: Key::PageUp
+            }
+        );
+        assert_eq!(parsed("pgdn").key, Key::PageDown);
+        assert!("ctrl+insert".parse::<KeyBinding>().is_err());
+        assert!("alt+x".parse::<KeyBinding>().is_err());
+
+        assert!(parsed("L").matches(&KeyModifier::Shift, &Key::Char('L')));
+        assert!(!parsed("l").matches(&KeyModifier::Control, &Key::Char('l')));
+        assert_eq!(parsed("ctrl+k").to_string(), "CTRL + 'k'");
+    }
+
+    #[test]
+    fn test_presets_are_valid() {
+        for preset in [
+            KeyBindingsPreset::Default,
+            KeyBindingsPreset::Vim,
+            KeyBindingsPreset::Emacs,
+        ] {
+            let key_bindings = KeyBindings::from_preset(preset);
+            assert_eq!(
+                key_bindings.validate().map_err(|err| err.to_string()),
+                Ok(())
+            );
+            for action in ApplicationAction::ALL {
+                assert_eq!(
+                    key_bindings.get(action).is_empty(),
+                    !action.is_configurable()
+                );
+            }
+        }
+        let vim = KeyBindings::from_preset(KeyBindingsPreset::Vim);
+        assert!(vim.matches(
+            &ApplicationAction::NavigateDown,
+            &KeyModifier::None,
+            &Key::Char('j')
+        ));
+    }
+
+    #[test]
+    fn test_key_bindings_configuration() {
+        let config: KeyBindingsConfiguration = toml::from_str(
+            r#"
+            preset = "Emacs"
+            toggle_help = "?"
+            quit = ["ctrl+c", "ctrl+x"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.preset, KeyBindingsPreset::Emacs);
+        assert_eq!(
+            config.overrides.get("toggle_help"),
+            Some(&KeyBindingSpecs::One("?".into()))
+        );
+        assert_eq!(
+            toml::from_str::<KeyBindingsConfiguration>(&toml::to_string(&config).unwrap()).unwrap(),
+            config
+        );
+        let key_bindings = config.to_key_bindings().unwrap();
+        assert_eq!(
+            key_bindings.get_representation(&ApplicationAction::Quit),
+            "CTRL + 'c' or CTRL + 'x'"
+        );
+
+        // conflicts
+        let mut overrides = BTreeMap::new();
+        overrides.insert("navigate_up".into(), KeyBindingSpecs::One("q".into()));
+        let conflicting = KeyBindingsConfiguration {
+            preset: KeyBindingsPreset::Default,
+            overrides,
+        };
+        assert!(conflicting.to_key_bindings().is_err());
+
+        let mut overrides = BTreeMap::new();
+        overrides.insert("input_go_to_start".into(), KeyBindingSpecs::One("a".into()));
+        let conflicting = KeyBindingsConfiguration {
+            preset: KeyBindingsPreset::Default,
+            overrides,
+        };
+        assert!(conflicting.to_key_bindings().is_err());
+
+        // unknown action
+        let mut overrides = BTreeMap::new();
+        overrides.insert("fly".into(), KeyBindingSpecs::One("x".into()));
+        let unknown = KeyBindingsConfiguration {
+            preset: KeyBindingsPreset::Default,
+            overrides,
+        };
+        assert!(unknown.to_key_bindings().is_err());
+    }
+}
@@ -5,9 +5,11 @@ use crate::{
     app::state::AppState,
     config::AppConfiguration,
     ui::screens::{
-        help::HelpScreen, home::HomeScreen, nested_comments::NestedCommentsScreen,
-        search_help::SearchHelpScreen, settings::SettingsScreen, story::StoryDetailsScreen,
-        user::UserDetailsScreen,
+        bookmarks::BookmarksScreen, help::HelpScreen, history::BrowsingHistoryScreen,
+        home::HomeScreen, nested_comments::NestedCommentsScreen, past::PastFrontPageScreen,
+        search_help::SearchHelpScreen, search_results::SearchResultsScreen,
+        settings::SettingsScreen, story::StoryDetailsScreen, user::UserDetailsScreen,
+        who_is_hiring::WhoIsHiringScreen,
     },
 };
 
@@ -18,6 +20,12 @@ use super::{displayable_item::DisplayableHackerNewsItem, screens::Screen};
 pub enum AppRoute {
     /// Home screen.
     Home(HnStoriesSections),
+    /// Past front page screen. The viewed day is stored in the application state.
+    PastFrontPage,
+    /// Latest "Who is hiring?" thread screen.
+    WhoIsHiring,
+    /// Full-text search results screen. Stores the query.
+    SearchResults(String),
     /// Item details screen.
     ItemDetails(DisplayableHackerNewsItem),
     /// Item nested comments screen.
@@ -28,6 +36,10 @@ pub enum AppRoute {
     // Search, // NB: disabled due to unofficial Algolia HN API becoming very limited
     /// Algolia-based search screen help.
     SearchHelp,
+    /// Browsing history screen. The filter is stored in the application state.
+    BrowsingHistory,
+    /// Bookmarks screen. The tag filter and sorting are stored in the application state.
+    Bookmarks,
     /// Settings screen.
     Settings,
     /// Help screen.
@@ -46,6 +58,18 @@ impl AppRoute {
         matches!(self, AppRoute::Home(_))
     }
 
+    pub fn is_past_front_page(&self) -> bool {
+        matches!(self, AppRoute::PastFrontPage)
+    }
+
+    pub fn is_who_is_hiring(&self) -> bool {
+        matches!(self, AppRoute::WhoIsHiring)
+    }
+
+    pub fn is_search_results(&self) -> bool {
+        matches!(self, AppRoute::SearchResults(_))
+    }
+
     pub fn is_search_help(&self) -> bool {
         matches!(self, AppRoute::SearchHelp)
     }
@@ -55,6 +79,14 @@ impl AppRoute {
         // matches!(self, AppRoute::Search | AppRoute::SearchHelp)
     }
 
+    pub fn is_browsing_history(&self) -> bool {
+        matches!(self, AppRoute::BrowsingHistory)
+    }
+
+    pub fn is_bookmarks(&self) -> bool {
+        matches!(self, AppRoute::Bookmarks)
+    }
+
     pub fn is_settings(&self) -> bool {
         matches!(self, AppRoute::Settings)
     }
@@ -90,12 +122,16 @@ impl AppRouter {
 
     /// Are we on the root screen, *i.e.* the initial screen showed on application launch?
     ///
-    /// NB: section tabs like "Ask HN" or similar **do** count as being on the initial screen.
+    /// NB: section tabs like "Ask HN", "Past" or "Hiring" **do** count as being on the initial screen.
     pub fn is_on_root_screen(&self) -> bool {
-        self.navigation_stack
+        let current_route = self
+            .navigation_stack
             .last()
-            .expect("router: there must be at least 1 screen present in the navigation stack")
-            .is_home()
+            .expect("router: there must be at least 1 screen present in the navigation stack");
+        current_route.is_home()
+            || current_route.is_past_front_page()
+            || current_route.is_who_is_hiring()
+            || current_route.is_search_results()
     }
 
     /// Get the current route state.
@@ -121,7 +157,12 @@ impl AppRouter {
             Help => Box::new(HelpScreen::new()),
             Settings => Box::new(SettingsScreen::new()),
             SearchHelp => Box::new(SearchHelpScreen::new()),
+            BrowsingHistory => Box::new(BrowsingHistoryScreen::new()),
+            Bookmarks => Box::new(BookmarksScreen::new()),
             Home(section) => Box::new(HomeScreen::new(section)),
+            PastFrontPage => Box::new(PastFrontPageScreen::new()),
+            WhoIsHiring => Box::new(WhoIsHiringScreen::new()),
+            SearchResults(_) => Box::new(SearchResultsScreen::new()),
             ItemDetails(item) => Box::new(StoryDetailsScreen::new(item)),
             ItemNestedComments(parent_comment) => {
                 Box::new(NestedCommentsScreen::new(parent_comment))
@@ -9,19 +9,31 @@ use crate::{
 
 use super::{
     common::UiComponentId,
-    handlers::InputsController,
+    components::widgets::{
+        custom_list::ListJump,
+        text_input::{
+            TEXT_INPUT_AVAILABLE_ACTIONS, TextInputState, TextInputStateAction,
+            TextInputStateActionBridge,
+        },
+    },
+    handlers::{ApplicationAction, InputsController},
     router::{AppRoute, AppRouter},
 };
 
+pub mod bookmarks;
 pub mod help;
 pub mod help_search;
+pub mod history;
 pub mod home;
 pub mod nested_comments;
+pub mod past;
 pub mod search;
 pub mod search_help;
+pub mod search_results;
 pub mod settings;
 pub mod story;
 pub mod user;
+pub mod who_is_hiring;
 
 /// Defines layout state by associating each visible component
 /// with a defined rendering target `Rect`.
@@ -63,3 +75,89 @@ pub trait Screen: Debug + Send + Sync {
         state: &AppState,
     );
 }
+
+/// Outcome of a key press handled by a text input being typed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextInputOutcome {
+    /// The input should be closed, without applying its value.
+    Cancelled,
+    /// The value of the input should be applied.
+    Submitted,
+    /// The value of the input, or its cursor, may have changed.
+    Edited,
+    /// The key press is unrelated to text editing.
+    Ignored,
+}
+
+/// Handle a key press while typing in the given text input, capped to the given length.
+///
+/// Screens handle their text inputs themselves, to swallow every key press while typing.
+fn handle_text_input(
+    inputs: &InputsController,
+    input: &mut TextInputState,
+    max_length: usize,
+) -> TextInputOutcome {
+    if inputs.is_active(&ApplicationAction::Back) {
+        TextInputOutcome::Cancelled
+    } else if inputs.is_active(&ApplicationAction::SelectItem) {
+        TextInputOutcome::Submitted
+    } else if let Some((_, char)) = inputs.get_active_input_key() {
+        if input.get_value().chars().count() < max_length {
+            input.handle_action(&TextInputStateAction::InsertCharacter(char));
+        }
+        TextInputOutcome::Edited
+    } else if let Some(available_action) = TEXT_INPUT_AVAILABLE_ACTIONS
+        .iter()
+        .find(|action| inputs.is_active(action))
+    {
+        input.handle_event(inputs, available_action);
+        TextInputOutcome::Edited
+    } else {
+        TextInputOutcome::Ignored
+    }
+}
+
+/// Maximum length of a typed-in stories filter.
+const MAX_STORIES_FILTER_INPUT_LENGTH: usize = 50;
+
+/// Handle the stories filter on the screens listing stories: typing it in, and clearing it.
+///
+/// Returns `None` if the event is unrelated to the filter.
+///
+/// NB: the stories panel keeps on handling the navigation while typing, to move among the matching stories.
+fn handle_stories_filter_inputs(
+    inputs: &InputsController,
+    state: &mut AppState,
+) -> Option<ScreenEventResponse> {
+    let Some(filter_input) = state.get_main_stories_filter_input_mut() else {
+        // clearing an applied filter
+        return (inputs.is_active(&ApplicationAction::Back)
+            && !state.get_main_stories_filter().is_empty())
+        .then(|| {
+            state.set_main_stories_filter(String::new());
+            ScreenEventResponse::Caught
+        });
+    };
+
+    match handle_text_input(inputs, filter_input, MAX_STORIES_FILTER_INPUT_LENGTH) {
+        TextInputOutcome::Cancelled => {
+            state.set_main_stories_filter(String::new());
+            state.set_main_stories_filter_input(None);
+        }
+        TextInputOutcome::Submitted => {
+            let filter = filter_input.get_value().trim().to_string();
+            state.set_main_stories_filter(filter);
+            state.set_main_stories_filter_input(None);
+        }
+        TextInputOutcome::Edited => (),
+        TextInputOutcome::Ignored => {
+            if inputs.is_active(&ApplicationAction::NavigateUp)
+                || inputs.is_active(&ApplicationAction::NavigateDown)
+                || ListJump::from_inputs(inputs).is_some()
+            {
+                return Some(ScreenEventResponse::PassThrough);
+            }
+        }
+    }
+    Some(ScreenEventResponse::Caught)
+}
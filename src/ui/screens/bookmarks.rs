@@ -0,0 +1,118 @@
+use ratatui::layout::Rect;
+
+use crate::{
+    app::{
+        history::{AppHistory, Bookmark},
+        state::AppState,
+    },
+    ui::{
+        components::{
+            bookmarks::{BookmarkField, filters::BOOKMARKS_FILTERS_ID, list::BOOKMARKS_LIST_ID},
+            navigation::NAVIGATION_ID,
+        },
+        handlers::{ApplicationAction, InputsController},
+        router::{AppRoute, AppRouter},
+        utils::breakpoints::{Breakpoints, BreakpointsDirection},
+    },
+};
+
+use super::{
+    Screen, ScreenComponentsRegistry, ScreenEventResponse, TextInputOutcome, handle_text_input,
+};
+
+/// Maximum length of typed-in bookmark tags or notes.
+const MAX_BOOKMARK_INPUT_LENGTH: usize = 200;
+
+/// The bookmarks screen of hncli, listing the stories and comments saved for later.
+///
+/// The current layout is as following:
+///
+/// ```md
+/// ------------------------------------------
+/// |              navigation                |
+/// ------------------------------------------
+/// |                filters                 |
+/// ------------------------------------------
+/// |                                        |
+/// |                                        |
+/// |               bookmarks                |
+/// |                                        |
+/// |                                        |
+/// ------------------------------------------
+/// ```
+#[derive(Debug)]
+pub struct BookmarksScreen {
+    breakpoints: Breakpoints,
+}
+
+impl BookmarksScreen {
+    pub fn new() -> Self {
+        Self {
+            breakpoints: Breakpoints::new("bookmarks_screen", &[20, 15, 65])
+                .breakpoint(25, &[10, 12, 78])
+                .breakpoint(45, &[5, 7, 88]),
+        }
+    }
+
+    /// Apply the typed-in bookmark field, if any, and persist it.
+    fn apply_bookmark_input(state: &mut AppState, history: &mut AppHistory) {
+        if let Some((item_id, field, input)) = state.get_bookmark_input() {
+            let value = input.get_value().trim();
+            match field {
+                BookmarkField::Tags => {
+                    history.set_bookmark_tags(*item_id, Bookmark::parse_tags(value))
+                }
+                BookmarkField::Notes => history.set_bookmark_notes(*item_id, value.to_string()),
+            }
+            history.persist();
+        }
+        state.set_bookmark_input(None);
+    }
+}
+
+impl Screen for BookmarksScreen {
+    fn before_unmount(&mut self, state: &mut AppState) {
+        state.set_bookmark_input(None);
+    }
+
+    fn handle_inputs(
+        &mut self,
+        inputs: &InputsController,
+        router: &mut AppRouter,
+        state: &mut AppState,
+        history: &mut AppHistory,
+    ) -> (ScreenEventResponse, Option<AppRoute>) {
+        let Some((_, _, input)) = state.get_bookmark_input_mut() else {
+            return if inputs.is_active(&ApplicationAction::Back) {
+                router.pop_navigation_stack();
+                (
+                    ScreenEventResponse::Caught,
+                    Some(router.get_current_route().clone()),
+                )
+            } else {
+                (ScreenEventResponse::PassThrough, None)
+            };
+        };
+
+        match handle_text_input(inputs, input, MAX_BOOKMARK_INPUT_LENGTH) {
+            TextInputOutcome::Cancelled => state.set_bookmark_input(None),
+            TextInputOutcome::Submitted => Self::apply_bookmark_input(state, history),
+            TextInputOutcome::Edited | TextInputOutcome::Ignored => (),
+        }
+        (ScreenEventResponse::Caught, None)
+    }
+
+    fn compute_layout(
+        &self,
+        frame_size: Rect,
+        components_registry: &mut ScreenComponentsRegistry,
+        _state: &AppState,
+    ) {
+        self.breakpoints.apply(
+            components_registry,
+            &[NAVIGATION_ID, BOOKMARKS_FILTERS_ID, BOOKMARKS_LIST_ID],
+            frame_size,
+            BreakpointsDirection::Vertical,
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use ratatui::layout::Rect;
+
+use crate::{
+    app::{history::AppHistory, state::AppState},
+    ui::{
+        components::{
+            browsing_history::{
+                entries::BROWSING_HISTORY_ENTRIES_ID, filter::BROWSING_HISTORY_FILTER_ID,
+            },
+            navigation::NAVIGATION_ID,
+        },
+        handlers::{ApplicationAction, InputsController},
+        router::{AppRoute, AppRouter},
+        utils::breakpoints::{Breakpoints, BreakpointsDirection},
+    },
+};
+
+use super::{
+    Screen, ScreenComponentsRegistry, ScreenEventResponse, TextInputOutcome, handle_text_input,
+};
+
+/// Maximum length of a typed-in filter.
+const MAX_FILTER_INPUT_LENGTH: usize = 50;
+
+/// The browsing history screen of hncli, listing the recently opened
+/// stories, comments and user profiles.
+///
+/// The current layout is as following:
+///
+/// ```md
+/// ------------------------------------------
+/// |              navigation                |
+/// ------------------------------------------
+/// |                filter                  |
+/// ------------------------------------------
+/// |                                        |
+/// |                                        |
+/// |                entries                 |
+/// |                                        |
+/// |                                        |
+/// ------------------------------------------
+/// ```
+#[derive(Debug)]
+pub struct BrowsingHistoryScreen {
+    breakpoints: Breakpoints,
+}
+
+impl BrowsingHistoryScreen {
+    pub fn new() -> Self {
+        Self {
+            breakpoints: Breakpoints::new("browsing_history_screen", &[20, 15, 65])
+                .breakpoint(25, &[10, 12, 78])
+                .breakpoint(45, &[5, 7, 88]),
+        }
+    }
+}
+
+impl Screen for BrowsingHistoryScreen {
+    fn before_unmount(&mut self, state: &mut AppState) {
+        state.set_browsing_history_filter_input(None);
+    }
+
+    fn handle_inputs(
+        &mut self,
+        inputs: &InputsController,
+        router: &mut AppRouter,
+        state: &mut AppState,
+        _history: &mut AppHistory,
+    ) -> (ScreenEventResponse, Option<AppRoute>) {
+        let Some(filter_input) = state.get_browsing_history_filter_input_mut() else {
+            return if inputs.is_active(&ApplicationAction::Back) {
+                router.pop_navigation_stack();
+                (
+                    ScreenEventResponse::Caught,
+                    Some(router.get_current_route().clone()),
+                )
+            } else {
+                (ScreenEventResponse::PassThrough, None)
+            };
+        };
+
+        match handle_text_input(inputs, filter_input, MAX_FILTER_INPUT_LENGTH) {
+            TextInputOutcome::Cancelled => state.set_browsing_history_filter_input(None),
+            TextInputOutcome::Submitted => {
+                let filter = filter_input.get_value().trim().to_string();
+                state.set_browsing_history_filter(filter);
+                state.set_browsing_history_filter_input(None);
+            }
+            TextInputOutcome::Edited | TextInputOutcome::Ignored => (),
+        }
+        (ScreenEventResponse::Caught, None)
+    }
+
+    fn compute_layout(
+        &self,
+        frame_size: Rect,
+        components_registry: &mut ScreenComponentsRegistry,
+        _state: &AppState,
+    ) {
+        self.breakpoints.apply(
+            components_registry,
+            &[
+                NAVIGATION_ID,
+                BROWSING_HISTORY_FILTER_ID,
+                BROWSING_HISTORY_ENTRIES_ID,
+            ],
+            frame_size,
+            BreakpointsDirection::Vertical,
+        );
+    }
+}
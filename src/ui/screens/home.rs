@@ -5,14 +5,20 @@ use crate::{
     app::{history::AppHistory, state::AppState},
     config::AppConfiguration,
     ui::{
-        components::{navigation::NAVIGATION_ID, options::OPTIONS_ID, stories::STORIES_PANEL_ID},
+        components::{
+            navigation::NAVIGATION_ID, options::OPTIONS_ID, stories::STORIES_PANEL_ID,
+            story_preview::STORY_PREVIEW_ID,
+        },
         handlers::InputsController,
         router::{AppRoute, AppRouter},
         utils::breakpoints::{Breakpoints, BreakpointsDirection},
     },
 };
 
-use super::{Screen, ScreenComponentsRegistry, ScreenEventResponse};
+use super::{Screen, ScreenComponentsRegistry, ScreenEventResponse, handle_stories_filter_inputs};
+
+/// Minimum terminal width to preview the selected story next to the stories list.
+const STORY_PREVIEW_MIN_WIDTH: u16 = 160;
 
 /// The Home screen of hncli.
 ///
@@ -31,10 +37,22 @@ use super::{Screen, ScreenComponentsRegistry, ScreenEventResponse};
 /// |          options (eg. sorting)         |
 /// ------------------------------------------
 /// ```
+///
+/// On wide terminals, and unless disabled, the stories are split as following:
+///
+/// ```md
+/// ------------------------------------------
+/// |                    |                   |
+/// |      stories       |   story preview   |
+/// |                    |                   |
+/// ------------------------------------------
+/// ```
 #[derive(Debug)]
 pub struct HomeScreen {
     section: HnStoriesSections,
     breakpoints: Breakpoints,
+    preview_breakpoints: Breakpoints,
+    display_story_preview: bool,
 }
 
 impl HomeScreen {
@@ -44,23 +62,34 @@ impl HomeScreen {
             breakpoints: Breakpoints::new("home_screen", &[20, 65, 15])
                 .breakpoint(25, &[10, 80, 10])
                 .breakpoint(45, &[5, 88, 7]),
+            preview_breakpoints: Breakpoints::new("home_screen_preview", &[100, 0])
+                .breakpoint(STORY_PREVIEW_MIN_WIDTH, &[55, 45]),
+            display_story_preview: false,
         }
     }
 }
 
 impl Screen for HomeScreen {
-    fn before_mount(&mut self, state: &mut AppState, _config: &AppConfiguration) {
+    fn before_mount(&mut self, state: &mut AppState, config: &AppConfiguration) {
         state.set_main_stories_section(self.section);
+        self.display_story_preview = config.get_display_story_preview_panel();
+    }
+
+    fn before_unmount(&mut self, state: &mut AppState) {
+        state.set_main_stories_filter_input(None);
     }
 
     fn handle_inputs(
         &mut self,
-        _inputs: &InputsController,
+        inputs: &InputsController,
         _router: &mut AppRouter,
-        _state: &mut AppState,
+        state: &mut AppState,
         _history: &mut AppHistory,
     ) -> (ScreenEventResponse, Option<AppRoute>) {
-        (ScreenEventResponse::PassThrough, None)
+        (
+            handle_stories_filter_inputs(inputs, state).unwrap_or(ScreenEventResponse::PassThrough),
+            None,
+        )
     }
 
     fn compute_layout(
@@ -75,5 +104,16 @@ impl Screen for HomeScreen {
             frame_size,
             BreakpointsDirection::Vertical,
         );
+        if self.display_story_preview
+            && let Some(&stories_area) = components_registry.get(&STORIES_PANEL_ID)
+        {
+            self.preview_breakpoints.apply_inside(
+                components_registry,
+                &[STORIES_PANEL_ID, STORY_PREVIEW_ID],
+                frame_size,
+                stories_area,
+                BreakpointsDirection::Horizontal,
+            );
+        }
     }
 }
@@ -0,0 +1,166 @@
+use chrono::{NaiveDate, Utc};
+use ratatui::layout::Rect;
+
+use crate::{
+    app::{history::AppHistory, state::AppState},
+    ui::{
+        components::{
+            date_picker::{DATE_PICKER_ID, DATE_PICKER_INPUT_FORMAT},
+            navigation::NAVIGATION_ID,
+            stories::STORIES_PANEL_ID,
+        },
+        flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
+        handlers::InputsController,
+        router::{AppRoute, AppRouter},
+        utils::breakpoints::{Breakpoints, BreakpointsDirection},
+    },
+};
+
+use super::{
+    Screen, ScreenComponentsRegistry, ScreenEventResponse, TextInputOutcome,
+    handle_stories_filter_inputs, handle_text_input,
+};
+
+/// Maximum length of the typed-in day, *i.e.* "YYYY-MM-DD".
+const MAX_DAY_INPUT_LENGTH: usize = 10;
+
+/// The Past front page screen of hncli, listing the stories having reached
+/// the front page on a given day.
+///
+/// The current layout is as following:
+///
+/// ```md
+/// ------------------------------------------
+/// |              navigation                |
+/// ------------------------------------------
+/// |                                        |
+/// |                                        |
+/// |               stories                  |
+/// |                                        |
+/// |                                        |
+/// ------------------------------------------
+/// |              date picker               |
+/// ------------------------------------------
+/// ```
+#[derive(Debug)]
+pub struct PastFrontPageScreen {
+    breakpoints: Breakpoints,
+}
+
+impl PastFrontPageScreen {
+    pub fn new() -> Self {
+        Self {
+            breakpoints: Breakpoints::new("past_front_page_screen", &[20, 65, 15])
+                .breakpoint(25, &[10, 80, 10])
+                .breakpoint(45, &[5, 88, 7]),
+        }
+    }
+
+    /// Try to apply the typed-in day, returning false if it is invalid.
+    fn apply_day_input(state: &mut AppState) -> bool {
+        let typed_day = state
+            .get_past_front_page_day_input()
+            .and_then(|input| parse_day_input(input.get_value(), Utc::now().date_naive()));
+        match typed_day {
+            Some(day) => {
+                state.set_past_front_page_day(day);
+                state.set_past_front_page_day_input(None);
+                state.set_main_stories_loading(true);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Screen for PastFrontPageScreen {
+    fn before_unmount(&mut self, state: &mut AppState) {
+        state.set_past_front_page_day_input(None);
+        state.set_main_stories_filter_input(None);
+    }
+
+    fn handle_inputs(
+        &mut self,
+        inputs: &InputsController,
+        _router: &mut AppRouter,
+        state: &mut AppState,
+        _history: &mut AppHistory,
+    ) -> (ScreenEventResponse, Option<AppRoute>) {
+        if state.get_past_front_page_day_input().is_none() {
+            return (
+                handle_stories_filter_inputs(inputs, state)
+                    .unwrap_or(ScreenEventResponse::PassThrough),
+                None,
+            );
+        }
+
+        let day_input = state
+            .get_past_front_page_day_input_mut()
+            .expect("PastFrontPageScreen: day input should be active");
+        match handle_text_input(inputs, day_input, MAX_DAY_INPUT_LENGTH) {
+            TextInputOutcome::Cancelled => state.set_past_front_page_day_input(None),
+            TextInputOutcome::Submitted => {
+                if !Self::apply_day_input(state) {
+                    state.set_flash_message(FlashMessage::new(
+                        "Invalid day: expected a past date formatted as YYYY-MM-DD.",
+                        FlashMessageType::Warning,
+                        FLASH_MESSAGE_DEFAULT_DURATION_MS,
+                    ));
+                }
+            }
+            TextInputOutcome::Edited | TextInputOutcome::Ignored => (),
+        }
+        (ScreenEventResponse::Caught, None)
+    }
+
+    fn compute_layout(
+        &self,
+        frame_size: Rect,
+        components_registry: &mut ScreenComponentsRegistry,
+        _state: &AppState,
+    ) {
+        self.breakpoints.apply(
+            components_registry,
+            &[NAVIGATION_ID, STORIES_PANEL_ID, DATE_PICKER_ID],
+            frame_size,
+            BreakpointsDirection::Vertical,
+        );
+    }
+}
+
+/// Parse the typed-in day, which must not be after the given current day.
+fn parse_day_input(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), DATE_PICKER_INPUT_FORMAT)
+        .ok()
+        .filter(|day| *day <= today)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::parse_day_input;
+
+    #[test]
+    fn test_parse_day_input() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        assert_eq!(
+            parse_day_input("2024-02-29", today),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+        );
+        assert_eq!(parse_day_input(" 2024-03-01 ", today), Some(today));
+        assert_eq!(
+            parse_day_input("2007-2-19", today),
+            NaiveDate::from_ymd_opt(2007, 2, 19)
+        );
+
+        // future days
+        assert_eq!(parse_day_input("2024-03-02", today), None);
+        // invalid days
+        assert_eq!(parse_day_input("2023-02-29", today), None);
+        assert_eq!(parse_day_input("2024-13-01", today), None);
+        assert_eq!(parse_day_input("01/03/2024", today), None);
+        assert_eq!(parse_day_input("", today), None);
+    }
+}
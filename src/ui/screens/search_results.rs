@@ -0,0 +1,85 @@
+use ratatui::layout::Rect;
+
+use crate::{
+    app::{history::AppHistory, state::AppState},
+    ui::{
+        components::{navigation::NAVIGATION_ID, stories::STORIES_PANEL_ID},
+        handlers::{ApplicationAction, InputsController},
+        router::{AppRoute, AppRouter},
+        utils::breakpoints::{Breakpoints, BreakpointsDirection},
+    },
+};
+
+use super::{Screen, ScreenComponentsRegistry, ScreenEventResponse, handle_stories_filter_inputs};
+
+/// The Search results screen of hncli, listing the stories matching
+/// a full-text query given on the command-line.
+///
+/// The current layout is as following:
+///
+/// ```md
+/// ------------------------------------------
+/// |              navigation                |
+/// ------------------------------------------
+/// |                                        |
+/// |                                        |
+/// |               stories                  |
+/// |                                        |
+/// |                                        |
+/// ------------------------------------------
+/// ```
+#[derive(Debug)]
+pub struct SearchResultsScreen {
+    breakpoints: Breakpoints,
+}
+
+impl SearchResultsScreen {
+    pub fn new() -> Self {
+        Self {
+            breakpoints: Breakpoints::new("search_results_screen", &[20, 80])
+                .breakpoint(25, &[10, 90])
+                .breakpoint(45, &[5, 95]),
+        }
+    }
+}
+
+impl Screen for SearchResultsScreen {
+    fn before_unmount(&mut self, state: &mut AppState) {
+        state.set_main_stories_filter_input(None);
+    }
+
+    fn handle_inputs(
+        &mut self,
+        inputs: &InputsController,
+        router: &mut AppRouter,
+        state: &mut AppState,
+        _history: &mut AppHistory,
+    ) -> (ScreenEventResponse, Option<AppRoute>) {
+        if let Some(response) = handle_stories_filter_inputs(inputs, state) {
+            (response, None)
+        } else if inputs.is_active(&ApplicationAction::Back) {
+            router.pop_navigation_stack();
+            state.set_main_stories_loading(true);
+            (
+                ScreenEventResponse::Caught,
+                Some(router.get_current_route().clone()),
+            )
+        } else {
+            (ScreenEventResponse::PassThrough, None)
+        }
+    }
+
+    fn compute_layout(
+        &self,
+        frame_size: Rect,
+        components_registry: &mut ScreenComponentsRegistry,
+        _state: &AppState,
+    ) {
+        self.breakpoints.apply(
+            components_registry,
+            &[NAVIGATION_ID, STORIES_PANEL_ID],
+            frame_size,
+            BreakpointsDirection::Vertical,
+        );
+    }
+}
@@ -3,7 +3,13 @@ use ratatui::layout::Rect;
 use crate::{
     app::{history::AppHistory, state::AppState},
     ui::{
-        components::{navigation::NAVIGATION_ID, settings::SETTINGS_ID},
+        components::{
+            navigation::NAVIGATION_ID,
+            settings::SETTINGS_ID,
+            widgets::text_input::{
+                TEXT_INPUT_AVAILABLE_ACTIONS, TextInputStateAction, TextInputStateActionBridge,
+            },
+        },
         flash::{FLASH_MESSAGE_DEFAULT_DURATION_MS, FlashMessage, FlashMessageType},
         handlers::{ApplicationAction, InputsController},
         router::{AppRoute, AppRouter},
@@ -13,6 +19,9 @@ use crate::{
 
 use super::{Screen, ScreenComponentsRegistry, ScreenEventResponse};
 
+/// Maximum length of a typed-in setting value, *e.g.* a color.
+const MAX_VALUE_INPUT_LENGTH: usize = 32;
+
 /// The settings screen of hncli.
 #[derive(Debug)]
 pub struct SettingsScreen {
@@ -30,8 +39,41 @@ impl SettingsScreen {
     }
 }
 
+impl SettingsScreen {
+    /// Handle the value input, swallowing every key press while typing.
+    ///
+    /// The submitted value is applied by the settings component, having access to the configuration.
+    fn handle_value_input(
+        inputs: &InputsController,
+        state: &mut AppState,
+    ) -> (ScreenEventResponse, Option<AppRoute>) {
+        if inputs.is_active(&ApplicationAction::Back) {
+            state.set_settings_value_input(None);
+        } else if inputs.is_active(&ApplicationAction::SelectItem) {
+            state.set_settings_value_input_submitted(true);
+        } else if let Some((_, char)) = inputs.get_active_input_key() {
+            let value_input = state
+                .get_settings_value_input_mut()
+                .expect("SettingsScreen: value input should be active");
+            if value_input.get_value().chars().count() < MAX_VALUE_INPUT_LENGTH {
+                value_input.handle_action(&TextInputStateAction::InsertCharacter(char));
+            }
+        } else if let Some(available_action) = TEXT_INPUT_AVAILABLE_ACTIONS
+            .iter()
+            .find(|action| inputs.is_active(action))
+        {
+            state
+                .get_settings_value_input_mut()
+                .expect("SettingsScreen: value input should be active")
+                .handle_event(inputs, available_action);
+        }
+        (ScreenEventResponse::Caught, None)
+    }
+}
+
 impl Screen for SettingsScreen {
     fn before_unmount(&mut self, state: &mut AppState) {
+        state.set_settings_value_input(None);
         state.set_flash_message(FlashMessage::new(
             "Settings successfully saved.",
             FlashMessageType::Info,
@@ -43,9 +85,13 @@ impl Screen for SettingsScreen {
         &mut self,
         inputs: &InputsController,
         router: &mut AppRouter,
-        _state: &mut AppState,
+        state: &mut AppState,
         _history: &mut AppHistory,
     ) -> (ScreenEventResponse, Option<AppRoute>) {
+        if state.get_settings_value_input().is_some() {
+            return Self::handle_value_input(inputs, state);
+        }
+
         if inputs.is_active(&ApplicationAction::Back) {
             router.pop_navigation_stack();
             (
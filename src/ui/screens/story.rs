@@ -41,14 +41,21 @@ impl StoryDetailsScreen {
 
 impl Screen for StoryDetailsScreen {
     fn before_mount(&mut self, state: &mut AppState, config: &AppConfiguration) {
+        // coming back from the nested comments of the same item: keep the focused top-level comment
+        let is_back_from_nested_comments = state
+            .get_currently_viewed_item()
+            .is_some_and(|item| item.id == self.item.id)
+            && state.get_currently_viewed_item_comments_chain().len() == 1;
         state.set_currently_viewed_item(Some(self.item.clone()));
         state.set_currently_viewed_item_has_switched(true);
 
-        state.reset_currently_viewed_item_comments_chain();
-        if let Some(item_kids) = self.item.kids.as_ref()
-            && let Some(first_comment_id) = item_kids.first()
-        {
-            state.push_currently_viewed_item_comments_chain(*first_comment_id);
+        if !is_back_from_nested_comments {
+            state.reset_currently_viewed_item_comments_chain();
+            if let Some(item_kids) = self.item.kids.as_ref()
+                && let Some(first_comment_id) = item_kids.first()
+            {
+                state.push_currently_viewed_item_comments_chain(*first_comment_id);
+            }
         }
 
         if let Some(item) = state.get_currently_viewed_item() {
@@ -77,17 +84,13 @@ impl Screen for StoryDetailsScreen {
         history: &mut AppHistory,
     ) -> (ScreenEventResponse, Option<AppRoute>) {
         if inputs.is_active(&ApplicationAction::Back) {
-            // navigation history handling
-            // TODO: should also persist when quitting the app
-            if let Some(focused_top_level_comment_id) =
-                state.get_currently_viewed_item_comments_chain().first()
-            {
-                history.persist_top_level_comment_id_for_story(
-                    self.item.id,
-                    *focused_top_level_comment_id,
-                );
+            // navigation history handling (see `App::persist_history` when quitting the app)
+            let comments_chain = state.get_currently_viewed_item_comments_chain();
+            if !comments_chain.is_empty() {
+                history.persist_comments_chain_for_story(self.item.id, comments_chain);
                 history.persist();
             }
+            state.clear_previous_visit();
 
             router.pop_navigation_stack();
             (
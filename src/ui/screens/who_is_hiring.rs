@@ -0,0 +1,120 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+
+use crate::{
+    app::{history::AppHistory, state::AppState},
+    ui::{
+        components::{
+            navigation::NAVIGATION_ID,
+            who_is_hiring::{
+                filters::WHO_IS_HIRING_FILTERS_ID,
+                posting_details::WHO_IS_HIRING_POSTING_DETAILS_ID,
+                postings::WHO_IS_HIRING_POSTINGS_ID,
+            },
+        },
+        handlers::InputsController,
+        router::{AppRoute, AppRouter},
+        utils::breakpoints::{Breakpoints, BreakpointsDirection},
+    },
+};
+
+use super::{
+    Screen, ScreenComponentsRegistry, ScreenEventResponse, TextInputOutcome, handle_text_input,
+};
+
+/// Maximum length of a typed-in filter.
+const MAX_FILTER_INPUT_LENGTH: usize = 50;
+
+/// The Who is hiring screen of hncli, browsing the postings of the latest
+/// monthly "Who is hiring?" thread.
+///
+/// The current layout is as following:
+///
+/// ```md
+/// ------------------------------------------
+/// |              navigation                |
+/// ------------------------------------------
+/// |                filters                 |
+/// ------------------------------------------
+/// |                 |                      |
+/// |                 |                      |
+/// |    postings     |   posting details    |
+/// |                 |                      |
+/// |                 |                      |
+/// ------------------------------------------
+/// ```
+#[derive(Debug)]
+pub struct WhoIsHiringScreen {
+    breakpoints: Breakpoints,
+}
+
+impl WhoIsHiringScreen {
+    pub fn new() -> Self {
+        Self {
+            breakpoints: Breakpoints::new("who_is_hiring_screen", &[20, 15, 65])
+                .breakpoint(25, &[10, 12, 78])
+                .breakpoint(45, &[5, 7, 88]),
+        }
+    }
+
+    /// Apply the typed-in filter, if any.
+    fn apply_filter_input(state: &mut AppState) {
+        if let Some((field, input)) = state.get_who_is_hiring_filter_input() {
+            let (field, value) = (*field, input.get_value().trim().to_string());
+            state.get_who_is_hiring_filters_mut().set_text(field, value);
+        }
+        state.set_who_is_hiring_filter_input(None);
+    }
+}
+
+impl Screen for WhoIsHiringScreen {
+    fn before_unmount(&mut self, state: &mut AppState) {
+        state.set_who_is_hiring_filter_input(None);
+    }
+
+    fn handle_inputs(
+        &mut self,
+        inputs: &InputsController,
+        _router: &mut AppRouter,
+        state: &mut AppState,
+        _history: &mut AppHistory,
+    ) -> (ScreenEventResponse, Option<AppRoute>) {
+        let Some((_, filter_input)) = state.get_who_is_hiring_filter_input_mut() else {
+            return (ScreenEventResponse::PassThrough, None);
+        };
+
+        match handle_text_input(inputs, filter_input, MAX_FILTER_INPUT_LENGTH) {
+            TextInputOutcome::Cancelled => state.set_who_is_hiring_filter_input(None),
+            TextInputOutcome::Submitted => Self::apply_filter_input(state),
+            TextInputOutcome::Edited | TextInputOutcome::Ignored => (),
+        }
+        (ScreenEventResponse::Caught, None)
+    }
+
+    fn compute_layout(
+        &self,
+        frame_size: Rect,
+        components_registry: &mut ScreenComponentsRegistry,
+        _state: &AppState,
+    ) {
+        self.breakpoints.apply(
+            components_registry,
+            &[
+                NAVIGATION_ID,
+                WHO_IS_HIRING_FILTERS_ID,
+                WHO_IS_HIRING_POSTINGS_ID,
+            ],
+            frame_size,
+            BreakpointsDirection::Vertical,
+        );
+
+        // split the main area between the postings list and the selected posting
+        if let Some(main_area) = components_registry.get(&WHO_IS_HIRING_POSTINGS_ID).cloned() {
+            let main_layout_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .split(main_area);
+            components_registry.insert(WHO_IS_HIRING_POSTINGS_ID, main_layout_chunks[0]);
+            components_registry.insert(WHO_IS_HIRING_POSTING_DETAILS_ID, main_layout_chunks[1]);
+        }
+    }
+}
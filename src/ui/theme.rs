@@ -1,35 +1,44 @@
+use std::str::FromStr;
+
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 
-/// Avoid boilerplate and easily add theme colors and palettes.
-macro_rules! theme_define_color_palettes {
-    ($ ( $name: ident : $color_blue: expr, $color_magenta: expr, $color_yellow: expr , )* ) => {
-    $(
-        pub fn $name(&self) -> Color {
-            match self {
-                Self::Blue => $color_blue,
-                Self::Magenta => $color_magenta,
-                Self::Yellow => $color_yellow,
-            }
-        }
-    )*
-    }
-}
+use crate::errors::{HnCliError, Result};
 
+/// Theme selected in the configuration.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UiTheme {
     Blue,
     Magenta,
     #[default]
     Yellow,
+    /// Built-in theme for terminals with a light background.
+    Light,
+    /// User-defined palette, from the `custom_theme` configuration table.
+    Custom,
 }
 
 impl UiTheme {
-    pub fn next_value(&self) -> UiTheme {
+    /// Get the next theme, skipping the custom one if there is none defined.
+    pub fn next_value(&self, has_custom_theme: bool) -> UiTheme {
         match self {
             Self::Blue => Self::Magenta,
             Self::Magenta => Self::Yellow,
-            Self::Yellow => Self::Blue,
+            Self::Yellow => Self::Light,
+            Self::Light if has_custom_theme => Self::Custom,
+            Self::Light | Self::Custom => Self::Blue,
+        }
+    }
+
+    /// Get the previous theme, skipping the custom one if there is none defined.
+    pub fn previous_value(&self, has_custom_theme: bool) -> UiTheme {
+        match self {
+            Self::Blue if has_custom_theme => Self::Custom,
+            Self::Blue => Self::Light,
+            Self::Magenta => Self::Blue,
+            Self::Yellow => Self::Magenta,
+            Self::Light => Self::Yellow,
+            Self::Custom => Self::Light,
         }
     }
 
@@ -38,12 +47,342 @@ impl UiTheme {
             Self::Blue => "Blue",
             Self::Magenta => "Magenta",
             Self::Yellow => "Yellow",
+            Self::Light => "Light",
+            Self::Custom => "Custom",
         }
     }
 
-    theme_define_color_palettes! {
-        get_main_color: Color::LightBlue, Color::LightMagenta, Color::LightYellow,
-        get_block_color: Color::LightCyan, Color::White, Color::White,
-        get_accent_color: Color::LightMagenta, Color::Magenta, Color::Yellow,
+    /// Get the palette of a built-in theme.
+    ///
+    /// NB: the custom theme palette is resolved by the configuration, this
+    /// falls back to the default theme.
+    pub fn get_builtin_palette(&self) -> UiThemePalette {
+        let dark = |main, block, accent, comment_depths: &[Color]| UiThemePalette {
+            main,
+            block,
+            accent,
+            text: Color::White,
+            muted: Color::Gray,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            info: Color::Blue,
+            highlight: Color::LightYellow,
+            comment_depths: comment_depths.to_vec(),
+        };
+
+        use Color::*;
+        match self {
+            Self::Blue => dark(
+                LightBlue,
+                LightCyan,
+                LightMagenta,
+                &[LightBlue, LightCyan, LightGreen, LightMagenta, LightYellow],
+            ),
+            Self::Magenta => dark(
+                LightMagenta,
+                White,
+                Magenta,
+                &[LightMagenta, LightBlue, LightCyan, LightGreen, LightYellow],
+            ),
+            Self::Yellow | Self::Custom => dark(
+                LightYellow,
+                White,
+                Yellow,
+                &[LightYellow, LightGreen, LightCyan, LightBlue, LightMagenta],
+            ),
+            Self::Light => UiThemePalette {
+                main: Blue,
+                block: DarkGray,
+                accent: Magenta,
+                text: Black,
+                muted: DarkGray,
+                success: Green,
+                warning: Rgb(181, 137, 0),
+                error: Red,
+                info: Blue,
+                highlight: LightBlue,
+                comment_depths: vec![Blue, Magenta, Green, Cyan, Red],
+            },
+        }
+    }
+}
+
+/// Resolved colors of a theme, used across the application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UiThemePalette {
+    main: Color,
+    block: Color,
+    accent: Color,
+    /// Regular text.
+    text: Color,
+    /// Secondary text.
+    muted: Color,
+    success: Color,
+    warning: Color,
+    error: Color,
+    info: Color,
+    /// Background of the highlighted elements, like the text input cursor.
+    highlight: Color,
+    /// Colors of the comments, cycled through according to their depth in the thread.
+    comment_depths: Vec<Color>,
+}
+
+impl Default for UiThemePalette {
+    fn default() -> Self {
+        UiTheme::default().get_builtin_palette()
+    }
+}
+
+impl UiThemePalette {
+    pub fn get_main_color(&self) -> Color {
+        self.main
+    }
+
+    pub fn get_block_color(&self) -> Color {
+        self.block
+    }
+
+    pub fn get_accent_color(&self) -> Color {
+        self.accent
+    }
+
+    pub fn get_text_color(&self) -> Color {
+        self.text
+    }
+
+    pub fn get_muted_color(&self) -> Color {
+        self.muted
+    }
+
+    pub fn get_success_color(&self) -> Color {
+        self.success
+    }
+
+    pub fn get_warning_color(&self) -> Color {
+        self.warning
+    }
+
+    pub fn get_error_color(&self) -> Color {
+        self.error
+    }
+
+    pub fn get_info_color(&self) -> Color {
+        self.info
+    }
+
+    pub fn get_highlight_color(&self) -> Color {
+        self.highlight
+    }
+
+    /// Get the color of a comment at the given depth (0 being a top-level comment).
+    pub fn get_comment_depth_color(&self, depth: usize) -> Color {
+        if self.comment_depths.is_empty() {
+            self.main
+        } else {
+            self.comment_depths[depth % self.comment_depths.len()]
+        }
+    }
+}
+
+/// Parse a color from the configuration.
+///
+/// Supports named colors (`"light-blue"`), 256-color indexes (`"208"`),
+/// hexadecimal (`"#ff8800"`) and RGB (`"rgb(255, 136, 0)"`) notations.
+pub fn parse_color(raw: &str) -> Result<Color> {
+    let raw = raw.trim();
+    if let Some(components) = raw
+        .strip_prefix("rgb(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let components: Vec<u8> = components
+            .split(',')
+            .map(|component| component.trim().parse::<u8>())
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| HnCliError::ThemeError(format!("invalid RGB color \"{raw}\"")))?;
+        return match components[..] {
+            [r, g, b] => Ok(Color::Rgb(r, g, b)),
+            _ => Err(HnCliError::ThemeError(format!(
+                "invalid RGB color \"{raw}\""
+            ))),
+        };
+    }
+    Color::from_str(raw).map_err(|_| HnCliError::ThemeError(format!("unknown color \"{raw}\"")))
+}
+
+/// Names of the colors of a custom palette, as in the `custom_theme` configuration table.
+pub const CUSTOM_THEME_COLORS: [&str; 10] = [
+    "main",
+    "block",
+    "accent",
+    "text",
+    "muted",
+    "success",
+    "warning",
+    "error",
+    "info",
+    "highlight",
+];
+
+/// User-defined palette, as defined in the `custom_theme` configuration table.
+///
+/// Every color is optional and defaults to the one of the `base` built-in theme.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UiThemePaletteConfiguration {
+    #[serde(default)]
+    pub base: UiTheme,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_depths: Option<Vec<String>>,
+}
+
+impl UiThemePaletteConfiguration {
+    /// Get a user-defined color from its name, *e.g.* "main".
+    pub fn get_color(&self, name: &str) -> Option<&str> {
+        match name {
+            "main" => self.main.as_deref(),
+            "block" => self.block.as_deref(),
+            "accent" => self.accent.as_deref(),
+            "text" => self.text.as_deref(),
+            "muted" => self.muted.as_deref(),
+            "success" => self.success.as_deref(),
+            "warning" => self.warning.as_deref(),
+            "error" => self.error.as_deref(),
+            "info" => self.info.as_deref(),
+            "highlight" => self.highlight.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Get a mutable handle on a user-defined color from its name, if it exists.
+    pub fn get_color_mut(&mut self, name: &str) -> Option<&mut Option<String>> {
+        match name {
+            "main" => Some(&mut self.main),
+            "block" => Some(&mut self.block),
+            "accent" => Some(&mut self.accent),
+            "text" => Some(&mut self.text),
+            "muted" => Some(&mut self.muted),
+            "success" => Some(&mut self.success),
+            "warning" => Some(&mut self.warning),
+            "error" => Some(&mut self.error),
+            "info" => Some(&mut self.info),
+            "highlight" => Some(&mut self.highlight),
+            _ => None,
+        }
+    }
+
+    /// Resolve the palette on top of the base built-in theme.
+    pub fn to_palette(&self) -> Result<UiThemePalette> {
+        if self.base == UiTheme::Custom {
+            return Err(HnCliError::ThemeError(
+                "the custom theme base must be a built-in theme".into(),
+            ));
+        }
+        let mut palette = self.base.get_builtin_palette();
+
+        let overrides = [
+            ("main", &self.main, &mut palette.main),
+            ("block", &self.block, &mut palette.block),
+            ("accent", &self.accent, &mut palette.accent),
+            ("text", &self.text, &mut palette.text),
+            ("muted", &self.muted, &mut palette.muted),
+            ("success", &self.success, &mut palette.success),
+            ("warning", &self.warning, &mut palette.warning),
+            ("error", &self.error, &mut palette.error),
+            ("info", &self.info, &mut palette.info),
+            ("highlight", &self.highlight, &mut palette.highlight),
+        ];
+        for (name, raw, color) in overrides {
+            if let Some(raw) = raw {
+                *color = parse_color(raw)
+                    .map_err(|why| HnCliError::ThemeError(format!("\"{name}\": {why}")))?;
+            }
+        }
+        if let Some(comment_depths) = &self.comment_depths {
+            palette.comment_depths = comment_depths
+                .iter()
+                .map(|raw| parse_color(raw))
+                .collect::<Result<_>>()?;
+        }
+
+        Ok(palette)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::{UiTheme, UiThemePaletteConfiguration, parse_color};
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("light-blue").unwrap(), Color::LightBlue);
+        assert_eq!(parse_color("DarkGray").unwrap(), Color::DarkGray);
+        assert_eq!(parse_color("208").unwrap(), Color::Indexed(208));
+        assert_eq!(parse_color("#ff8800").unwrap(), Color::Rgb(255, 136, 0));
+        assert_eq!(
+            parse_color("rgb(12, 34, 56)").unwrap(),
+            Color::Rgb(12, 34, 56)
+        );
+        assert!(parse_color("rgb(12, 34)").is_err());
+        assert!(parse_color("rgb(12, 34, 256)").is_err());
+        assert!(parse_color("#ff88").is_err());
+        assert!(parse_color("not-a-color").is_err());
+    }
+
+    #[test]
+    fn test_custom_palette() {
+        let configuration: UiThemePaletteConfiguration = toml::from_str(
+            r##"
+            base = "Light"
+            main = "#005f87"
+            error = "124"
+            comment_depths = ["blue", "rgb(0, 95, 0)"]
+            "##,
+        )
+        .unwrap();
+        let palette = configuration.to_palette().unwrap();
+        let light = UiTheme::Light.get_builtin_palette();
+
+        assert_eq!(palette.get_main_color(), Color::Rgb(0, 95, 135));
+        assert_eq!(palette.get_error_color(), Color::Indexed(124));
+        assert_eq!(palette.get_text_color(), light.get_text_color());
+        assert_eq!(palette.get_comment_depth_color(0), Color::Blue);
+        assert_eq!(palette.get_comment_depth_color(3), Color::Rgb(0, 95, 0));
+
+        let invalid: UiThemePaletteConfiguration = toml::from_str("accent = \"nope\"").unwrap();
+        assert!(invalid.to_palette().is_err());
+        let invalid_base: UiThemePaletteConfiguration =
+            toml::from_str("base = \"Custom\"").unwrap();
+        assert!(invalid_base.to_palette().is_err());
+    }
+
+    #[test]
+    fn test_next_value() {
+        assert_eq!(UiTheme::Light.next_value(false), UiTheme::Blue);
+        assert_eq!(UiTheme::Light.next_value(true), UiTheme::Custom);
+        assert_eq!(UiTheme::Custom.next_value(true), UiTheme::Blue);
+        assert_eq!(UiTheme::Blue.previous_value(false), UiTheme::Light);
+        assert_eq!(UiTheme::Blue.previous_value(true), UiTheme::Custom);
     }
 }
@@ -32,9 +32,58 @@ pub fn open_browser_tab(url: &str) {
     let _ = webbrowser::open(url);
 }
 
+/// Fuzzy match the given pattern against the text, case-insensitively and ignoring
+/// the whitespaces of the pattern, returning the (characters) indices of the matched
+/// text characters if the pattern is found in order.
+///
+/// A contiguous occurrence of the pattern is preferred, for a more readable highlighting.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<Vec<usize>> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    // NB: only the first lowercase character is kept, to keep the indices aligned
+    let text: Vec<char> = text
+        .chars()
+        .map(|char| char.to_lowercase().next().unwrap_or(char))
+        .collect();
+    if pattern.is_empty() {
+        return Some(vec![]);
+    }
+
+    if let Some(start) = text
+        .windows(pattern.len())
+        .position(|window| window == pattern.as_slice())
+    {
+        return Some((start..start + pattern.len()).collect());
+    }
+
+    let mut indices = Vec::with_capacity(pattern.len());
+    let mut pattern_chars = pattern.iter().peekable();
+    for (index, char) in text.iter().enumerate() {
+        match pattern_chars.peek() {
+            Some(&pattern_char) if pattern_char == char => {
+                indices.push(index);
+                pattern_chars.next();
+            }
+            Some(_) => {}
+            None => break,
+        }
+    }
+    pattern_chars.peek().is_none().then_some(indices)
+}
+
+/// Sorting key of a fuzzy match, given its matched indices, the best matches coming first:
+/// a contiguous occurrence first, then the earliest one.
+pub fn fuzzy_match_quality(indices: &[usize]) -> (bool, usize) {
+    let is_scattered = indices.windows(2).any(|pair| pair[1] != pair[0] + 1);
+    (is_scattered, indices.first().copied().unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::datetime_from_hn_time;
+    use super::{datetime_from_hn_time, fuzzy_match, fuzzy_match_quality};
 
     #[test]
     pub fn test_datetime_from_hn_time() {
@@ -43,4 +92,34 @@ mod tests {
 
         assert_eq!(formatted_date, "2008-02-22 02:33:40".to_string());
     }
+
+    #[test]
+    pub fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("", "Show HN"), Some(vec![]));
+        assert_eq!(fuzzy_match("rust", "Why Rust?"), Some(vec![4, 5, 6, 7]));
+        assert_eq!(fuzzy_match("RST", "Why Rust?"), Some(vec![4, 6, 7]));
+        assert_eq!(
+            fuzzy_match("show hn", "Show HN: hncli"),
+            Some(vec![0, 1, 2, 3, 5, 6])
+        );
+        assert_eq!(fuzzy_match("ghub", "github.com"), Some(vec![0, 3, 4, 5]));
+        assert_eq!(fuzzy_match("élan", "Un Élan"), Some(vec![3, 4, 5, 6]));
+        assert_eq!(fuzzy_match("tsur", "Why Rust?"), None);
+        assert_eq!(fuzzy_match("rusty", "Why Rust?"), None);
+    }
+
+    #[test]
+    pub fn test_fuzzy_match_quality() {
+        assert_eq!(fuzzy_match_quality(&[]), (false, 0));
+        assert_eq!(fuzzy_match_quality(&[4, 5, 6, 7]), (false, 4));
+        assert_eq!(fuzzy_match_quality(&[0, 3, 4, 5]), (true, 0));
+
+        let mut qualities = [
+            fuzzy_match_quality(&[0, 3, 4, 5]),
+            fuzzy_match_quality(&[4, 5, 6, 7]),
+            fuzzy_match_quality(&[1, 2]),
+        ];
+        qualities.sort();
+        assert_eq!(qualities, [(false, 1), (false, 4), (true, 0)]);
+    }
 }
@@ -68,6 +68,45 @@ impl Breakpoints {
         components_ids: &[UiComponentId],
         frame_size: Rect,
         along: BreakpointsDirection,
+    ) {
+        self.split(
+            components_registry,
+            components_ids,
+            frame_size,
+            frame_size,
+            along,
+            2,
+        );
+    }
+
+    /// Apply the layout inside the area of an already laid out component, replacing it,
+    /// the breakpoints being matched against the frame size.
+    pub fn apply_inside(
+        &self,
+        components_registry: &mut ScreenComponentsRegistry,
+        components_ids: &[UiComponentId],
+        frame_size: Rect,
+        area: Rect,
+        along: BreakpointsDirection,
+    ) {
+        self.split(
+            components_registry,
+            components_ids,
+            frame_size,
+            area,
+            along,
+            0,
+        );
+    }
+
+    fn split(
+        &self,
+        components_registry: &mut ScreenComponentsRegistry,
+        components_ids: &[UiComponentId],
+        frame_size: Rect,
+        area: Rect,
+        along: BreakpointsDirection,
+        margin: u16,
     ) {
         use BreakpointsDirection::*;
 
@@ -86,7 +125,7 @@ impl Breakpoints {
             Horizontal => Direction::Horizontal,
             Vertical => Direction::Vertical,
         };
-        let layout = Layout::default().margin(2).direction(direction);
+        let layout = Layout::default().margin(margin).direction(direction);
 
         let mut constraints = Vec::with_capacity(percentages.len());
         let mut active_components_ids = Vec::with_capacity(percentages.len());
@@ -98,7 +137,7 @@ impl Breakpoints {
             active_components_ids.push(components_ids[i]);
         }
 
-        let chunks = layout.constraints(constraints).split(frame_size);
+        let chunks = layout.constraints(constraints).split(area);
         for (i, component_id) in active_components_ids.iter().enumerate() {
             components_registry.insert(*component_id, chunks[i]);
         }
@@ -201,4 +240,39 @@ mod tests {
         assert!(!components_registry.contains_key(&component_id_a));
         assert!(components_registry.contains_key(&component_id_b));
     }
+
+    #[test]
+    fn test_breakpoints_apply_inside() {
+        let mut components_registry = ScreenComponentsRegistry::new();
+        let (component_id_a, component_id_b) = ("a", "b");
+        let breakpoints = Breakpoints::new("test", &[100, 0]).breakpoint(100, &[60, 40]);
+        let area = Rect::new(2, 5, 80, 20);
+
+        breakpoints.apply_inside(
+            &mut components_registry,
+            &[component_id_a, component_id_b],
+            Rect::new(0, 0, 84, 30),
+            area,
+            BreakpointsDirection::Horizontal,
+        );
+        assert_eq!(components_registry.get(&component_id_a), Some(&area));
+        assert!(!components_registry.contains_key(&component_id_b));
+
+        components_registry.clear();
+        breakpoints.apply_inside(
+            &mut components_registry,
+            &[component_id_a, component_id_b],
+            Rect::new(0, 0, 100, 30),
+            area,
+            BreakpointsDirection::Horizontal,
+        );
+        assert_eq!(
+            components_registry.get(&component_id_a),
+            Some(&Rect::new(2, 5, 48, 20))
+        );
+        assert_eq!(
+            components_registry.get(&component_id_b),
+            Some(&Rect::new(50, 5, 32, 20))
+        );
+    }
 }